@@ -0,0 +1,24 @@
+use dialoguer::{theme::ColorfulTheme, CascadeSelect};
+
+fn main() {
+    let path = CascadeSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt("Location")
+        .level("Country", |_| vec!["Austria", "Germany", "Liechtenstein"])
+        .level("Region", |path| match path[0].as_str() {
+            "Austria" => vec!["Styria", "Vienna"],
+            "Germany" => vec!["Bavaria", "Berlin", "Hamburg"],
+            _ => vec![],
+        })
+        .level("City", |path| match path[1].as_str() {
+            "Styria" => vec!["Graz", "Leoben"],
+            "Bavaria" => vec!["Munich", "Nuremberg"],
+            _ => vec![],
+        })
+        .interact_opt()
+        .unwrap();
+
+    match path {
+        Some(path) => println!("You chose {}", path.join(", ")),
+        None => println!("You did not choose a location"),
+    }
+}
@@ -1,13 +1,14 @@
 use dialoguer::{theme::ColorfulTheme, Completion, Input};
 
 fn main() {
-    println!("Use the Right arrow or Tab to complete your command");
+    println!("Use the Right arrow, End or Tab to complete your command");
 
     let completion = MyCompletion::default();
 
     Input::<String>::with_theme(&ColorfulTheme::default())
         .with_prompt("dialoguer")
         .completion_with(&completion)
+        .ghost_text(true)
         .interact_text()
         .unwrap();
 }
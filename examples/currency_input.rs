@@ -0,0 +1,25 @@
+use dialoguer::{theme::ColorfulTheme, CurrencyInput, NumberFormat};
+
+fn main() {
+    let cents = CurrencyInput::with_theme(&ColorfulTheme::default())
+        .with_prompt("Price")
+        .default(999)
+        .interact()
+        .unwrap();
+
+    println!("The price is {} cents", cents);
+
+    let cents = CurrencyInput::with_theme(&ColorfulTheme::default())
+        .with_prompt("Preis")
+        .symbol(" €")
+        .symbol_after(true)
+        .number_format(
+            NumberFormat::new()
+                .decimal_separator(',')
+                .grouping_separator(Some('.')),
+        )
+        .interact()
+        .unwrap();
+
+    println!("Der Preis ist {} Cent", cents);
+}
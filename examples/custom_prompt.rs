@@ -0,0 +1,66 @@
+use console::{Key, Term};
+use dialoguer::{
+    theme::{render::TermThemeRenderer, ColorfulTheme},
+    Paging, Result,
+};
+
+/// A custom prompt which picks a number with the arrow keys.
+fn pick_number(term: &Term, prompt: &str, numbers: &[u32]) -> Result<u32> {
+    let theme = ColorfulTheme::default();
+    let mut render = TermThemeRenderer::new(term, &theme);
+    let mut paging = Paging::new(term, numbers.len(), None);
+    let mut sel = 0;
+
+    let items: Vec<_> = numbers
+        .iter()
+        .map(|number| format!("#{}", number))
+        .collect();
+    let size_vec: Vec<_> = items.iter().map(String::len).collect();
+
+    term.hide_cursor()?;
+
+    loop {
+        paging.render_prompt(|paging_info| render.select_prompt(prompt, paging_info))?;
+
+        for (idx, item) in items
+            .iter()
+            .enumerate()
+            .skip(paging.current_page * paging.capacity)
+            .take(paging.capacity)
+        {
+            render.select_prompt_item(item, idx == sel)?;
+        }
+
+        term.flush()?;
+
+        match term.read_key()? {
+            Key::ArrowDown => sel = (sel + 1) % items.len(),
+            Key::ArrowUp => sel = (sel + items.len() - 1) % items.len(),
+            Key::ArrowRight if paging.active => sel = paging.next_page(),
+            Key::ArrowLeft if paging.active => sel = paging.previous_page(),
+            Key::Enter => {
+                render.clear()?;
+                render.select_prompt_selection(prompt, &items[sel])?;
+                term.show_cursor()?;
+
+                return Ok(numbers[sel]);
+            }
+            _ => {}
+        }
+
+        paging.update(sel)?;
+
+        if paging.active {
+            render.clear()?;
+        } else {
+            render.clear_preserve_prompt(&size_vec)?;
+        }
+    }
+}
+
+fn main() {
+    let numbers: Vec<u32> = (1..=50).map(|n| n * n).collect();
+    let number = pick_number(&Term::stderr(), "Pick a square number", &numbers).unwrap();
+
+    println!("You picked {}", number);
+}
@@ -0,0 +1,31 @@
+use dialoguer::{theme::ColorfulTheme, FilePicker};
+
+fn main() {
+    let path = FilePicker::with_theme(&ColorfulTheme::default())
+        .with_prompt("Pick a Rust file")
+        .extensions(["rs"])
+        .interact_opt()
+        .unwrap();
+
+    match path {
+        Some(path) => println!("You picked {}", path.display()),
+        None => println!("You did not pick a file"),
+    }
+
+    let path = FilePicker::with_theme(&ColorfulTheme::default())
+        .with_prompt("Pick a directory")
+        .directories_only(true)
+        .interact()
+        .unwrap();
+
+    println!("You picked {}", path.display());
+
+    let path = FilePicker::with_theme(&ColorfulTheme::default())
+        .with_prompt("Save as")
+        .save(true)
+        .file_name("notes.txt")
+        .interact()
+        .unwrap();
+
+    println!("Saving to {}", path.display());
+}
@@ -39,4 +39,19 @@ fn main() {
             println!("  {}", multiselected[selection]);
         }
     }
+
+    let selections = MultiSelect::with_theme(&ColorfulTheme::default())
+        .with_prompt("Pick your food, press '/' to filter")
+        .items(&multiselected[..])
+        .filter_mode(true)
+        .interact()
+        .unwrap();
+    if selections.is_empty() {
+        println!("You did not select anything :(");
+    } else {
+        println!("You selected these things:");
+        for selection in selections {
+            println!("  {}", multiselected[selection]);
+        }
+    }
 }
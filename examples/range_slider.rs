@@ -0,0 +1,23 @@
+use dialoguer::{theme::ColorfulTheme, RangeSlider};
+
+fn main() {
+    let (low, high) = RangeSlider::with_theme(&ColorfulTheme::default())
+        .with_prompt("Port range")
+        .bounds(1024, 65535)
+        .step(1000)
+        .default(8000, 9000)
+        .interact()
+        .unwrap();
+
+    println!("Listening on ports {} to {}", low, high);
+
+    let (low, high) = RangeSlider::new()
+        .with_prompt("Price between")
+        .bounds(0, 500)
+        .step(10)
+        .width(20)
+        .interact()
+        .unwrap();
+
+    println!("Showing prices from {} to {}", low, high);
+}
@@ -0,0 +1,51 @@
+use dialoguer::{theme::ColorfulTheme, Session};
+
+fn main() -> dialoguer::Result<()> {
+    let theme = ColorfulTheme::default();
+    let mut session = Session::with_theme(&theme).summary(true).back(true);
+
+    let mut name = String::new();
+    let mut license = 0;
+    let mut features = Vec::new();
+    let mut create = false;
+
+    // Press 'Esc' to go back to the previous question
+    session.wizard(&mut [
+        &mut |session: &mut Session| {
+            name = session.input(|input| input.with_prompt("Project name"))?;
+            Ok(())
+        },
+        &mut |session: &mut Session| {
+            license = session.select(|select| {
+                select
+                    .with_prompt("License")
+                    .items(["MIT", "Apache-2.0", "MIT OR Apache-2.0"])
+                    .default(2)
+            })?;
+            Ok(())
+        },
+        &mut |session: &mut Session| {
+            features = session.multi_select(|multi_select| {
+                multi_select
+                    .with_prompt("Features")
+                    .items(["cli", "serde", "async"])
+            })?;
+            Ok(())
+        },
+        &mut |session: &mut Session| {
+            create = session.confirm(|confirm| confirm.with_prompt("Create the project?"))?;
+            Ok(())
+        },
+    ])?;
+
+    session.finish()?;
+
+    if create {
+        println!(
+            "Creating {} (license #{}, features {:?})",
+            name, license, features
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use dialoguer::{theme::ColorfulTheme, TextArea};
+
+fn main() {
+    if let Some(notes) = TextArea::with_theme(&ColorfulTheme::default())
+        .with_prompt("Release notes")
+        .with_initial_text("## Changes\n")
+        .height(5)
+        .interact_opt()
+        .unwrap()
+    {
+        println!("Your notes:");
+        println!("{}", notes);
+    } else {
+        println!("Abort!");
+    }
+}
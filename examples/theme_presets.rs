@@ -0,0 +1,35 @@
+use dialoguer::{theme::ColorfulTheme, Select};
+
+fn main() {
+    let presets = [
+        "Nord",
+        "Solarized Dark",
+        "Solarized Light",
+        "Dracula",
+        "Catppuccin Latte",
+        "Catppuccin Mocha",
+    ];
+
+    let mut selection = 0;
+    loop {
+        let theme = match selection {
+            0 => ColorfulTheme::nord(),
+            1 => ColorfulTheme::solarized_dark(),
+            2 => ColorfulTheme::solarized_light(),
+            3 => ColorfulTheme::dracula(),
+            4 => ColorfulTheme::catppuccin_latte(),
+            _ => ColorfulTheme::catppuccin_mocha(),
+        };
+
+        match Select::with_theme(&theme)
+            .with_prompt("Pick a theme, 'Esc' to quit")
+            .default(selection)
+            .items(&presets[..])
+            .interact_opt()
+            .unwrap()
+        {
+            Some(picked) => selection = picked,
+            None => break,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+/// Reads the text of the system clipboard.
+///
+/// Returns `None` if the clipboard can't be accessed or holds no text.
+pub(crate) fn read() -> Option<String> {
+    arboard::Clipboard::new().ok()?.get_text().ok()
+}
@@ -2,11 +2,12 @@ use std::{
     env,
     ffi::{OsStr, OsString},
     fs,
-    io::{Read, Write},
+    io::{self, Read, Write},
+    path::Path,
     process,
 };
 
-use crate::Result;
+use crate::{Result, TextArea};
 
 /// Launches the default editor to edit a string.
 ///
@@ -27,6 +28,8 @@ pub struct Editor {
     extension: String,
     require_save: bool,
     trim_newlines: bool,
+    text_area_fallback: bool,
+    position: Option<(usize, usize)>,
 }
 
 fn get_default_editor() -> OsString {
@@ -57,6 +60,8 @@ impl Editor {
             extension: ".txt".into(),
             require_save: true,
             trim_newlines: true,
+            text_area_fallback: false,
+            position: None,
         }
     }
 
@@ -86,6 +91,39 @@ impl Editor {
         self
     }
 
+    /// Enables or disables editing in a [`TextArea`] if the editor can't be found,
+    /// e.g. in containers without an editor.
+    ///
+    /// The text is returned once it's saved with Ctrl-S, or `None` if it's cancelled
+    /// with 'Esc'. This is off by default.
+    pub fn text_area_fallback(&mut self, val: bool) -> &mut Self {
+        self.text_area_fallback = val;
+        self
+    }
+
+    /// Opens the editor with the cursor at `line` and `col`, both starting at 1.
+    ///
+    /// The position is passed to editors which are known to support it: vi, vim,
+    /// neovim, nano, micro, emacs, kakoune, helix, VS Code and Sublime Text.
+    /// Other editors open the file at its start.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Editor;
+    ///
+    /// let config = "[server]\nport = eighty\n";
+    /// let fixed = Editor::new()
+    ///     .extension(".toml")
+    ///     .open_at(2, 8)
+    ///     .edit(config)
+    ///     .unwrap();
+    /// ```
+    pub fn open_at(&mut self, line: usize, col: usize) -> &mut Self {
+        self.position = Some((line.max(1), col.max(1)));
+        self
+    }
+
     /// Launches the editor to edit a string.
     ///
     /// Returns `None` if the file was not saved or otherwise the
@@ -100,20 +138,25 @@ impl Editor {
         f.flush()?;
         let ts = fs::metadata(f.path())?.modified()?;
 
-        let s: String = self.editor.clone().into_string().unwrap();
-        let (cmd, args) = match shell_words::split(&s) {
-            Ok(mut parts) => {
-                let cmd = parts.remove(0);
-                (cmd, parts)
-            }
-            Err(_) => (s, vec![]),
+        let (cmd, args) = self.command();
+        let file_args = match self.position {
+            Some((line, col)) => position_args(&cmd, f.path(), line, col),
+            None => vec![f.path().into()],
         };
 
-        let rv = process::Command::new(cmd)
+        let mut child = match process::Command::new(cmd)
             .args(args)
-            .arg(f.path())
-            .spawn()?
-            .wait()?;
+            .args(file_args)
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(err) if self.text_area_fallback && err.kind() == io::ErrorKind::NotFound => {
+                let rv = TextArea::new().with_initial_text(s).interact_opt()?;
+                return Ok(rv.map(|rv| self.trimmed(rv)));
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let rv = child.wait()?;
 
         if rv.success() && self.require_save && ts >= fs::metadata(f.path())?.modified()? {
             return Ok(None);
@@ -123,11 +166,221 @@ impl Editor {
         let mut rv = String::new();
         new_f.read_to_string(&mut rv)?;
 
+        Ok(Some(self.trimmed(rv)))
+    }
+
+    /// Launches the editor to edit several files and returns for each file whether
+    /// it was changed.
+    ///
+    /// Editors which are known to open several files at once, like in
+    /// [`open_at`](Self::open_at), get all files in one invocation. Other editors are
+    /// launched for one file after the other. If the editor exits with an error,
+    /// the remaining files are not opened. Files which don't exist yet are
+    /// created if the editor saves them. The [`text_area_fallback`](Self::text_area_fallback)
+    /// edits one file after the other, a file cancelled with 'Esc' is left unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use dialoguer::Editor;
+    ///
+    /// let files = [PathBuf::from("Cargo.toml"), PathBuf::from("src/main.rs")];
+    /// let changed = Editor::new().edit_files(&files).unwrap();
+    ///
+    /// for (file, changed) in files.iter().zip(changed) {
+    ///     if changed {
+    ///         println!("You changed {}", file.display());
+    ///     }
+    /// }
+    /// ```
+    pub fn edit_files<P: AsRef<Path>>(&self, paths: &[P]) -> Result<Vec<bool>> {
+        let before: Vec<_> = paths.iter().map(|path| fs::read(path).ok()).collect();
+        let (cmd, args) = self.command();
+
+        let batches: Vec<&[P]> = if opens_several_files(&cmd) {
+            vec![paths]
+        } else {
+            paths.chunks(1).collect()
+        };
+
+        for batch in batches {
+            let files = batch.iter().map(|path| path.as_ref());
+
+            match process::Command::new(&cmd).args(&args).args(files).status() {
+                Ok(status) if status.success() => {}
+                // The editor failed or was aborted, e.g. with ':cq' in vim
+                Ok(_) => break,
+                Err(err) if self.text_area_fallback && err.kind() == io::ErrorKind::NotFound => {
+                    return self.edit_files_in_text_area(paths, before);
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(changed(paths, before))
+    }
+
+    /// Edits the files one after the other in a [`TextArea`].
+    fn edit_files_in_text_area<P: AsRef<Path>>(
+        &self,
+        paths: &[P],
+        before: Vec<Option<Vec<u8>>>,
+    ) -> Result<Vec<bool>> {
+        for path in paths {
+            let path = path.as_ref();
+            let text = match fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+                Err(err) => return Err(err.into()),
+            };
+
+            if let Some(text) = TextArea::new()
+                .with_prompt(path.display().to_string())
+                .with_initial_text(text)
+                .interact_opt()?
+            {
+                fs::write(path, text)?;
+            }
+        }
+
+        Ok(changed(paths, before))
+    }
+
+    /// Returns the executable of the editor and its arguments.
+    fn command(&self) -> (String, Vec<String>) {
+        let editor: String = self.editor.clone().into_string().unwrap();
+        match shell_words::split(&editor) {
+            Ok(mut parts) if !parts.is_empty() => {
+                let cmd = parts.remove(0);
+                (cmd, parts)
+            }
+            _ => (editor, vec![]),
+        }
+    }
+
+    /// Strips the trailing newlines of the text, if enabled.
+    fn trimmed(&self, mut rv: String) -> String {
         if self.trim_newlines {
             let len = rv.trim_end_matches(&['\n', '\r'][..]).len();
             rv.truncate(len);
         }
 
-        Ok(Some(rv))
+        rv
+    }
+}
+
+/// Returns for each file whether its content differs from the content `before`.
+fn changed<P: AsRef<Path>>(paths: &[P], before: Vec<Option<Vec<u8>>>) -> Vec<bool> {
+    paths
+        .iter()
+        .zip(before)
+        .map(|(path, before)| fs::read(path).ok() != before)
+        .collect()
+}
+
+/// Returns the name of the editor `cmd` without its directory and extension.
+fn editor_name(cmd: &str) -> &str {
+    Path::new(cmd)
+        .file_stem()
+        .and_then(OsStr::to_str)
+        .unwrap_or(cmd)
+}
+
+/// Indicates whether the editor `cmd` opens all files it's given at once.
+fn opens_several_files(cmd: &str) -> bool {
+    matches!(
+        editor_name(cmd),
+        "vi" | "vim"
+            | "nvim"
+            | "gvim"
+            | "mvim"
+            | "nano"
+            | "micro"
+            | "emacs"
+            | "emacsclient"
+            | "kak"
+            | "code"
+            | "code-insiders"
+            | "codium"
+            | "subl"
+            | "hx"
+            | "helix"
+    )
+}
+
+/// Returns the arguments which open `path` at `line` and `col` in the editor `cmd`.
+fn position_args(cmd: &str, path: &Path, line: usize, col: usize) -> Vec<OsString> {
+    let name = editor_name(cmd);
+
+    // Editors which take the position as part of the path, e.g. `file:27:5`
+    let with_position = || {
+        let mut arg = path.as_os_str().to_owned();
+        arg.push(format!(":{}:{}", line, col));
+        arg
+    };
+
+    match name {
+        "vi" | "vim" | "nvim" | "gvim" | "mvim" => {
+            vec![
+                format!("+call cursor({}, {})", line, col).into(),
+                path.into(),
+            ]
+        }
+        "nano" => vec![format!("+{},{}", line, col).into(), path.into()],
+        "micro" | "emacs" | "emacsclient" | "kak" => {
+            vec![format!("+{}:{}", line, col).into(), path.into()]
+        }
+        "code" | "code-insiders" | "codium" => vec!["--goto".into(), with_position()],
+        "subl" | "hx" | "helix" => vec![with_position()],
+        _ => vec![path.into()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_args() {
+        let path = Path::new("config.toml");
+
+        assert_eq!(
+            position_args("vim", path, 27, 5),
+            ["+call cursor(27, 5)", "config.toml"]
+        );
+        assert_eq!(
+            position_args("/usr/bin/nano", path, 27, 5),
+            ["+27,5", "config.toml"]
+        );
+        assert_eq!(
+            position_args("code", path, 27, 5),
+            ["--goto", "config.toml:27:5"]
+        );
+        assert_eq!(position_args("notepad.exe", path, 27, 5), ["config.toml"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_edit_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        let files = [dir.join("a.txt"), dir.join("b.txt"), dir.join("c.txt")];
+        fs::write(&files[0], "a").unwrap();
+        fs::write(&files[1], "b").unwrap();
+
+        let unchanged = Editor::new().executable("true").edit_files(&files).unwrap();
+        assert_eq!(unchanged, [false, false, false]);
+
+        // Appends to every file it's given, one file per invocation
+        let changed = Editor::new()
+            .executable("sh -c 'echo x >> \"$0\"'")
+            .edit_files(&files[1..])
+            .unwrap();
+
+        assert_eq!(changed, [true, true]);
+        assert_eq!(fs::read_to_string(&files[0]).unwrap(), "a");
+        assert_eq!(fs::read_to_string(&files[2]).unwrap(), "x\n");
     }
 }
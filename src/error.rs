@@ -1,24 +1,65 @@
-use std::{io::Error as IoError, result::Result as StdResult};
+use std::{
+    io::{Error as IoError, ErrorKind},
+    result::Result as StdResult,
+};
 
 use thiserror::Error;
 
 /// Possible errors returned by prompts.
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Error while executing IO operations.
     #[error("IO error: {0}")]
-    IO(#[from] IoError),
+    IO(IoError),
+
+    /// The input ended, e.g. because stdin was closed.
+    #[error("end of input")]
+    Eof,
+
+    /// The prompt was started on a terminal which is not interactive.
+    #[error("not a terminal")]
+    NotATerminal,
+
+    /// The prompt was started without any items to choose from.
+    #[error("empty list of items")]
+    EmptyItems,
+
+    /// The user cancelled the prompt, e.g. with 'Esc'.
+    #[error("prompt cancelled")]
+    Cancelled,
+
+    /// The user went back to the previous prompt of a [`Session`](crate::Session).
+    #[error("went back to the previous prompt")]
+    Back,
+
+    /// The input was rejected by a validator.
+    #[error("validation failed: {0}")]
+    ValidationFailed(String),
 }
 
 /// Result type where errors are of type [Error](enum@Error).
 pub type Result<T = ()> = StdResult<T, Error>;
 
+impl From<IoError> for Error {
+    fn from(value: IoError) -> Self {
+        if value.kind() == ErrorKind::UnexpectedEof {
+            Error::Eof
+        } else {
+            Error::IO(value)
+        }
+    }
+}
+
 impl From<Error> for IoError {
     fn from(value: Error) -> Self {
         match value {
             Error::IO(err) => err,
-            // If other error types are added in the future:
-            // err => IoError::new(std::io::ErrorKind::Other, err),
+            Error::NotATerminal => IoError::new(ErrorKind::NotConnected, value),
+            Error::Eof => IoError::new(ErrorKind::UnexpectedEof, value),
+            err => IoError::new(ErrorKind::Other, err),
         }
     }
 }
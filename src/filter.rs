@@ -0,0 +1,87 @@
+//! Provides filtering of list items for prompts with a `/` search.
+
+/// Returns the indices of all items matching the filter.
+///
+/// Matching is case-insensitive and an item matches if it contains the filter
+/// anywhere. An empty filter matches all items.
+pub(crate) fn filter_items<S: AsRef<str>>(items: &[S], filter: &str) -> Vec<usize> {
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| matches_filter(item.as_ref(), filter))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Checks whether `item` contains the filter, ignoring case.
+pub(crate) fn matches_filter(item: &str, filter: &str) -> bool {
+    item.to_lowercase().contains(&filter.to_lowercase())
+}
+
+/// Checks whether `text` matches the glob `pattern`, ignoring case.
+///
+/// `*` matches any number of characters and `?` matches a single character.
+pub(crate) fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // The position after the last `*` and the position in the text it matches up to
+    let mut star = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                star = Some((p, t));
+            }
+            Some(&chr) if chr == '?' || chr == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            // Lets the last `*` match one more character
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p;
+                    t = star_t + 1;
+                    star = Some((star_p, t));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&chr| chr == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("*.rs", "main.rs"));
+        assert!(glob_matches("*.RS", "Main.rs"));
+        assert!(glob_matches("m??n.*", "main.rs"));
+        assert!(glob_matches("*a*b*", "xxaybzz"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("*.rs", "main.rs.bak"));
+        assert!(!glob_matches("?", ""));
+        assert!(!glob_matches("a*b", "abc"));
+    }
+
+    #[test]
+    fn test_filter_items() {
+        let items = vec![
+            "Ice Cream".to_string(),
+            "Vanilla Cupcake".to_string(),
+            "Chocolate Muffin".to_string(),
+        ];
+
+        assert_eq!(filter_items(&items, ""), vec![0, 1, 2]);
+        assert_eq!(filter_items(&items, "c"), vec![0, 1, 2]);
+        assert_eq!(filter_items(&items, "CUP"), vec![1]);
+        assert_eq!(filter_items(&items, "muffin"), vec![2]);
+        assert!(filter_items(&items, "mustard").is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::memory::{escape, split};
+
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+
+/// The number of the latest choices of an item which are kept.
+const CHOICES: usize = 100;
+
+/// Ranks items by how frequently and recently they were chosen.
+///
+/// Records the chosen items of a prompt in a file, so that
+/// [`Select`](crate::Select::frecency) lists them first and
+/// [`FuzzySelect`](crate::FuzzySelect::frecency) boosts their matches the next time.
+/// The file can be shared by several prompts with different ids. Items are
+/// recognized by their text. Errors when reading or writing the file are ignored.
+///
+/// Every choice of an item counts, weighted by its age: choices of the last hour
+/// count four times, of the last day twice, of the last week once and older ones half.
+/// Only the last 100 choices of an item are kept.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{Frecency, Select};
+///
+/// fn main() {
+///     let commands = ["build", "test", "deploy", "clean"];
+///     let frecency = Frecency::new("command", "/tmp/my-cli/frecency");
+///
+///     let selection = Select::new()
+///         .items(&commands)
+///         .frecency(frecency.clone())
+///         .interact()
+///         .unwrap();
+///
+///     let command = commands[selection];
+///     println!("The score of {} is {}", command, frecency.score(command));
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Frecency {
+    id: String,
+    path: PathBuf,
+}
+
+impl Frecency {
+    /// Creates a ranking for the prompt `id`, which is stored in the file at `path`.
+    pub fn new<I: Into<String>, P: AsRef<Path>>(id: I, path: P) -> Self {
+        Frecency {
+            id: id.into(),
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the score of `item`, which is 0 if it was never chosen.
+    pub fn score(&self, item: &str) -> f64 {
+        self.scores().get(item).copied().unwrap_or(0.0)
+    }
+
+    /// Sorts the items by their score, the best first.
+    ///
+    /// Items with equal scores keep their order.
+    pub fn sort<S: AsRef<str>>(&self, items: &mut [S]) {
+        let scores = self.scores();
+        let score = |item: &S| scores.get(item.as_ref()).copied().unwrap_or(0.0);
+
+        items.sort_by(|a, b| score(b).total_cmp(&score(a)));
+    }
+
+    /// Records that `item` was chosen.
+    pub fn record(&self, item: &str) {
+        let _ = self.write(item, now());
+    }
+
+    /// Returns the scores of all items which were chosen.
+    pub(crate) fn scores(&self) -> HashMap<String, f64> {
+        let content = fs::read_to_string(&self.path).unwrap_or_default();
+        let now = now();
+
+        content
+            .lines()
+            .map(split)
+            .filter_map(|fields| match &fields[..] {
+                [id, item, times] if *id == self.id => {
+                    Some((item.clone(), score(&parse_times(times)?, now)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn write(&self, item: &str, now: u64) -> io::Result<()> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut times = Vec::new();
+        let mut lines: Vec<String> = content
+            .lines()
+            .filter(|line| match &split(line)[..] {
+                [id, it, old] if *id == self.id && it == item => {
+                    times = parse_times(old).unwrap_or_default();
+                    false
+                }
+                _ => true,
+            })
+            .map(str::to_owned)
+            .collect();
+
+        times.push(now);
+        let times: Vec<_> = times[times.len().saturating_sub(CHOICES)..]
+            .iter()
+            .map(u64::to_string)
+            .collect();
+
+        lines.push(format!(
+            "{}\t{}\t{}",
+            escape(&self.id),
+            escape(item),
+            times.join(" ")
+        ));
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.path, lines.join("\n") + "\n")
+    }
+}
+
+/// Parses the space separated times at which an item was chosen.
+fn parse_times(field: &str) -> Option<Vec<u64>> {
+    field.split(' ').map(|time| time.parse().ok()).collect()
+}
+
+/// Returns the score of an item chosen at `times`, each weighted by its age.
+fn score(times: &[u64], now: u64) -> f64 {
+    times
+        .iter()
+        .map(|&time| match now.saturating_sub(time) {
+            age if age < HOUR => 4.0,
+            age if age < DAY => 2.0,
+            age if age < WEEK => 1.0,
+            _ => 0.5,
+        })
+        .sum()
+}
+
+/// Returns the current time in seconds since the epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        assert_eq!(score(&[100, 100, 100], 100), 12.0);
+        assert_eq!(score(&[100, 100, 100], 100 + DAY), 3.0);
+        assert_eq!(score(&[0], 2 * WEEK), 0.5);
+        assert_eq!(score(&[0, 0, 0, 0, 2 * WEEK], 2 * WEEK), 6.0);
+        assert_eq!(score(&[], 0), 0.0);
+    }
+
+    #[test]
+    fn test_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("frecency");
+        let commands = Frecency::new("command", &path);
+        let regions = Frecency::new("region", &path);
+
+        commands.record("test");
+        regions.record("eu-west-1");
+        commands.record("deploy");
+        commands.record("test");
+
+        assert_eq!(commands.score("test"), 8.0);
+        assert_eq!(commands.score("deploy"), 4.0);
+        assert_eq!(commands.score("eu-west-1"), 0.0);
+
+        for _ in 0..3 {
+            commands.write("build", 0).unwrap();
+        }
+        commands.record("build");
+
+        assert_eq!(commands.score("build"), 5.5);
+
+        let mut items = ["build", "deploy", "clean", "test"];
+        commands.sort(&mut items);
+        assert_eq!(items, ["test", "build", "deploy", "clean"]);
+    }
+}
@@ -16,6 +16,16 @@ pub trait History<T> {
     fn write(&mut self, val: &T);
 }
 
+impl<T, H: History<T> + ?Sized> History<T> for &mut H {
+    fn read(&self, pos: usize) -> Option<String> {
+        (**self).read(pos)
+    }
+
+    fn write(&mut self, val: &T) {
+        (**self).write(val)
+    }
+}
+
 pub struct BasicHistory {
     max_entries: Option<usize>,
     deque: VecDeque<String>,
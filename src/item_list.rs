@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+
+/// The items, the values, the former index of each item and the highlighted item.
+type Parts<'a, T> = (Vec<Cow<'a, str>>, Vec<T>, Vec<Option<usize>>, usize);
+
+/// The items of a list prompt, which can be changed by a key action.
+///
+/// Key actions are registered with the `on_key` method of
+/// [`Select`](crate::Select::on_key) and [`MultiSelect`](crate::MultiSelect::on_key).
+/// Items keep their state, e.g. whether they are checked, when other items are
+/// added or removed, or when they are renamed.
+pub struct ItemList<'a, T = ()> {
+    items: Vec<Cow<'a, str>>,
+    values: Vec<T>,
+    origins: Vec<Option<usize>>,
+    highlighted: usize,
+}
+
+impl<'a, T> ItemList<'a, T> {
+    pub(crate) fn new(items: Vec<Cow<'a, str>>, values: Vec<T>, highlighted: usize) -> Self {
+        ItemList {
+            origins: (0..items.len()).map(Some).collect(),
+            items,
+            values,
+            highlighted,
+        }
+    }
+
+    /// Returns the items, the values, the former index of each item and the
+    /// highlighted item.
+    pub(crate) fn into_parts(self) -> Parts<'a, T> {
+        let highlighted = self.highlighted.min(self.items.len().saturating_sub(1));
+
+        (self.items, self.values, self.origins, highlighted)
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns whether there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the item at `idx`.
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        self.items.get(idx).map(|item| &**item)
+    }
+
+    /// Returns the index of the highlighted item.
+    pub fn highlighted(&self) -> usize {
+        self.highlighted
+    }
+
+    /// Highlights the item at `idx`.
+    pub fn highlight(&mut self, idx: usize) {
+        self.highlighted = idx;
+    }
+
+    /// Changes the text of the item at `idx`, which keeps its value and state.
+    pub fn rename<S: ToString>(&mut self, idx: usize, item: S) {
+        if let Some(text) = self.items.get_mut(idx) {
+            *text = item.to_string().into();
+        }
+    }
+
+    /// Removes the item at `idx` and returns it.
+    pub fn remove(&mut self, idx: usize) -> Option<String> {
+        if idx >= self.items.len() {
+            return None;
+        }
+
+        if idx < self.highlighted {
+            self.highlighted -= 1;
+        }
+
+        self.values.remove(idx);
+        self.origins.remove(idx);
+        Some(self.items.remove(idx).into_owned())
+    }
+
+    /// Adds an item which carries a value at the end.
+    pub fn push_with_value<S: ToString>(&mut self, item: S, value: T) {
+        self.insert_with_value(self.items.len(), item, value);
+    }
+
+    /// Inserts an item which carries a value at `idx`.
+    pub fn insert_with_value<S: ToString>(&mut self, idx: usize, item: S, value: T) {
+        let idx = idx.min(self.items.len());
+
+        if idx <= self.highlighted && !self.items.is_empty() {
+            self.highlighted += 1;
+        }
+
+        self.items.insert(idx, item.to_string().into());
+        self.values.insert(idx, value);
+        self.origins.insert(idx, None);
+    }
+}
+
+impl ItemList<'_> {
+    /// Adds an item at the end.
+    pub fn push<S: ToString>(&mut self, item: S) {
+        self.push_with_value(item, ());
+    }
+
+    /// Inserts an item at `idx`.
+    pub fn insert<S: ToString>(&mut self, idx: usize, item: S) {
+        self.insert_with_value(idx, item, ());
+    }
+}
+
+/// Rearranges the state of the items after a key action, see [`ItemList::into_parts`].
+///
+/// New items get the default state.
+pub(crate) fn rearrange<V: Clone + Default>(states: &[V], origins: &[Option<usize>]) -> Vec<V> {
+    origins
+        .iter()
+        .map(|origin| origin.map_or_else(V::default, |idx| states[idx].clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_list() {
+        let mut list = ItemList::new(vec!["a".into(), "b".into(), "c".into()], vec![(); 3], 1);
+
+        list.remove(0);
+        list.insert(1, "d");
+        list.rename(2, "e");
+        list.push("f");
+
+        assert_eq!(list.highlighted(), 0);
+
+        let (items, _, origins, highlighted) = list.into_parts();
+
+        assert_eq!(items, ["b", "d", "e", "f"]);
+        assert_eq!(origins, [Some(1), None, Some(2), None]);
+        assert_eq!(highlighted, 0);
+        assert_eq!(
+            rearrange(&[true, false, true], &origins),
+            [false, false, true, false]
+        );
+    }
+}
@@ -15,7 +15,9 @@
 //! * Input validation
 //! * Selections prompts (single and multi)
 //! * Fuzzy select prompt
+//! * File picker prompt
 //! * Other kind of prompts
+//! * Sessions which run several prompts as one dialog
 //! * Editor launching
 //!
 //! # Crate Features
@@ -26,6 +28,13 @@
 //! * `history`: enables input prompts to be able to track history of inputs
 //! * `password`: enables password input prompt
 //! * `completion`: enables ability to implement custom tab-completion for input prompts
+//! * `keyring`: enables storing passwords in the keyring of the operating system, implies `password`
+//! * `theme-presets`: enables the Nord, Solarized, Dracula and Catppuccin presets of
+//!   [`ColorfulTheme`](theme::ColorfulTheme)
+//! * `clipboard`: enables pasting the system clipboard with Ctrl-V or Shift-Insert in input
+//!   and password prompts
+//! * `tracing`: emits [tracing](https://docs.rs/tracing) spans and events when prompts are shown,
+//!   keys are pressed, answers are rejected and prompts are answered. Passwords are redacted.
 //!
 //! By default `editor` and `password` are enabled.
 
@@ -39,27 +48,60 @@ pub use completion::Completion;
 #[cfg(feature = "editor")]
 pub use edit::Editor;
 pub use error::{Error, Result};
+pub use frecency::Frecency;
 #[cfg(feature = "history")]
 pub use history::{BasicHistory, History};
-use paging::Paging;
-pub use validate::{InputValidator, PasswordValidator};
+pub use item_list::ItemList;
+pub use number::NumberFormat;
+pub use observe::PromptState;
+pub use paging::{Paging, PagingInfo, ScrollMode};
+pub use session::Session;
+pub use signals::set_cleanup_on_signal;
+pub use translate::{set_translator, Translator};
+#[cfg(feature = "password")]
+pub use validate::PasswordPolicy;
+pub use validate::{
+    all_of, any_of, not, optional, InputValidator, PasswordValidator, ValidatorSet,
+};
 
 #[cfg(feature = "fuzzy-select")]
 pub use prompts::fuzzy_select::FuzzySelect;
 #[cfg(feature = "password")]
 pub use prompts::password::Password;
 pub use prompts::{
-    confirm::Confirm, input::Input, multi_select::MultiSelect, select::Select, sort::Sort,
+    cascade_select::CascadeSelect,
+    confirm::Confirm,
+    currency_input::CurrencyInput,
+    file_picker::{FileOrder, FilePicker},
+    input::Input,
+    multi_select::MultiSelect,
+    range_slider::RangeSlider,
+    select::Select,
+    sort::Sort,
+    text_area::TextArea,
+    Outcome, Prompt, Target,
 };
 
+#[cfg(feature = "clipboard")]
+mod clipboard;
 #[cfg(feature = "completion")]
 mod completion;
 #[cfg(feature = "editor")]
 mod edit;
 mod error;
+mod filter;
+mod frecency;
 #[cfg(feature = "history")]
 mod history;
+mod item_list;
+mod memory;
+mod number;
+mod observe;
 mod paging;
 mod prompts;
+mod session;
+mod signals;
 pub mod theme;
+mod trace;
+mod translate;
 mod validate;
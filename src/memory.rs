@@ -0,0 +1,130 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Remembers the last selection of a prompt in a file.
+///
+/// The file holds one line per prompt id, with the id and the selected items
+/// separated by tabs.
+#[derive(Clone)]
+pub(crate) struct Memory {
+    id: String,
+    path: PathBuf,
+}
+
+impl Memory {
+    pub(crate) fn new<P: AsRef<Path>>(id: String, path: P) -> Self {
+        Memory {
+            id,
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the remembered items, or `None` if nothing was remembered yet.
+    pub(crate) fn recall(&self) -> Option<Vec<String>> {
+        let content = fs::read_to_string(&self.path).ok()?;
+
+        content.lines().map(split).find_map(|mut fields| {
+            if fields.first() == Some(&self.id) {
+                fields.remove(0);
+                Some(fields)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Remembers the selected items.
+    ///
+    /// Errors are ignored, as the memory only provides defaults for the next time.
+    pub(crate) fn remember<S: AsRef<str>>(&self, items: &[S]) {
+        let _ = self.write(items);
+    }
+
+    fn write<S: AsRef<str>>(&self, items: &[S]) -> io::Result<()> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let mut lines: Vec<String> = content
+            .lines()
+            .filter(|line| split(line).first() != Some(&self.id))
+            .map(str::to_owned)
+            .collect();
+
+        let mut line = escape(&self.id);
+        for item in items {
+            line.push('\t');
+            line.push_str(&escape(item.as_ref()));
+        }
+        lines.push(line);
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(&self.path, lines.join("\n") + "\n")
+    }
+}
+
+pub(crate) fn escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+pub(crate) fn split(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars();
+
+    while let Some(chr) = chars.next() {
+        let field = fields.last_mut().unwrap();
+
+        match chr {
+            '\t' => fields.push(String::new()),
+            '\\' => match chars.next() {
+                Some('t') => field.push('\t'),
+                Some('n') => field.push('\n'),
+                Some(chr) => field.push(chr),
+                None => field.push('\\'),
+            },
+            chr => field.push(chr),
+        }
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        let fields = ["a\tb", "c\nd", "e\\f", ""];
+        let line = fields.map(escape).join("\t");
+
+        assert_eq!(split(&line), fields);
+    }
+
+    #[test]
+    fn test_remember() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("memory");
+        let region = Memory::new("region".into(), &path);
+        let tags = Memory::new("tags".into(), &path);
+
+        assert_eq!(region.recall(), None);
+
+        region.remember(&["eu-west-1"]);
+        tags.remember(&["a", "b"]);
+        region.remember(&["us-east-1"]);
+
+        assert_eq!(region.recall(), Some(vec!["us-east-1".to_owned()]));
+        assert_eq!(tags.recall(), Some(vec!["a".to_owned(), "b".to_owned()]));
+    }
+}
@@ -0,0 +1,143 @@
+/// The separators of numbers typed into an [`Input`](crate::Input::number_format).
+///
+/// Numbers are typed and shown with the separators of the format, e.g. "1.234,56"
+/// with a decimal comma, and parsed like "1234.56". The default is a decimal point
+/// and a comma to group thousands, like "1,234.56".
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{Input, NumberFormat};
+///
+/// fn main() {
+///     let price: f64 = Input::new()
+///         .with_prompt("Preis")
+///         .number_format(
+///             NumberFormat::new()
+///                 .decimal_separator(',')
+///                 .grouping_separator(Some('.')),
+///         )
+///         .interact_text()
+///         .unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub(crate) decimal_separator: char,
+    pub(crate) grouping_separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Creates the format with a decimal point and a comma to group thousands.
+    pub fn new() -> Self {
+        NumberFormat {
+            decimal_separator: '.',
+            grouping_separator: Some(','),
+        }
+    }
+
+    /// Sets the character which separates the fraction, e.g. ',' in many European locales.
+    pub fn decimal_separator(mut self, val: char) -> Self {
+        self.decimal_separator = val;
+        self
+    }
+
+    /// Sets the character which groups thousands, or `None` to not group them.
+    ///
+    /// The separator may be typed anywhere in a number, it is ignored when parsing.
+    pub fn grouping_separator(mut self, val: Option<char>) -> Self {
+        self.grouping_separator = val;
+        self
+    }
+
+    /// Converts a number typed in this format to the format of `FromStr`, e.g.
+    /// "1.234,56" to "1234.56".
+    pub fn normalize(&self, text: &str) -> String {
+        text.trim()
+            .chars()
+            .filter(|&chr| Some(chr) != self.grouping_separator)
+            .map(|chr| {
+                if chr == self.decimal_separator {
+                    '.'
+                } else {
+                    chr
+                }
+            })
+            .collect()
+    }
+
+    /// Converts a number in the format of `Display` to this format, e.g. "1234.56"
+    /// to "1.234,56".
+    ///
+    /// Text which is not a plain decimal number, e.g. "NaN" or "1e9", is returned as it is.
+    pub fn format(&self, text: &str) -> String {
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", text),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let is_digits = |part: &str| part.chars().all(|chr| chr.is_ascii_digit());
+        if integer.is_empty() || !is_digits(integer) || !fraction.map_or(true, is_digits) {
+            return text.to_string();
+        }
+
+        let mut formatted = sign.to_string();
+        for (idx, digit) in integer.chars().enumerate() {
+            if idx > 0 && (integer.len() - idx) % 3 == 0 {
+                formatted.extend(self.grouping_separator);
+            }
+            formatted.push(digit);
+        }
+
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal_separator);
+            formatted.push_str(fraction);
+        }
+
+        formatted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let german = NumberFormat::new()
+            .decimal_separator(',')
+            .grouping_separator(Some('.'));
+
+        assert_eq!(german.normalize(" 1.234,56 "), "1234.56");
+        assert_eq!(NumberFormat::new().normalize("-1,234.5"), "-1234.5");
+    }
+
+    #[test]
+    fn test_format() {
+        let german = NumberFormat::new()
+            .decimal_separator(',')
+            .grouping_separator(Some('.'));
+
+        assert_eq!(german.format("1234.56"), "1.234,56");
+        assert_eq!(NumberFormat::new().format("-1234567"), "-1,234,567");
+        assert_eq!(NumberFormat::new().format("123"), "123");
+        assert_eq!(NumberFormat::new().format("NaN"), "NaN");
+        assert_eq!(NumberFormat::new().format("1e9"), "1e9");
+        assert_eq!(
+            NumberFormat::new()
+                .grouping_separator(None)
+                .format("1234.5"),
+            "1234.5"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+
+use console::Key;
+
+use crate::trace;
+
+type RenderObserver<'a> = Arc<Mutex<dyn FnMut(PromptState) + 'a>>;
+type KeyObserver<'a> = Arc<Mutex<dyn FnMut(&Key, PromptState) + 'a>>;
+
+/// The state of a prompt, which is passed to its observers.
+///
+/// Observers are registered with the `on_render` and `on_key_event` methods of the
+/// prompts, e.g. for logging or to mirror the prompt in another user interface.
+/// Indices refer to the items in the order they were added.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PromptState<'s> {
+    /// The current answer of a confirm prompt, if any.
+    Confirm { value: Option<bool> },
+    /// The text of an input prompt and the position of the cursor in characters.
+    Input { text: &'s str, cursor: usize },
+    /// The number of characters entered into a password prompt.
+    Password { len: usize },
+    /// The highlighted item of a select prompt, if any.
+    Select { highlighted: Option<usize> },
+    /// The highlighted item of a multi select prompt, if any, and the state of the items.
+    MultiSelect {
+        highlighted: Option<usize>,
+        checked: &'s [bool],
+    },
+    /// The highlighted position of a sort prompt, if any, and the current order of the items.
+    Sort {
+        highlighted: Option<usize>,
+        order: &'s [usize],
+    },
+    /// The selected range of a range slider and whether the handle of `low` is active.
+    RangeSlider {
+        low: i64,
+        high: i64,
+        low_active: bool,
+    },
+    /// The search text of a fuzzy select prompt, the position of the cursor in
+    /// characters and the highlighted item, if any.
+    FuzzySelect {
+        text: &'s str,
+        cursor: usize,
+        highlighted: Option<usize>,
+    },
+}
+
+/// The observers of a prompt.
+#[derive(Clone, Default)]
+pub(crate) struct Observers<'a> {
+    render: Option<RenderObserver<'a>>,
+    key: Option<KeyObserver<'a>>,
+    redacted: bool,
+}
+
+impl<'a> Observers<'a> {
+    pub(crate) fn set_render<F: FnMut(PromptState) + 'a>(&mut self, observer: F) {
+        self.render = Some(Arc::new(Mutex::new(observer)));
+    }
+
+    pub(crate) fn set_key<F: FnMut(&Key, PromptState) + 'a>(&mut self, observer: F) {
+        self.key = Some(Arc::new(Mutex::new(observer)));
+    }
+
+    /// Redacts the characters typed into the prompt in traces.
+    #[cfg(feature = "password")]
+    pub(crate) fn redact(&mut self) {
+        self.redacted = true;
+    }
+
+    pub(crate) fn is_redacted(&self) -> bool {
+        self.redacted
+    }
+
+    /// Notifies the observer that the prompt was rendered.
+    pub(crate) fn rendered(&self, state: PromptState) {
+        if let Some(ref observer) = self.render {
+            observer.lock().unwrap()(state);
+        }
+    }
+
+    /// Notifies the observer that a key was pressed, before the prompt handles it.
+    pub(crate) fn key_pressed(&self, key: &Key, state: PromptState) {
+        let redact = self.redacted || matches!(state, PromptState::Password { .. });
+        trace::key_pressed(key, redact);
+
+        if let Some(ref observer) = self.key {
+            observer.lock().unwrap()(key, state);
+        }
+    }
+}
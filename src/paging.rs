@@ -2,30 +2,64 @@ use console::Term;
 
 use crate::Result;
 
+/// How a list which doesn't fit on the terminal is scrolled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollMode {
+    /// Flips whole pages, showing the page number next to the prompt.
+    #[default]
+    Page,
+    /// Slides the visible window line by line with the cursor, showing the
+    /// position of the cursor next to the prompt.
+    Window,
+}
+
+/// The position in a scrolled list, which is shown next to the prompt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PagingInfo {
+    /// The current page, starting at 1, the number of pages and the number of items.
+    Page {
+        page: usize,
+        pages: usize,
+        items: usize,
+    },
+    /// The item at the cursor, starting at 1, and the number of items.
+    Window { item: usize, items: usize },
+}
+
 /// Creates a paging module
 ///
 /// The paging module serves as tracking structure to allow paged views
 /// and automatically (de-)activates paging depending on the current terminal size.
+///
+/// It can be used to build custom list prompts which page like the prompts of
+/// this crate, see [`TermThemeRenderer`](crate::theme::render::TermThemeRenderer).
 pub struct Paging<'a> {
+    /// The number of pages.
     pub pages: usize,
+    /// The index of the current page.
     pub current_page: usize,
+    /// The number of items on a page.
     pub capacity: usize,
+    /// Whether the items are paged, i.e. whether there is more than one page.
     pub active: bool,
+    /// The maximum number of lines of the prompt, including two lines for the prompt and paging info.
     pub max_capacity: Option<usize>,
     term: &'a Term,
     current_term_size: (u16, u16),
     items_len: usize,
+    reserved_lines: usize,
+    scroll_mode: ScrollMode,
+    scroll_margin: usize,
+    cursor_pos: usize,
+    first_item: usize,
     activity_transition: bool,
 }
 
 impl<'a> Paging<'a> {
+    /// Creates a paging module for `items_len` items on the given terminal.
     pub fn new(term: &'a Term, items_len: usize, max_capacity: Option<usize>) -> Paging<'a> {
         let term_size = term.size();
-        // Subtract -2 because we need space to render the prompt, if paging is active
-        let capacity = max_capacity
-            .unwrap_or(std::usize::MAX)
-            .clamp(3, term_size.0 as usize)
-            - 2;
+        let capacity = Self::compute_capacity(max_capacity, term_size.0, 0);
         let pages = (items_len as f64 / capacity as f64).ceil() as usize;
 
         Paging {
@@ -37,13 +71,96 @@ impl<'a> Paging<'a> {
             current_term_size: term_size,
             items_len,
             max_capacity,
+            reserved_lines: 0,
+            scroll_mode: ScrollMode::Page,
+            scroll_margin: 0,
+            cursor_pos: 0,
+            first_item: 0,
             // Set transition initially to true to trigger prompt rendering for inactive paging on start
             activity_transition: true,
         }
     }
 
+    fn compute_capacity(
+        max_capacity: Option<usize>,
+        term_rows: u16,
+        reserved_lines: usize,
+    ) -> usize {
+        // Subtract -2 because we need space to render the prompt, if paging is active
+        let rows = (term_rows as usize).saturating_sub(reserved_lines).max(3);
+
+        max_capacity.unwrap_or(usize::MAX).clamp(3, rows) - 2
+    }
+
+    /// Reserves lines of the terminal which are rendered in addition to the items,
+    /// e.g. a filter line, so that they are not available for items.
+    pub fn reserve_lines(&mut self, lines: usize) {
+        self.reserved_lines = lines;
+        self.capacity = Self::compute_capacity(self.max_capacity, self.current_term_size.0, lines);
+        self.pages = (self.items_len as f64 / self.capacity as f64).ceil() as usize;
+        self.active = self.pages > 1;
+    }
+
+    /// Sets how the items are scrolled, by flipping pages by default.
+    pub fn set_scroll_mode(&mut self, mode: ScrollMode) {
+        self.scroll_mode = mode;
+    }
+
+    /// Sets the number of items kept visible above and below the cursor in
+    /// [`ScrollMode::Window`].
+    ///
+    /// The window scrolls as soon as the cursor comes within `margin` items of its
+    /// top or bottom edge, so that the items around the cursor stay visible.
+    pub fn set_scroll_margin(&mut self, margin: usize) {
+        self.scroll_margin = margin;
+    }
+
+    /// Returns the index of the first visible item.
+    pub fn first_item(&self) -> usize {
+        match self.scroll_mode {
+            ScrollMode::Page => self.current_page * self.capacity,
+            ScrollMode::Window => self.first_item,
+        }
+    }
+
+    /// Changes the number of items to be paged, e.g. after the items were filtered.
+    ///
+    /// This navigates back to the first page and triggers rendering the prompt again,
+    /// so the whole prompt should be cleared afterwards.
+    pub fn set_items_len(&mut self, items_len: usize) {
+        self.items_len = items_len;
+        self.pages = (items_len as f64 / self.capacity as f64).ceil() as usize;
+        self.current_page = 0;
+        self.cursor_pos = 0;
+        self.first_item = 0;
+        self.active = self.pages > 1;
+        self.activity_transition = true;
+    }
+
+    /// Navigates to the page of the item at `cursor_pos`.
+    ///
+    /// In [`ScrollMode::Window`] the window scrolls just as far as needed instead.
+    /// A position of `!0` stands for no item and keeps the current page.
     pub fn update_page(&mut self, cursor_pos: usize) {
-        if cursor_pos != !0
+        if self.scroll_mode == ScrollMode::Window {
+            if cursor_pos != !0 {
+                // The margin can't keep the cursor away from both edges of a small window
+                let margin = self.scroll_margin.min(self.capacity.saturating_sub(1) / 2);
+
+                if cursor_pos < self.first_item + margin {
+                    self.first_item = cursor_pos.saturating_sub(margin);
+                } else if cursor_pos + margin >= self.first_item + self.capacity {
+                    self.first_item = cursor_pos + margin + 1 - self.capacity;
+                }
+
+                self.current_page = cursor_pos / self.capacity;
+                self.cursor_pos = cursor_pos;
+            }
+
+            self.first_item = self
+                .first_item
+                .min(self.items_len.saturating_sub(self.capacity));
+        } else if cursor_pos != !0
             && (cursor_pos < self.current_page * self.capacity
                 || cursor_pos >= (self.current_page + 1) * self.capacity)
         {
@@ -57,11 +174,11 @@ impl<'a> Paging<'a> {
 
         if self.current_term_size != new_term_size {
             self.current_term_size = new_term_size;
-            self.capacity = self
-                .max_capacity
-                .unwrap_or(std::usize::MAX)
-                .clamp(3, self.current_term_size.0 as usize)
-                - 2;
+            self.capacity = Self::compute_capacity(
+                self.max_capacity,
+                self.current_term_size.0,
+                self.reserved_lines,
+            );
             self.pages = (self.items_len as f64 / self.capacity as f64).ceil() as usize;
         }
 
@@ -82,13 +199,25 @@ impl<'a> Paging<'a> {
     /// Renders a prompt when the following conditions are met:
     /// * Paging is active
     /// * Transition of the paging activity happened (active -> inactive / inactive -> active)
+    ///
+    /// The paging info depends on the [`ScrollMode`].
     pub fn render_prompt<F>(&mut self, mut render_prompt: F) -> Result
     where
-        F: FnMut(Option<(usize, usize)>) -> Result,
+        F: FnMut(Option<PagingInfo>) -> Result,
     {
         if self.active {
-            let paging_info = Some((self.current_page + 1, self.pages));
-            render_prompt(paging_info)?;
+            let paging_info = match self.scroll_mode {
+                ScrollMode::Page => PagingInfo::Page {
+                    page: self.current_page + 1,
+                    pages: self.pages,
+                    items: self.items_len,
+                },
+                ScrollMode::Window => PagingInfo::Window {
+                    item: self.cursor_pos + 1,
+                    items: self.items_len,
+                },
+            };
+            render_prompt(Some(paging_info))?;
         } else if self.activity_transition {
             render_prompt(None)?;
         }
@@ -119,4 +248,18 @@ impl<'a> Paging<'a> {
 
         self.current_page * self.capacity
     }
+
+    /// Navigates to the first page
+    pub fn first_page(&mut self) -> usize {
+        self.current_page = 0;
+
+        0
+    }
+
+    /// Navigates to the last page
+    pub fn last_page(&mut self) -> usize {
+        self.current_page = self.pages.saturating_sub(1);
+
+        self.current_page * self.capacity
+    }
 }
@@ -0,0 +1,330 @@
+use std::sync::{Arc, Mutex};
+
+use console::{Key, Term};
+
+use crate::{
+    observe::{Observers, PromptState},
+    prompts::{Outcome, Prompt, Target},
+    signals::Cleanup,
+    theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    trace, Error, Result, Select,
+};
+
+type CascadeItemsCallback<'a> = Arc<Mutex<dyn FnMut(&[String]) -> Vec<String> + 'a>>;
+
+/// Renders select prompts which depend on each other, like country, region and city.
+///
+/// Each [`level`](Self::level) lists the items for the values chosen on the levels
+/// before. 'Enter' chooses an item and goes to the next level, 'Left' or 'Backspace'
+/// go back a level. The chosen values are shown as breadcrumbs above the items.
+/// Returns the chosen value of every level. A level without items ends the cascade
+/// early, so the levels may be nested to different depths.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::CascadeSelect;
+///
+/// fn main() {
+///     let path = CascadeSelect::new()
+///         .with_prompt("Location")
+///         .level("Country", |_| vec!["Austria", "Germany"])
+///         .level("City", |path| match path[0].as_str() {
+///             "Austria" => vec!["Graz", "Vienna"],
+///             _ => vec!["Berlin", "Hamburg", "Munich"],
+///         })
+///         .interact()
+///         .unwrap();
+///
+///     println!("You chose {}", path.join(", "));
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CascadeSelect<'a> {
+    prompt: Option<String>,
+    levels: Vec<(String, CascadeItemsCallback<'a>)>,
+    report: bool,
+    max_length: Option<usize>,
+    output: Target,
+    observers: Observers<'a>,
+    theme: &'a dyn Theme,
+}
+
+impl Default for CascadeSelect<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CascadeSelect<'static> {
+    /// Creates a cascading select prompt with default theme.
+    pub fn new() -> Self {
+        Self::with_theme(&SimpleTheme)
+    }
+}
+
+impl CascadeSelect<'_> {
+    /// Sets the prompt which reports the chosen values after interaction.
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Indicates whether to report the chosen values after interaction.
+    ///
+    /// The default is to report the chosen values if there is a
+    /// [prompt](Self::with_prompt).
+    pub fn report(mut self, val: bool) -> Self {
+        self.report = val;
+        self
+    }
+
+    /// Sets an optional max length for a page of items.
+    pub fn max_length(mut self, val: usize) -> Self {
+        self.max_length = Some(val);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Enables user interaction and returns the chosen value of every level.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
+    #[inline]
+    pub fn interact(self) -> Result<Vec<String>> {
+        let term = self.output.term()?;
+        self.interact_on(&term)
+    }
+
+    /// Enables user interaction and returns the chosen value of every level.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// Result contains `Some(values)` if the user chose an item on the last level
+    /// or `None` if the user cancelled with 'Esc' or 'q'.
+    #[inline]
+    pub fn interact_opt(self) -> Result<Option<Vec<String>>> {
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
+    }
+
+    /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on(self, term: &Term) -> Result<Vec<String>> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(self, term: &Term) -> Result<Option<Vec<String>>> {
+        self._interact_on(term, true)
+    }
+
+    fn _interact_on(&self, term: &Term, allow_quit: bool) -> Result<Option<Vec<String>>> {
+        if !term.is_term() {
+            return Err(Error::NotATerminal);
+        }
+
+        let _span = trace::shown("cascade_select", self.prompt.as_deref());
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact(term, allow_quit), |path| {
+            Some(path.join(" > "))
+        })
+    }
+
+    fn _interact(&self, term: &Term, allow_quit: bool) -> Result<Option<Vec<String>>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        // The chosen values, and the items of the levels up to the current one
+        let mut path: Vec<String> = vec![];
+        let mut levels = vec![self.items(&path)];
+
+        if levels[0].is_empty() {
+            return Err(Error::EmptyItems);
+        }
+
+        while let Some(items) = levels.last() {
+            let level = levels.len() - 1;
+            // Highlights the value chosen before going back to this level, if any
+            let default = path
+                .get(level)
+                .and_then(|value| items.iter().position(|item| item == value))
+                .unwrap_or(0);
+
+            let mut select = Select::with_theme(self.theme)
+                .with_prompt(self.levels[level].0.as_str())
+                .report(false)
+                .default(default)
+                .items(items)
+                .watch_keys([Key::ArrowLeft, Key::Backspace]);
+            select.observers = self.observers.clone();
+
+            if let Some(max_length) = self.max_length {
+                select = select.max_length(max_length);
+            }
+
+            if !allow_quit {
+                select = select.abort_keys([]);
+            }
+
+            if level > 0 {
+                let segments: Vec<&str> = path[..level].iter().map(String::as_str).collect();
+                render.breadcrumbs(&segments, None)?;
+            }
+
+            let outcome = select.interact_outcome_on(term);
+            render.clear()?;
+
+            match outcome? {
+                Outcome::Answered(idx) => {
+                    path.truncate(level);
+                    path.push(items[idx].clone());
+
+                    let next = if path.len() < self.levels.len() {
+                        self.items(&path)
+                    } else {
+                        vec![]
+                    };
+
+                    if next.is_empty() {
+                        break;
+                    }
+
+                    levels.push(next);
+                }
+                Outcome::Key { .. } if level > 0 => {
+                    levels.pop();
+                }
+                Outcome::Key { .. } => {}
+                Outcome::Cancelled => return Ok(None),
+            }
+        }
+
+        if let (Some(prompt), true) = (&self.prompt, self.report) {
+            render.select_prompt_selection(prompt, &path.join(" > "))?;
+        }
+
+        Ok(Some(path))
+    }
+
+    /// Returns the items of the level after the chosen values of `path`.
+    fn items(&self, path: &[String]) -> Vec<String> {
+        match self.levels.get(path.len()) {
+            Some((_, items)) => items.lock().unwrap()(path),
+            None => vec![],
+        }
+    }
+}
+
+impl<'a> CascadeSelect<'a> {
+    /// Creates a cascading select prompt with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{theme::ColorfulTheme, CascadeSelect};
+    ///
+    /// fn main() {
+    ///     let path = CascadeSelect::with_theme(&ColorfulTheme::default())
+    ///         .level("Category", |_| vec!["Fruit", "Vegetable"])
+    ///         .level("Item", |path| match path[0].as_str() {
+    ///             "Fruit" => vec!["Apple", "Banana"],
+    ///             _ => vec!["Carrot", "Pea"],
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self {
+            prompt: None,
+            levels: vec![],
+            report: true,
+            max_length: None,
+            output: Target::default(),
+            observers: Observers::default(),
+            theme,
+        }
+    }
+
+    /// Adds a level with a prompt and a function which returns its items.
+    ///
+    /// The function is called with the values chosen on the levels before, so it
+    /// gets an empty slice on the first level.
+    pub fn level<S, F, I, T>(mut self, prompt: S, mut items: F) -> Self
+    where
+        S: Into<String>,
+        F: FnMut(&[String]) -> I + 'a,
+        I: IntoIterator<Item = T>,
+        T: ToString,
+    {
+        self.levels.push((
+            prompt.into(),
+            Arc::new(Mutex::new(move |path: &[String]| {
+                items(path)
+                    .into_iter()
+                    .map(|item| item.to_string())
+                    .collect()
+            })),
+        ));
+        self
+    }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    ///
+    /// The state is the one of the select prompt listing the items of the current level.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    ///
+    /// The state is the one of the select prompt listing the items of the current level.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for CascadeSelect<'_> {
+    type Output = Vec<String>;
+
+    fn interact_on(&mut self, term: &Term) -> Result<Vec<String>> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    fn interact(&mut self) -> Result<Vec<String>> {
+        let term = self.output.term()?;
+        Prompt::interact_on(self, &term)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        let select = CascadeSelect::new()
+            .level("Country", |_| ["Austria", "Germany"])
+            .level("City", |path: &[String]| match path[0].as_str() {
+                "Austria" => vec!["Graz", "Vienna"],
+                _ => vec![],
+            });
+
+        assert_eq!(select.items(&[]), ["Austria", "Germany"]);
+        assert_eq!(select.items(&["Austria".into()]), ["Graz", "Vienna"]);
+        assert!(select.items(&["Germany".into()]).is_empty());
+        assert!(select.items(&["Austria".into(), "Graz".into()]).is_empty());
+    }
+}
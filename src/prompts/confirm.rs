@@ -1,10 +1,11 @@
-use std::io;
-
 use console::{Key, Term};
 
 use crate::{
+    observe::{Observers, PromptState},
+    prompts::{AbortKeys, Prompt, Target, CTRL_D, CTRL_Z},
+    signals::{suspend, Cleanup},
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
-    Result,
+    trace, Error, Result,
 };
 
 /// Renders a confirm prompt.
@@ -29,11 +30,16 @@ use crate::{
 /// ```
 #[derive(Clone)]
 pub struct Confirm<'a> {
-    prompt: String,
+    pub(crate) prompt: String,
     report: bool,
     default: Option<bool>,
     show_default: bool,
     wait_for_newline: bool,
+    remember_option: Option<String>,
+    bell: bool,
+    pub(crate) abort_keys: AbortKeys,
+    output: Target,
+    observers: Observers<'a>,
     theme: &'a dyn Theme,
 }
 
@@ -79,6 +85,32 @@ impl Confirm<'_> {
         self
     }
 
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc', 'q' and Ctrl-D.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
     /// Sets a default.
     ///
     /// Out of the box the prompt does not have a default and will continue
@@ -97,20 +129,46 @@ impl Confirm<'_> {
         self
     }
 
+    /// Adds a checkbox below the question, e.g. "Don't ask me again".
+    ///
+    /// 'Space' toggles the checkbox, 'y' and 'n' choose the answer and 'Enter'
+    /// confirms it, like with [`wait_for_newline`](Self::wait_for_newline). Use
+    /// [`interact_remember`](Self::interact_remember) to get whether the checkbox was
+    /// checked.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Confirm;
+    ///
+    /// fn main() {
+    ///     let (delete, dont_ask_again) = Confirm::new()
+    ///         .with_prompt("Delete the branch?")
+    ///         .with_remember_option("Don't ask me again")
+    ///         .interact_remember()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn with_remember_option<S: Into<String>>(mut self, label: S) -> Self {
+        self.remember_option = Some(label.into());
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     ///
     /// Result contains `bool` if user answered "yes" or "no" or `default` (configured in [`default`](Self::default) if pushes enter.
     /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
     #[inline]
     pub fn interact(self) -> Result<bool> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
     }
 
     /// Enables user interaction and returns the result.
     ///
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     ///
     /// Result contains `Some(bool)` if user answered "yes" or "no" or `Some(default)` (configured in [`default`](Self::default)) if pushes enter,
     /// or `None` if user cancelled with 'Esc' or 'q'.
@@ -133,15 +191,14 @@ impl Confirm<'_> {
     /// ```
     #[inline]
     pub fn interact_opt(self) -> Result<Option<bool>> {
-        self.interact_on_opt(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
     #[inline]
     pub fn interact_on(self, term: &Term) -> Result<bool> {
-        Ok(self
-            ._interact_on(term, false)?
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))?)
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
     }
 
     /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
@@ -150,11 +207,135 @@ impl Confirm<'_> {
         self._interact_on(term, true)
     }
 
+    /// Like [`interact`](Self::interact) but also returns whether the checkbox of the
+    /// [`remember option`](Self::with_remember_option) was checked.
+    ///
+    /// Without a remember option the prompt behaves like `interact` and the second
+    /// value is always `false`.
+    #[inline]
+    pub fn interact_remember(self) -> Result<(bool, bool)> {
+        let term = self.output.term()?;
+        self.interact_remember_on(&term)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but also returns whether the checkbox
+    /// of the [`remember option`](Self::with_remember_option) was checked.
+    ///
+    /// Without a remember option the prompt behaves like `interact_opt` and the second
+    /// value is always `false`.
+    #[inline]
+    pub fn interact_remember_opt(self) -> Result<Option<(bool, bool)>> {
+        let term = self.output.term()?;
+        self.interact_remember_on_opt(&term)
+    }
+
+    /// Like [`interact_remember`](Self::interact_remember) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_remember_on(self, term: &Term) -> Result<(bool, bool)> {
+        self._interact_remember_on(term, false)?
+            .ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_remember_opt`](Self::interact_remember_opt) but allows a specific
+    /// terminal to be set.
+    #[inline]
+    pub fn interact_remember_on_opt(self, term: &Term) -> Result<Option<(bool, bool)>> {
+        self._interact_remember_on(term, true)
+    }
+
     fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<bool>> {
+        Ok(self
+            ._interact_remember_on(term, allow_quit)?
+            .map(|(value, _)| value))
+    }
+
+    fn _interact_remember_on(self, term: &Term, allow_quit: bool) -> Result<Option<(bool, bool)>> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
         }
 
+        let _span = trace::shown("confirm", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+
+        let answer = match self.remember_option {
+            Some(ref label) => self._interact_remember(term, allow_quit, label),
+            None => self
+                ._interact(term, allow_quit)
+                .map(|value| value.map(|value| (value, false))),
+        };
+
+        trace::answered(answer, |(value, remember)| {
+            Some(format!("{} (remember: {})", value, remember))
+        })
+    }
+
+    /// Like `_interact` but renders the checkbox of the remember option below the
+    /// question and waits for 'Enter'.
+    fn _interact_remember(
+        &self,
+        term: &Term,
+        allow_quit: bool,
+        label: &str,
+    ) -> Result<Option<(bool, bool)>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut value = if self.show_default {
+            self.default
+        } else {
+            None
+        };
+        let mut remember = false;
+
+        term.hide_cursor()?;
+
+        let rv = loop {
+            render.confirm_prompt(&self.prompt, value)?;
+            render.confirm_remember_option(label, remember)?;
+            term.flush()?;
+            self.observers.rendered(PromptState::Confirm { value });
+
+            let input = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers
+                .key_pressed(&input, PromptState::Confirm { value });
+
+            match input {
+                key if key == CTRL_Z && cfg!(unix) => {
+                    term.clear_line()?;
+                    render.clear()?;
+                    suspend(term)?;
+                }
+                Key::Char('y') | Key::Char('Y') => value = Some(true),
+                Key::Char('n') | Key::Char('N') => value = Some(false),
+                Key::Char(' ') => remember = !remember,
+                Key::Enter => match value.or(self.default) {
+                    Some(value) => break Some((value, remember)),
+                    None if self.bell => render.bell()?,
+                    None => {}
+                },
+                key if allow_quit && self.abort_keys.contains(&key) => break None,
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+
+            term.clear_line()?;
+            render.clear()?;
+        };
+
+        term.clear_line()?;
+        render.clear()?;
+        if self.report {
+            render.confirm_prompt_selection(&self.prompt, rv.map(|(value, _)| value))?;
+        }
+        term.show_cursor()?;
+        term.flush()?;
+
+        Ok(rv)
+    }
+
+    fn _interact(self, term: &Term, allow_quit: bool) -> Result<Option<bool>> {
         let mut render = TermThemeRenderer::new(term, self.theme);
 
         let default_if_show = if self.show_default {
@@ -167,6 +348,9 @@ impl Confirm<'_> {
 
         term.hide_cursor()?;
         term.flush()?;
+        self.observers.rendered(PromptState::Confirm {
+            value: default_if_show,
+        });
 
         let rv;
 
@@ -176,9 +360,15 @@ impl Confirm<'_> {
             let mut value = default_if_show;
 
             loop {
-                let input = term.read_key()?;
+                let input = self.abort_keys.read_key(term, allow_quit)?;
+                self.observers
+                    .key_pressed(&input, PromptState::Confirm { value });
 
                 match input {
+                    key if key == CTRL_Z && cfg!(unix) => {
+                        term.clear_line()?;
+                        suspend(term)?;
+                    }
                     Key::Char('y') | Key::Char('Y') => {
                         value = Some(true);
                     }
@@ -194,30 +384,54 @@ impl Confirm<'_> {
                             rv = value;
                             break;
                         }
+
+                        if self.bell {
+                            render.bell()?;
+                        }
                         continue;
                     }
-                    Key::Escape | Key::Char('q') if allow_quit => {
+                    key if allow_quit && self.abort_keys.contains(&key) => {
                         value = None;
                     }
                     _ => {
+                        if self.bell {
+                            render.bell()?;
+                        }
                         continue;
                     }
                 };
 
                 term.clear_line()?;
                 render.confirm_prompt(&self.prompt, value)?;
+                self.observers.rendered(PromptState::Confirm { value });
             }
         } else {
             // Default behavior: matches continuously on every keystroke,
             // and does not wait for user to hit the Enter key.
             loop {
-                let input = term.read_key()?;
+                let input = self.abort_keys.read_key(term, allow_quit)?;
+                self.observers.key_pressed(
+                    &input,
+                    PromptState::Confirm {
+                        value: default_if_show,
+                    },
+                );
+
                 let value = match input {
+                    key if key == CTRL_Z && cfg!(unix) => {
+                        term.clear_line()?;
+                        suspend(term)?;
+                        render.confirm_prompt(&self.prompt, default_if_show)?;
+                        continue;
+                    }
                     Key::Char('y') | Key::Char('Y') => Some(true),
                     Key::Char('n') | Key::Char('N') => Some(false),
                     Key::Enter if self.default.is_some() => Some(self.default.unwrap()),
-                    Key::Escape | Key::Char('q') if allow_quit => None,
+                    key if allow_quit && self.abort_keys.contains(&key) => None,
                     _ => {
+                        if self.bell {
+                            render.bell()?;
+                        }
                         continue;
                     }
                 };
@@ -259,9 +473,40 @@ impl<'a> Confirm<'a> {
             default: None,
             show_default: true,
             wait_for_newline: false,
+            remember_option: None,
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, Key::Char('q'), CTRL_D]),
+            output: Target::default(),
+            observers: Observers::default(),
             theme,
         }
     }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for Confirm<'_> {
+    type Output = bool;
+
+    fn interact_on(&mut self, term: &Term) -> Result<bool> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<bool> {
+        self.clone().interact()
+    }
 }
 
 #[cfg(test)]
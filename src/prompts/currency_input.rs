@@ -0,0 +1,457 @@
+use console::{Key, Term};
+
+use crate::{
+    observe::{Observers, PromptState},
+    prompts::{AbortKeys, Prompt, Target, CTRL_D},
+    signals::Cleanup,
+    theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    trace, Error, NumberFormat, Result,
+};
+
+/// Renders a prompt for an amount of money.
+///
+/// The amount is shown with the currency symbol and grouped thousands while it's
+/// typed, e.g. "$1,234.50". Only digits and up to the configured decimal places
+/// can be entered. The amount is returned in minor units, e.g. cents, to avoid the
+/// rounding errors of floats.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::CurrencyInput;
+///
+/// fn main() {
+///     let cents = CurrencyInput::new()
+///         .with_prompt("Price")
+///         .interact()
+///         .unwrap();
+///
+///     println!("The price is {}.{:02}", cents / 100, cents % 100);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CurrencyInput<'a> {
+    prompt: String,
+    report: bool,
+    default: Option<u64>,
+    symbol: String,
+    symbol_after: bool,
+    decimal_places: u32,
+    number_format: NumberFormat,
+    bell: bool,
+    abort_keys: AbortKeys,
+    output: Target,
+    observers: Observers<'a>,
+    theme: &'a dyn Theme,
+}
+
+impl Default for CurrencyInput<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CurrencyInput<'static> {
+    /// Creates a currency input prompt with default theme.
+    pub fn new() -> Self {
+        Self::with_theme(&SimpleTheme)
+    }
+}
+
+impl CurrencyInput<'_> {
+    /// Sets the currency input prompt.
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Indicates whether to report the amount after interaction.
+    ///
+    /// The default is to report the amount.
+    pub fn report(mut self, val: bool) -> Self {
+        self.report = val;
+        self
+    }
+
+    /// Sets a default in minor units, which is accepted with 'Enter' on an empty input.
+    pub fn default(mut self, val: u64) -> Self {
+        self.default = Some(val);
+        self
+    }
+
+    /// Sets the currency symbol, which is shown before the amount.
+    ///
+    /// The default is "$".
+    pub fn symbol<S: Into<String>>(mut self, symbol: S) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Indicates whether the symbol is shown after the amount instead of before it.
+    ///
+    /// The symbol is appended as it is, so it should contain a leading space if
+    /// one is wanted, e.g. " €".
+    pub fn symbol_after(mut self, val: bool) -> Self {
+        self.symbol_after = val;
+        self
+    }
+
+    /// Sets the number of decimal places, which determines the minor units.
+    ///
+    /// The default is 2, e.g. cents. With 0 no decimal separator can be entered.
+    pub fn decimal_places(mut self, val: u32) -> Self {
+        self.decimal_places = val;
+        self
+    }
+
+    /// Sets the separators of the amount.
+    ///
+    /// The default is a decimal point and a comma to group thousands. The grouping
+    /// separator can be typed, but is ignored.
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = format;
+        self
+    }
+
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning. If [`Key::CtrlC`]
+    /// is in the list, Ctrl-C cancels the prompt instead of interrupting the process.
+    /// The default is 'Esc' and Ctrl-D, which only cancels on an empty input.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Enables user interaction and returns the amount in minor units.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc'.
+    pub fn interact(self) -> Result<u64> {
+        let term = self.output.term()?;
+        self.interact_on(&term)
+    }
+
+    /// Enables user interaction and returns the amount in minor units.
+    ///
+    /// Result contains `Some(amount)` if the user entered an amount or `None` if
+    /// the user cancelled with 'Esc'.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    pub fn interact_opt(self) -> Result<Option<u64>> {
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
+    }
+
+    /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
+    pub fn interact_on(self, term: &Term) -> Result<u64> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
+    pub fn interact_on_opt(self, term: &Term) -> Result<Option<u64>> {
+        self._interact_on(term, true)
+    }
+
+    /// Like `interact_on` but allows to cancel the prompt with 'Esc' if `allow_quit` is set.
+    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<u64>> {
+        if !term.is_term() {
+            return Err(Error::NotATerminal);
+        }
+
+        let _span = trace::shown("currency_input", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact(term, allow_quit), |value| {
+            Some(value.to_string())
+        })
+    }
+
+    fn _interact(self, term: &Term, allow_quit: bool) -> Result<Option<u64>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let default = self
+            .default
+            .map(|default| self.shown_amount(&self.amount_text(default)));
+        let mut amount = String::new();
+
+        loop {
+            term.clear_line()?;
+            render.input_prompt(&self.prompt, default.as_deref())?;
+            if !amount.is_empty() {
+                term.write_str(&self.shown_amount(&amount))?;
+            }
+            term.flush()?;
+
+            let state = || PromptState::Input {
+                text: &amount,
+                cursor: amount.chars().count(),
+            };
+            self.observers.rendered(state());
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state());
+
+            match key {
+                Key::Enter => {
+                    let value = match self.minor_units(&amount) {
+                        Some(value) if !amount.is_empty() => value,
+                        _ => match self.default {
+                            Some(default) if amount.is_empty() => default,
+                            _ => {
+                                if self.bell {
+                                    render.bell()?;
+                                }
+                                continue;
+                            }
+                        },
+                    };
+
+                    term.clear_line()?;
+                    render.clear()?;
+
+                    if self.report {
+                        let amount = self.shown_amount(&self.amount_text(value));
+                        render.input_prompt_selection(&self.prompt, &amount)?;
+                    }
+                    term.flush()?;
+
+                    return Ok(Some(value));
+                }
+                key if allow_quit
+                    && self.abort_keys.contains(&key)
+                    && (key != CTRL_D || amount.is_empty()) =>
+                {
+                    term.clear_line()?;
+                    render.clear()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Backspace if !amount.is_empty() => {
+                    amount.pop();
+                }
+                Key::Char(chr) => match self.push(&amount, chr) {
+                    Some(text) => amount = text,
+                    None if self.bell => render.bell()?,
+                    None => {}
+                },
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the amount after typing `chr`, or `None` if it can't be typed.
+    ///
+    /// The amount is kept in the format of `FromStr`, e.g. "1234.5".
+    fn push(&self, amount: &str, chr: char) -> Option<String> {
+        if Some(chr) == self.number_format.grouping_separator {
+            return Some(amount.to_string());
+        }
+
+        let text = if chr.is_ascii_digit() {
+            match amount.split_once('.') {
+                Some((_, fraction)) if fraction.len() >= self.decimal_places as usize => {
+                    return None
+                }
+                None if amount == "0" => chr.to_string(),
+                _ => format!("{}{}", amount, chr),
+            }
+        } else if chr == self.number_format.decimal_separator {
+            if self.decimal_places == 0 || amount.contains('.') {
+                return None;
+            }
+            match amount {
+                "" => "0.".to_string(),
+                _ => format!("{}.", amount),
+            }
+        } else {
+            return None;
+        };
+
+        self.minor_units(&text).map(|_| text)
+    }
+
+    /// Converts an amount to minor units, or `None` if it's too large.
+    fn minor_units(&self, amount: &str) -> Option<u64> {
+        let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        let integer: u64 = match integer {
+            "" => 0,
+            integer => integer.parse().ok()?,
+        };
+        let fraction: u64 = format!(
+            "{:0<width$}",
+            fraction,
+            width = self.decimal_places as usize
+        )
+        .parse()
+        .unwrap_or(0);
+
+        integer
+            .checked_mul(10u64.checked_pow(self.decimal_places)?)?
+            .checked_add(fraction)
+    }
+
+    /// Converts minor units to an amount with all decimal places, e.g. "1234.50".
+    fn amount_text(&self, value: u64) -> String {
+        match 10u64.checked_pow(self.decimal_places) {
+            Some(unit) if self.decimal_places > 0 => format!(
+                "{}.{:0width$}",
+                value / unit,
+                value % unit,
+                width = self.decimal_places as usize
+            ),
+            _ => value.to_string(),
+        }
+    }
+
+    /// Returns an amount as it's shown, with the separators and the symbol.
+    fn shown_amount(&self, amount: &str) -> String {
+        let number = self.number_format.format(amount);
+
+        if self.symbol_after {
+            format!("{}{}", number, self.symbol)
+        } else {
+            format!("{}{}", self.symbol, number)
+        }
+    }
+}
+
+impl<'a> CurrencyInput<'a> {
+    /// Creates a currency input prompt with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{theme::ColorfulTheme, CurrencyInput, NumberFormat};
+    ///
+    /// fn main() {
+    ///     let cents = CurrencyInput::with_theme(&ColorfulTheme::default())
+    ///         .with_prompt("Preis")
+    ///         .symbol(" €")
+    ///         .symbol_after(true)
+    ///         .number_format(
+    ///             NumberFormat::new()
+    ///                 .decimal_separator(',')
+    ///                 .grouping_separator(Some('.')),
+    ///         )
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self {
+            prompt: "".into(),
+            report: true,
+            default: None,
+            symbol: "$".into(),
+            symbol_after: false,
+            decimal_places: 2,
+            number_format: NumberFormat::new(),
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, CTRL_D]),
+            output: Target::default(),
+            observers: Observers::default(),
+            theme,
+        }
+    }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for CurrencyInput<'_> {
+    type Output = u64;
+
+    fn interact_on(&mut self, term: &Term) -> Result<u64> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<u64> {
+        self.clone().interact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(input: &CurrencyInput, keys: &str) -> String {
+        keys.chars().fold(String::new(), |amount, chr| {
+            input.push(&amount, chr).unwrap_or(amount)
+        })
+    }
+
+    #[test]
+    fn test_push() {
+        let input = CurrencyInput::new();
+
+        assert_eq!(typed(&input, "1,234.567"), "1234.56");
+        assert_eq!(typed(&input, "0.5.1"), "0.51");
+        assert_eq!(typed(&input, "007"), "7");
+        assert_eq!(typed(&input, ".5"), "0.5");
+        assert_eq!(typed(&input, "12a"), "12");
+        assert_eq!(typed(&input.decimal_places(0), "12.3"), "123");
+        assert_eq!(
+            typed(&CurrencyInput::new(), "99999999999999999999"),
+            "99999999999999999"
+        );
+    }
+
+    #[test]
+    fn test_minor_units() {
+        let input = CurrencyInput::new();
+
+        assert_eq!(input.minor_units("1234.5"), Some(123450));
+        assert_eq!(input.minor_units("0."), Some(0));
+        assert_eq!(input.amount_text(123450), "1234.50");
+        assert_eq!(input.amount_text(7), "0.07");
+        assert_eq!(input.decimal_places(0).amount_text(7), "7");
+    }
+
+    #[test]
+    fn test_shown_amount() {
+        let euro = CurrencyInput::new()
+            .symbol(" €")
+            .symbol_after(true)
+            .number_format(
+                NumberFormat::new()
+                    .decimal_separator(',')
+                    .grouping_separator(Some('.')),
+            );
+
+        assert_eq!(CurrencyInput::new().shown_amount("1234.5"), "$1,234.5");
+        assert_eq!(euro.shown_amount("1234.50"), "1.234,50 €");
+        assert_eq!(euro.shown_amount(&typed(&euro, "1.234,5")), "1.234,5 €");
+    }
+}
@@ -0,0 +1,816 @@
+use std::{
+    cmp::Ordering,
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use console::{Key, Term};
+
+use crate::{
+    filter::glob_matches,
+    observe::{Observers, PromptState},
+    prompts::{Outcome, Prompt, Target},
+    signals::Cleanup,
+    theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    trace, translate, Confirm, Error, Input, Result, Select,
+};
+
+/// The key which shows or hides hidden entries.
+const TOGGLE_HIDDEN: Key = Key::Char('.');
+
+/// The key which asks for a filter.
+const FILTER: Key = Key::Char('/');
+
+/// The order of the entries of a [`FilePicker`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FileOrder {
+    /// Sorts the entries by name, ignoring case.
+    #[default]
+    Name,
+    /// Sorts the entries by modification time, newest first.
+    Modified,
+    /// Sorts the entries by size, largest first.
+    Size,
+}
+
+/// Renders a file picker.
+///
+/// The user navigates the directories with 'Enter', goes to the parent directory
+/// with '../' or 'Backspace', shows or hides hidden entries with '.' and filters the
+/// files with a glob like `*.rs` after '/'. The path of the directory is shown as
+/// breadcrumbs above the entries: 'Left' and 'Right' highlight a directory of the path
+/// and 'Enter' jumps to it, as do the number keys, '1' jumps to the root.
+/// Returns the path of the picked file, or of the picked directory if only
+/// [`directories`](Self::directories_only) are listed. In [`save`](Self::save) mode
+/// the user picks a path to save a file to instead.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::FilePicker;
+///
+/// fn main() {
+///     let path = FilePicker::new()
+///         .with_prompt("Pick a manifest")
+///         .extensions(["toml"])
+///         .interact()
+///         .unwrap();
+///
+///     println!("You picked {}", path.display());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FilePicker<'a> {
+    prompt: Option<String>,
+    directory: Option<PathBuf>,
+    show_hidden: bool,
+    extensions: Vec<String>,
+    filter: String,
+    order: FileOrder,
+    directories_first: bool,
+    directories_only: bool,
+    follow_symlinks: bool,
+    save: bool,
+    file_name: Option<String>,
+    report: bool,
+    max_length: Option<usize>,
+    output: Target,
+    observers: Observers<'a>,
+    theme: &'a dyn Theme,
+}
+
+impl Default for FilePicker<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FilePicker<'_> {
+    /// Creates a file picker with default theme.
+    pub fn new() -> Self {
+        Self::with_theme(&SimpleTheme)
+    }
+}
+
+impl FilePicker<'_> {
+    /// Prefaces the picker with a prompt.
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Sets the directory the picker starts in.
+    ///
+    /// Relative paths are relative to the current directory, which is the default.
+    pub fn directory<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.directory = Some(path.into());
+        self
+    }
+
+    /// Indicates whether hidden entries, whose names start with a dot, are listed.
+    ///
+    /// The user can show or hide them with '.'. The default is to hide them.
+    pub fn show_hidden(mut self, val: bool) -> Self {
+        self.show_hidden = val;
+        self
+    }
+
+    /// Lists only files with one of the extensions, ignoring case.
+    ///
+    /// The extensions are given without the dot, e.g. `["rs", "toml"]`.
+    /// Directories are always listed. The default is to list all files.
+    pub fn extensions<S, I>(mut self, extensions: I) -> Self
+    where
+        S: AsRef<str>,
+        I: IntoIterator<Item = S>,
+    {
+        self.extensions = extensions
+            .into_iter()
+            .map(|ext| ext.as_ref().trim_start_matches('.').to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Lists only files whose names match the glob, ignoring case.
+    ///
+    /// `*` matches any number of characters and `?` a single character, e.g. `*.rs`
+    /// or `test_??.txt`. Directories are always listed. The user can change the filter
+    /// with '/', an empty filter lists all files, which is the default.
+    pub fn filter<S: Into<String>>(mut self, glob: S) -> Self {
+        self.filter = glob.into();
+        self
+    }
+
+    /// Sets the order of the entries.
+    ///
+    /// The default is [`FileOrder::Name`].
+    pub fn order(mut self, val: FileOrder) -> Self {
+        self.order = val;
+        self
+    }
+
+    /// Indicates whether directories are listed before files.
+    ///
+    /// The default is to list directories first.
+    pub fn directories_first(mut self, val: bool) -> Self {
+        self.directories_first = val;
+        self
+    }
+
+    /// Lists only directories, so that the picker returns a directory.
+    ///
+    /// The current directory is picked with './'. The default is to pick a file.
+    pub fn directories_only(mut self, val: bool) -> Self {
+        self.directories_only = val;
+        self
+    }
+
+    /// Indicates whether symbolic links are followed.
+    ///
+    /// Links to directories can be navigated if they are followed, otherwise all
+    /// links are listed as files. Either way the picker returns the path of the link
+    /// rather than of its target. The default is to follow links.
+    pub fn follow_symlinks(mut self, val: bool) -> Self {
+        self.follow_symlinks = val;
+        self
+    }
+
+    /// Picks a path to save a file to, which does not need to exist.
+    ///
+    /// The user navigates to a directory and enters the name of a new file, or picks
+    /// an existing file. Overwriting an existing file has to be confirmed. Names which
+    /// are not valid on the platform, e.g. with a '/', are rejected.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FilePicker;
+    ///
+    /// fn main() {
+    ///     let path = FilePicker::new()
+    ///         .with_prompt("Save the report as")
+    ///         .save(true)
+    ///         .file_name("report.csv")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn save(mut self, val: bool) -> Self {
+        self.save = val;
+        self
+    }
+
+    /// Sets the initial name of a new file in [`save`](Self::save) mode.
+    pub fn file_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.file_name = Some(name.into());
+        self
+    }
+
+    /// Indicates whether to report the picked path after interaction.
+    ///
+    /// The default is to report the picked path.
+    pub fn report(mut self, val: bool) -> Self {
+        self.report = val;
+        self
+    }
+
+    /// Sets an optional max length for a page of entries.
+    pub fn max_length(mut self, val: usize) -> Self {
+        self.max_length = Some(val);
+        self
+    }
+
+    /// Sets the terminal the picker is rendered on.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Enables user interaction and returns the picked path.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
+    #[inline]
+    pub fn interact(self) -> Result<PathBuf> {
+        let term = self.output.term()?;
+        self.interact_on(&term)
+    }
+
+    /// Enables user interaction and returns the picked path.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// Result contains `Some(path)` if user picked a path or `None` if user cancelled with 'Esc' or 'q'.
+    #[inline]
+    pub fn interact_opt(self) -> Result<Option<PathBuf>> {
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
+    }
+
+    /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on(self, term: &Term) -> Result<PathBuf> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_on_opt(self, term: &Term) -> Result<Option<PathBuf>> {
+        self._interact_on(term, true)
+    }
+
+    fn _interact_on(&self, term: &Term, allow_quit: bool) -> Result<Option<PathBuf>> {
+        if !term.is_term() {
+            return Err(Error::NotATerminal);
+        }
+
+        let _span = trace::shown("file_picker", self.prompt.as_deref());
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact(term, allow_quit), |path| {
+            Some(path.display().to_string())
+        })
+    }
+
+    fn _interact(&self, term: &Term, allow_quit: bool) -> Result<Option<PathBuf>> {
+        let mut dir = match self.directory {
+            Some(ref dir) => env::current_dir()?.join(dir),
+            None => env::current_dir()?,
+        };
+        let mut show_hidden = self.show_hidden;
+        let mut filter = self.filter.clone();
+        let mut file_name = self.file_name.clone();
+        let mut highlight = None;
+        let mut crumb = None;
+        let mut error = None;
+        let mut render = TermThemeRenderer::new(term, self.theme);
+
+        loop {
+            let entries = match self.read_entries(&dir, show_hidden, &filter) {
+                Ok(entries) => entries,
+                // Goes back to the parent, e.g. if the user may not list the directory
+                Err(err) if dir.parent().is_some() => {
+                    error = Some(format!("{}: {}", dir.display(), err));
+                    highlight = go_up(&mut dir);
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
+
+            let has_parent = dir.parent().is_some();
+            let offset = usize::from(has_parent) + usize::from(self.save || self.directories_only);
+            // Highlights the directory the user came from, or the first entry
+            let default = highlight
+                .take()
+                .and_then(|name| entries.iter().position(|entry| entry.name == name))
+                .or_else(|| (!entries.is_empty()).then_some(0))
+                .map_or(0, |idx| idx + offset);
+
+            let prompt = match (&self.prompt, filter.is_empty()) {
+                (Some(prompt), true) => Some(prompt.clone()),
+                (Some(prompt), false) => Some(format!("{} ({})", prompt, filter)),
+                (None, true) => None,
+                (None, false) => Some(filter.clone()),
+            };
+
+            let mut select = Select::with_theme(self.theme)
+                .report(false)
+                .default(default)
+                .watch_keys(
+                    [
+                        TOGGLE_HIDDEN,
+                        FILTER,
+                        Key::Backspace,
+                        Key::ArrowLeft,
+                        Key::ArrowRight,
+                    ]
+                    .into_iter()
+                    .chain(('1'..='9').map(Key::Char)),
+                );
+            select.observers = self.observers.clone();
+
+            if let Some(prompt) = prompt {
+                select = select.with_prompt(prompt);
+            }
+
+            if has_parent {
+                select = select.item("../");
+            }
+
+            if self.save {
+                select = select.item(translate::text("new-file", "New file…"));
+            } else if self.directories_only {
+                select = select.item("./");
+            }
+
+            select = select.items(entries.iter().map(Entry::label));
+
+            if let Some(max_length) = self.max_length {
+                select = select.max_length(max_length);
+            }
+
+            if !allow_quit {
+                select = select.abort_keys([]);
+            }
+
+            if let Some(err) = error.take() {
+                render.error(&err)?;
+            }
+
+            let mut ancestors: Vec<&Path> = dir.ancestors().collect();
+            ancestors.reverse();
+
+            let names: Vec<_> = ancestors.iter().map(|path| segment(path)).collect();
+            let segments: Vec<&str> = names.iter().map(String::as_str).collect();
+            render.breadcrumbs(&segments, crumb)?;
+
+            let outcome = select.interact_outcome_on(term);
+            render.clear()?;
+
+            // The ancestor to jump to, and the crumb highlighted from now on
+            let last = ancestors.len() - 1;
+            let (jump, next_crumb) = match outcome {
+                Ok(Outcome::Key {
+                    key: Key::ArrowLeft,
+                    ..
+                }) => (
+                    None,
+                    (last > 0).then(|| crumb.unwrap_or(last).saturating_sub(1)),
+                ),
+                Ok(Outcome::Key {
+                    key: Key::ArrowRight,
+                    ..
+                }) => (None, crumb.map(|idx| idx + 1).filter(|&idx| idx < last)),
+                Ok(Outcome::Key {
+                    key: Key::Char(chr @ '1'..='9'),
+                    ..
+                }) => (Some(chr as usize - '1' as usize), None),
+                Ok(Outcome::Answered(_)) if crumb.is_some() => (crumb, None),
+                _ => (None, None),
+            };
+
+            crumb = next_crumb;
+
+            if let Some(idx) = jump.filter(|&idx| idx < last) {
+                highlight = Some(segment(ancestors[idx + 1]));
+                dir = ancestors[idx].to_path_buf();
+                continue;
+            }
+
+            match outcome? {
+                Outcome::Cancelled => return Ok(None),
+                Outcome::Key {
+                    key: Key::ArrowLeft | Key::ArrowRight | Key::Char('1'..='9'),
+                    highlighted,
+                } => {
+                    highlight = highlighted
+                        .and_then(|idx| idx.checked_sub(offset))
+                        .map(|idx| entries[idx].name.clone());
+                }
+                Outcome::Key { key, highlighted } if key == TOGGLE_HIDDEN || key == FILTER => {
+                    if key == TOGGLE_HIDDEN {
+                        show_hidden = !show_hidden;
+                    } else if let Some(glob) = self.ask_filter(term, &filter)? {
+                        filter = glob;
+                    }
+
+                    highlight = highlighted
+                        .and_then(|idx| idx.checked_sub(offset))
+                        .map(|idx| entries[idx].name.clone());
+                }
+                Outcome::Key { .. } => {
+                    highlight = go_up(&mut dir);
+                }
+                Outcome::Answered(idx) => match idx.checked_sub(offset) {
+                    Some(idx) if entries[idx].is_dir => {
+                        dir.push(&entries[idx].name);
+                    }
+                    Some(idx) => {
+                        let path = dir.join(&entries[idx].name);
+
+                        if !self.save || self.confirm_overwrite(term, &path)? {
+                            return self.picked(&mut render, path);
+                        }
+
+                        highlight = Some(entries[idx].name.clone());
+                    }
+                    None if has_parent && idx == 0 => {
+                        highlight = go_up(&mut dir);
+                    }
+                    None if self.save => {
+                        if let Some(path) = self.new_file(term, &dir, &mut file_name)? {
+                            return self.picked(&mut render, path);
+                        }
+                    }
+                    None => return self.picked(&mut render, dir),
+                },
+            }
+        }
+    }
+
+    /// Asks for the name of a new file in `dir`, until the user confirms to overwrite
+    /// an existing file. Returns `None` if the user cancelled.
+    fn new_file(
+        &self,
+        term: &Term,
+        dir: &Path,
+        file_name: &mut Option<String>,
+    ) -> Result<Option<PathBuf>> {
+        loop {
+            let mut input = Input::<String>::with_theme(self.theme)
+                .with_prompt(translate::text("file-name", "File name"))
+                .report(false)
+                .validate_with(|name: &String| -> std::result::Result<(), String> {
+                    if is_valid_file_name(name) && !dir.join(name).is_dir() {
+                        Ok(())
+                    } else {
+                        Err(
+                            translate::text("invalid-file-name", "Not a valid file name")
+                                .into_owned(),
+                        )
+                    }
+                });
+
+            if let Some(ref name) = file_name {
+                input = input.with_initial_text(name);
+            }
+
+            let name = match input.interact_text_on_opt(term)? {
+                Some(name) => name,
+                None => return Ok(None),
+            };
+            let path = dir.join(&name);
+            *file_name = Some(name);
+
+            if !path.exists() || self.confirm_overwrite(term, &path)? {
+                return Ok(Some(path));
+            }
+        }
+    }
+
+    /// Asks for a new filter, starting with the current one. Returns `None` if the
+    /// user cancelled.
+    fn ask_filter(&self, term: &Term, filter: &str) -> Result<Option<String>> {
+        let glob = Input::<String>::with_theme(self.theme)
+            .with_prompt(translate::text("filter", "Filter"))
+            .with_initial_text(filter)
+            .allow_empty(true)
+            .report(false)
+            .interact_text_on_opt(term)?;
+
+        Ok(glob.map(|glob| glob.trim().to_string()))
+    }
+
+    /// Asks whether to overwrite the existing file at `path`.
+    fn confirm_overwrite(&self, term: &Term, path: &Path) -> Result<bool> {
+        let overwrite = Confirm::with_theme(self.theme)
+            .with_prompt(format!(
+                "{} {}?",
+                translate::text("overwrite", "Overwrite"),
+                path.display()
+            ))
+            .default(false)
+            .report(false)
+            .interact_on_opt(term)?;
+
+        Ok(overwrite.unwrap_or(false))
+    }
+
+    /// Reports the picked path and returns it.
+    fn picked(&self, render: &mut TermThemeRenderer, path: PathBuf) -> Result<Option<PathBuf>> {
+        if let Some(ref prompt) = self.prompt {
+            if self.report {
+                render.select_prompt_selection(prompt, &path.display().to_string())?;
+            }
+        }
+
+        Ok(Some(path))
+    }
+
+    /// Returns the entries of `dir` which are listed, in order.
+    fn read_entries(&self, dir: &Path, show_hidden: bool, filter: &str) -> io::Result<Vec<Entry>> {
+        let mut entries = vec![];
+
+        for entry in fs::read_dir(dir)?.filter_map(|entry| entry.ok()) {
+            let name = entry.file_name().to_string_lossy().into_owned();
+
+            if !show_hidden && name.starts_with('.') {
+                continue;
+            }
+
+            // Skips entries which are removed while they are listed
+            let mut metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+
+            let link = if metadata.file_type().is_symlink() {
+                if self.follow_symlinks {
+                    // Broken links are listed as files
+                    if let Ok(target) = fs::metadata(entry.path()) {
+                        metadata = target;
+                    }
+                }
+
+                fs::read_link(entry.path()).ok()
+            } else {
+                None
+            };
+
+            let is_dir = metadata.is_dir();
+
+            if !is_dir
+                && (self.directories_only
+                    || !self.has_extension(&name)
+                    || !(filter.is_empty() || glob_matches(filter, &name)))
+            {
+                continue;
+            }
+
+            entries.push(Entry {
+                name,
+                is_dir,
+                link,
+                modified: metadata.modified().ok(),
+                len: metadata.len(),
+            });
+        }
+
+        entries.sort_by(|a, b| self.compare(a, b));
+        Ok(entries)
+    }
+
+    fn has_extension(&self, name: &str) -> bool {
+        self.extensions.is_empty()
+            || Path::new(name).extension().map_or(false, |ext| {
+                self.extensions
+                    .contains(&ext.to_string_lossy().to_lowercase())
+            })
+    }
+
+    fn compare(&self, a: &Entry, b: &Entry) -> Ordering {
+        let dirs = if self.directories_first {
+            b.is_dir.cmp(&a.is_dir)
+        } else {
+            Ordering::Equal
+        };
+
+        let order = match self.order {
+            FileOrder::Name => Ordering::Equal,
+            FileOrder::Modified => b.modified.cmp(&a.modified),
+            FileOrder::Size => b.len.cmp(&a.len),
+        };
+
+        dirs.then(order)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+            .then_with(|| a.name.cmp(&b.name))
+    }
+}
+
+impl<'a> FilePicker<'a> {
+    /// Creates a file picker with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{theme::ColorfulTheme, FilePicker};
+    ///
+    /// fn main() {
+    ///     let path = FilePicker::with_theme(&ColorfulTheme::default())
+    ///         .directories_only(true)
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self {
+            prompt: None,
+            directory: None,
+            show_hidden: false,
+            extensions: vec![],
+            filter: String::new(),
+            order: FileOrder::default(),
+            directories_first: true,
+            directories_only: false,
+            follow_symlinks: true,
+            save: false,
+            file_name: None,
+            report: true,
+            max_length: None,
+            output: Target::default(),
+            observers: Observers::default(),
+            theme,
+        }
+    }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    ///
+    /// The state is the one of the select prompt listing the entries of the directory.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    ///
+    /// The state is the one of the select prompt listing the entries of the directory.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for FilePicker<'_> {
+    type Output = PathBuf;
+
+    fn interact_on(&mut self, term: &Term) -> Result<PathBuf> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    fn interact(&mut self) -> Result<PathBuf> {
+        let term = self.output.term()?;
+        Prompt::interact_on(self, &term)
+    }
+}
+
+/// An entry of a directory.
+struct Entry {
+    name: String,
+    is_dir: bool,
+    link: Option<PathBuf>,
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl Entry {
+    /// Returns the text of the entry, e.g. `src/` or `link -> target`.
+    fn label(&self) -> String {
+        let mut label = self.name.clone();
+
+        if self.is_dir {
+            label.push('/');
+        }
+
+        if let Some(ref target) = self.link {
+            label.push_str(" -> ");
+            label.push_str(&target.to_string_lossy());
+        }
+
+        label
+    }
+}
+
+/// Checks whether `name` is a valid name for a file on the platform.
+fn is_valid_file_name(name: &str) -> bool {
+    if name.is_empty() || name == "." || name == ".." {
+        return false;
+    }
+
+    if cfg!(windows) {
+        // Names of devices are reserved, even with an extension
+        let stem = name.split('.').next().unwrap_or(name).to_uppercase();
+        let reserved = matches!(&*stem, "CON" | "PRN" | "AUX" | "NUL")
+            || (stem.len() == 4
+                && (stem.starts_with("COM") || stem.starts_with("LPT"))
+                && matches!(stem.as_bytes()[3], b'1'..=b'9'));
+
+        !reserved
+            && !name.ends_with([' ', '.'])
+            && !name
+                .chars()
+                .any(|chr| chr.is_control() || "<>:\"/\\|?*".contains(chr))
+    } else {
+        !name.contains(['/', '\0'])
+    }
+}
+
+/// Returns the name of the directory at `path`, or the root itself.
+fn segment(path: &Path) -> String {
+    match path.file_name() {
+        Some(name) => name.to_string_lossy().into_owned(),
+        None => path.display().to_string(),
+    }
+}
+
+/// Goes to the parent directory and returns the name of the directory it left.
+fn go_up(dir: &mut PathBuf) -> Option<String> {
+    let name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned());
+
+    dir.pop().then_some(name).flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(entries: &[Entry]) -> Vec<&str> {
+        entries.iter().map(|entry| &*entry.name).collect()
+    }
+
+    #[test]
+    fn test_read_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        fs::create_dir_all(dir.join("src")).unwrap();
+
+        for file in ["b.rs", "A.TOML", "c.txt", ".hidden.rs"] {
+            fs::write(dir.join(file), file).unwrap();
+        }
+
+        let picker = FilePicker::new();
+        let entries = picker.read_entries(dir, false, "").unwrap();
+        assert_eq!(names(&entries), ["src", "A.TOML", "b.rs", "c.txt"]);
+        assert_eq!(entries[0].label(), "src/");
+
+        let picker = FilePicker::new().extensions(["rs", ".toml"]);
+        let entries = picker.read_entries(dir, true, "").unwrap();
+        assert_eq!(names(&entries), ["src", ".hidden.rs", "A.TOML", "b.rs"]);
+
+        let picker = FilePicker::new().order(FileOrder::Size);
+        let entries = picker.read_entries(dir, false, "").unwrap();
+        assert_eq!(names(&entries), ["src", "A.TOML", "c.txt", "b.rs"]);
+
+        let picker = FilePicker::new().directories_first(false);
+        let entries = picker.read_entries(dir, false, "").unwrap();
+        assert_eq!(names(&entries), ["A.TOML", "b.rs", "c.txt", "src"]);
+
+        let picker = FilePicker::new();
+        let entries = picker.read_entries(dir, false, "?.*t").unwrap();
+        assert_eq!(names(&entries), ["src", "c.txt"]);
+
+        let picker = FilePicker::new().directories_only(true);
+        let entries = picker.read_entries(dir, true, "").unwrap();
+        assert_eq!(names(&entries), ["src"]);
+    }
+
+    #[test]
+    fn test_is_valid_file_name() {
+        assert!(is_valid_file_name("report.csv"));
+        assert!(is_valid_file_name(".hidden"));
+        assert!(!is_valid_file_name(""));
+        assert!(!is_valid_file_name(".."));
+        assert!(!is_valid_file_name("dir/report.csv"));
+
+        if cfg!(windows) {
+            assert!(!is_valid_file_name("a?b"));
+            assert!(!is_valid_file_name("nul.txt"));
+            assert!(!is_valid_file_name("COM1"));
+            assert!(!is_valid_file_name("report. "));
+            assert!(is_valid_file_name("COM10"));
+        }
+    }
+
+    #[test]
+    fn test_go_up() {
+        let mut dir = PathBuf::from("/tmp/dir");
+        assert_eq!(go_up(&mut dir).as_deref(), Some("dir"));
+        assert_eq!(dir, Path::new("/tmp"));
+    }
+}
@@ -1,13 +1,38 @@
-use std::{io, ops::Rem};
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::HashMap,
+    io, mem,
+    ops::Rem,
+    path::Path,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
 
 use console::{Key, Term};
-use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 
 use crate::{
+    memory::Memory,
+    observe::{Observers, PromptState},
+    prompts::{
+        fetch_on_thread, read_paste, render_fetching, AbortKeys, AltScreen, BracketedPaste,
+        Outcome, Prompt, Target, WatchKeys, CTRL_D, CTRL_Z, PASTE_START,
+    },
+    signals::{suspend, Cleanup},
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
-    Result,
+    trace, translate, Error, Frecency, Result,
 };
 
+type ItemSource<'a> = Arc<Mutex<dyn FnMut(&str) -> Vec<String> + Send + 'a>>;
+// Fetches the items while calling the callback with the tick of the spinner
+type FetchCallback<'a> =
+    Arc<Mutex<dyn FnMut(&mut dyn FnMut(usize) -> Result) -> Result<Vec<String>> + 'a>>;
+
 /// Renders a select prompt with fuzzy search.
 ///
 /// User can use fuzzy search to limit selectable items.
@@ -33,26 +58,42 @@ use crate::{
 #[derive(Clone)]
 pub struct FuzzySelect<'a> {
     default: Option<usize>,
-    items: Vec<String>,
+    items: Vec<Cow<'a, str>>,
+    keywords: HashMap<usize, Vec<String>>,
     prompt: String,
     report: bool,
     clear: bool,
     highlight_matches: bool,
+    match_count: bool,
+    min_score: i64,
+    sort_by_score: bool,
+    extended_search: bool,
     enable_vim_mode: bool,
     max_length: Option<usize>,
+    fullscreen: bool,
+    item_source: Option<ItemSource<'a>>,
+    fetch: Option<FetchCallback<'a>>,
+    debounce: Duration,
+    bell: bool,
+    abort_keys: AbortKeys,
+    watch_keys: WatchKeys,
+    output: Target,
+    memory: Option<Memory>,
+    frecency: Option<Frecency>,
+    observers: Observers<'a>,
     theme: &'a dyn Theme,
     /// Search string that a fuzzy search with start with.
     /// Defaults to an empty string.
     initial_text: String,
 }
 
-impl Default for FuzzySelect<'static> {
+impl Default for FuzzySelect<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl FuzzySelect<'static> {
+impl FuzzySelect<'_> {
     /// Creates a fuzzy select prompt with default theme.
     pub fn new() -> Self {
         Self::with_theme(&SimpleTheme)
@@ -69,6 +110,8 @@ impl FuzzySelect<'_> {
     }
 
     /// Sets a default for the menu
+    ///
+    /// Element is indicated by the index at which it appears in [`item`](Self::item) method invocation or [`items`](Self::items) slice.
     pub fn default(mut self, val: usize) -> Self {
         self.default = Some(val);
         self
@@ -76,7 +119,38 @@ impl FuzzySelect<'_> {
 
     /// Add a single item to the fuzzy selector.
     pub fn item<T: ToString>(mut self, item: T) -> Self {
-        self.items.push(item.to_string());
+        self.items.push(item.to_string().into());
+        self
+    }
+
+    /// Add a single item with hidden keywords to the fuzzy selector.
+    ///
+    /// The search matches the keywords, e.g. aliases, tags or ids, like the item
+    /// itself, but only the item is rendered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FuzzySelect;
+    ///
+    /// fn main() {
+    ///     let selection = FuzzySelect::new()
+    ///         .item_with_keywords("API Server", ["prod", "api-1"])
+    ///         .item_with_keywords("Worker", ["staging", "worker-3"])
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn item_with_keywords<T, K, I>(mut self, item: T, keywords: I) -> Self
+    where
+        T: ToString,
+        K: ToString,
+        I: IntoIterator<Item = K>,
+    {
+        let keywords = keywords.into_iter().map(|k| k.to_string()).collect();
+
+        self.keywords.insert(self.items.len(), keywords);
+        self.items.push(item.to_string().into());
         self
     }
 
@@ -87,12 +161,33 @@ impl FuzzySelect<'_> {
         I: IntoIterator<Item = T>,
     {
         self.items
-            .extend(items.into_iter().map(|item| item.to_string()));
+            .extend(items.into_iter().map(|item| item.to_string().into()));
 
         self
     }
 
     /// Sets the search text that a fuzzy search starts with.
+    ///
+    /// The items are filtered by this text from the start and the best match is
+    /// highlighted, unless a [`default`](Self::default) matches the text. The user
+    /// can edit the text like any other search text.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FuzzySelect;
+    ///
+    /// fn main() {
+    ///     let branches = vec!["main", "feat/paging", "feat/fuzzy", "fix/windows"];
+    ///
+    ///     let selection = FuzzySelect::new()
+    ///         .with_prompt("Branch")
+    ///         .items(&branches)
+    ///         .with_initial_text("feat/")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
     pub fn with_initial_text<S: Into<String>>(mut self, initial_text: S) -> Self {
         self.initial_text = initial_text.into();
         self
@@ -123,6 +218,53 @@ impl FuzzySelect<'_> {
         self
     }
 
+    /// Indicates whether to show the number of matching items next to the search text,
+    /// like "23/5000 matches".
+    ///
+    /// The count is not shown for an [`item_source`](Self::item_source), as the number
+    /// of all items is unknown. The default is to hide the count.
+    pub fn match_count(mut self, val: bool) -> Self {
+        self.match_count = val;
+        self
+    }
+
+    /// Sets the minimum score a match needs to be shown.
+    ///
+    /// Matches with a lower score are dropped, which hides items that only match
+    /// loosely. The threshold is not applied while the search text is empty.
+    ///
+    /// The default is to show all matches.
+    pub fn min_score(mut self, val: i64) -> Self {
+        self.min_score = val;
+        self
+    }
+
+    /// Indicates whether to sort the matches by their score.
+    ///
+    /// When disabled, the items are only filtered and keep their original order,
+    /// which is useful for lists that are already ordered in a meaningful way.
+    /// Matches with equal scores are always kept in their original order.
+    ///
+    /// The default is to sort the best matches first.
+    pub fn sort_by_score(mut self, val: bool) -> Self {
+        self.sort_by_score = val;
+        self
+    }
+
+    /// Indicates whether to use an extended search syntax.
+    ///
+    /// The search text is split at whitespace into tokens which all have to match,
+    /// in any order. A token prefixed with `'` has to be contained exactly and a
+    /// token prefixed with `!` must not be contained, e.g. `rust client !deprecated`.
+    /// Like the fuzzy matching, tokens are case-insensitive unless they contain an
+    /// uppercase character.
+    ///
+    /// The default is to match the whole search text as one pattern.
+    pub fn extended_search(mut self, val: bool) -> Self {
+        self.extended_search = val;
+        self
+    }
+
     /// Indicated whether to allow the use of vim mode
     ///
     /// Vim mode can be entered by pressing Escape.
@@ -142,21 +284,114 @@ impl FuzzySelect<'_> {
         self
     }
 
+    /// Indicates whether to show the prompt on the whole terminal screen.
+    ///
+    /// The prompt is shown on the alternate screen with a line explaining the keys,
+    /// which gives long lists as much room as possible. The previous output is shown
+    /// again afterwards, followed by the report. The default is to render the prompt
+    /// below the previous output.
+    pub fn fullscreen(mut self, val: bool) -> Self {
+        self.fullscreen = val;
+        self
+    }
+
+    /// Sets how long the search text has to stay unchanged before it is passed
+    /// to the [`item_source`](Self::item_source).
+    ///
+    /// The default is 200 milliseconds.
+    pub fn debounce(mut self, val: Duration) -> Self {
+        self.debounce = val;
+        self
+    }
+
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc' and Ctrl-D.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets keys which end the prompt, so that the caller can react to them, e.g.
+    /// show help on '?' or details of the highlighted item on 'd'.
+    ///
+    /// Watched keys take precedence over the usual meaning of the keys. They are only
+    /// watched by [`interact_outcome`](Self::interact_outcome), the other `interact`
+    /// methods ignore them.
+    pub fn watch_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.watch_keys = WatchKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Remembers the selected item in a file and selects it by default the next time.
+    ///
+    /// The file can be shared by several prompts with different ids. Items are
+    /// recognized by their text, so the memory survives reordered or new items. Errors
+    /// when reading or writing the file are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FuzzySelect;
+    ///
+    /// fn main() {
+    ///     let selection = FuzzySelect::new()
+    ///         .with_prompt("Which region?")
+    ///         .items(&["us-east-1", "eu-west-1", "ap-south-1"])
+    ///         .remember_as("region", "/tmp/my-cli/answers")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn remember_as<I: Into<String>, P: AsRef<Path>>(mut self, id: I, path: P) -> Self {
+        self.memory = Some(Memory::new(id.into(), path));
+        self
+    }
+
+    /// Boosts the matches of the items which were chosen most frequently and recently,
+    /// and records the chosen item.
+    ///
+    /// The boost only applies if the matches are [sorted by score](Self::sort_by_score).
+    /// See [`Frecency`] for an example.
+    pub fn frecency(mut self, frecency: Frecency) -> Self {
+        self.frecency = Some(frecency);
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// The user can select the items using 'Enter' and the index of selected item will be returned.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `index` of selected item if user hit 'Enter'.
     /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
     #[inline]
     pub fn interact(self) -> Result<usize> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
     }
 
     /// Enables user interaction and returns the result.
     ///
     /// The user can select the items using 'Enter' and the index of selected item will be returned.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `Some(index)` if user hit 'Enter' or `None` if user cancelled with 'Esc' or 'q'.
     ///
     /// ## Example
@@ -180,30 +415,210 @@ impl FuzzySelect<'_> {
     /// ```
     #[inline]
     pub fn interact_opt(self) -> Result<Option<usize>> {
-        self.interact_on_opt(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
     #[inline]
     pub fn interact_on(self, term: &Term) -> Result<usize> {
-        Ok(self
-            ._interact_on(term, false)?
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))?)
+        self._interact_on(term, false)?
+            .map(|(idx, _)| idx)
+            .ok_or(Error::Cancelled)
     }
 
     /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
     #[inline]
     pub fn interact_on_opt(self, term: &Term) -> Result<Option<usize>> {
-        self._interact_on(term, true)
+        Ok(self._interact_on(term, true)?.map(|(idx, _)| idx))
+    }
+
+    /// Enables user interaction and returns the outcome.
+    ///
+    /// Like [`interact_opt`](Self::interact_opt), but the prompt also ends when the user
+    /// presses one of the [`watch_keys`](Self::watch_keys), see
+    /// [`Select::interact_outcome`](crate::Select::interact_outcome).
+    pub fn interact_outcome(self) -> Result<Outcome<usize>> {
+        let term = self.output.term()?;
+        self.interact_outcome_on(&term)
+    }
+
+    /// Like [`interact_outcome`](Self::interact_outcome) but allows a specific terminal to be set.
+    pub fn interact_outcome_on(mut self, term: &Term) -> Result<Outcome<usize>> {
+        let watch_keys = self.watch_keys.activate();
+        let answer = self._interact_on(term, true)?.map(|(idx, _)| idx);
+
+        Ok(watch_keys.outcome(answer))
+    }
+
+    /// Like [`interact`](Self::interact) but returns the selected item instead of its index.
+    ///
+    /// This is most useful together with an [`item_source`](Self::item_source), as the
+    /// items are not known upfront in that case.
+    #[inline]
+    pub fn interact_item(self) -> Result<String> {
+        let term = self.output.term()?;
+        self.interact_item_on(&term)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but returns the selected item instead of its index.
+    #[inline]
+    pub fn interact_item_opt(self) -> Result<Option<String>> {
+        let term = self.output.term()?;
+        self.interact_item_on_opt(&term)
+    }
+
+    /// Like [`interact_item`](Self::interact_item) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_item_on(self, term: &Term) -> Result<String> {
+        self._interact_on(term, false)?
+            .map(|(_, item)| item)
+            .ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_item_opt`](Self::interact_item_opt) but allows a specific terminal to be set.
+    #[inline]
+    pub fn interact_item_on_opt(self, term: &Term) -> Result<Option<String>> {
+        Ok(self._interact_on(term, true)?.map(|(_, item)| item))
+    }
+
+    fn _interact_on(mut self, term: &Term, allow_quit: bool) -> Result<Option<(usize, String)>> {
+        if !term.is_term() {
+            return Err(Error::NotATerminal);
+        }
+
+        if let Some(fetch) = self.fetch.take() {
+            let prompt = Some(self.prompt.as_str()).filter(|prompt| !prompt.is_empty());
+            let fetched =
+                render_fetching(term, self.theme, prompt, |tick| fetch.lock().unwrap()(tick))?;
+            self.items.extend(fetched.into_iter().map(Cow::Owned));
+        }
+
+        if let Some(remembered) = self.memory.as_ref().and_then(Memory::recall) {
+            if let Some(idx) = remembered
+                .first()
+                .and_then(|item| self.items.iter().position(|it| it == item))
+            {
+                self.default = Some(idx);
+            }
+        }
+
+        let _span = trace::shown("fuzzy_select", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+
+        let selection = if self.fullscreen {
+            self._interact_fullscreen(term, allow_quit)
+        } else {
+            self._interact_source(term, allow_quit)
+        };
+        let selection = trace::answered(selection, |(_, item)| Some(item.clone()))?;
+
+        if let (Some(memory), Some((_, item))) = (&self.memory, &selection) {
+            memory.remember(&[item]);
+        }
+
+        if let (Some(frecency), Some((_, item))) = (&self.frecency, &selection) {
+            frecency.record(item);
+        }
+
+        Ok(selection)
+    }
+
+    /// Like `_interact_on` but renders the prompt on the alternate screen and
+    /// reports the selection on the main screen.
+    fn _interact_fullscreen(
+        &mut self,
+        term: &Term,
+        allow_quit: bool,
+    ) -> Result<Option<(usize, String)>> {
+        let report = mem::replace(&mut self.report, false);
+        let alt_screen = AltScreen::enable(term)?;
+        let selection = self._interact_source(term, allow_quit);
+
+        drop(alt_screen);
+        self.report = report;
+        let selection = selection?;
+
+        if let (Some((_, item)), true) = (&selection, report) {
+            TermThemeRenderer::new(term, self.theme).input_prompt_selection(&self.prompt, item)?;
+            term.flush()?;
+        }
+
+        Ok(selection)
     }
 
-    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
+    /// Like `_interact_on` but runs the item source, if any, next to the prompt.
+    fn _interact_source(&self, term: &Term, allow_quit: bool) -> Result<Option<(usize, String)>> {
+        let source = match self.item_source {
+            Some(ref source) => source.clone(),
+            None => return self.run(term, allow_quit, None),
+        };
+
+        thread::scope(|scope| {
+            let (event_tx, event_rx) = mpsc::channel();
+            let (query_tx, query_rx) = mpsc::channel::<String>();
+            let (read_tx, read_rx) = mpsc::channel::<()>();
+
+            // Keys are only read on request, so that no key is consumed after the prompt ended
+            let key_tx = event_tx.clone();
+            let key_term = term.clone();
+            let abort_keys = &self.abort_keys;
+            scope.spawn(move || {
+                while read_rx.recv().is_ok() {
+                    let key = abort_keys.read_key(&key_term, allow_quit);
+
+                    if key_tx.send(Event::Key(key)).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let debounce = self.debounce;
+            scope.spawn(move || {
+                while let Ok(mut query) = query_rx.recv() {
+                    // Only the latest search text is passed on once the user stopped typing
+                    loop {
+                        match query_rx.recv_timeout(debounce) {
+                            Ok(newer) => query = newer,
+                            Err(RecvTimeoutError::Timeout) => break,
+                            Err(RecvTimeoutError::Disconnected) => return,
+                        }
+                    }
+
+                    let items = (source.lock().unwrap())(&query);
+
+                    if event_tx.send(Event::Items(query, items)).is_err() {
+                        break;
+                    }
+                }
+            });
+
+            let channels = SourceChannels {
+                events: event_rx,
+                queries: query_tx,
+                key_requests: read_tx,
+                key_requested: false,
+            };
+
+            self.run(term, allow_quit, Some(channels))
+        })
+    }
+
+    fn run(
+        &self,
+        term: &Term,
+        allow_quit: bool,
+        mut source: Option<SourceChannels>,
+    ) -> Result<Option<(usize, String)>> {
         // Place cursor at the end of the search term
         let mut cursor = self.initial_text.chars().count();
         let mut search_term = self.initial_text.to_owned();
 
         let mut render = TermThemeRenderer::new(term, self.theme);
-        let mut sel = self.default;
+        let mut sel = None;
+        // The default item is highlighted once it is known where it shows up in the filtered list
+        let mut initial_sel = true;
 
         let mut size_vec = Vec::new();
         for items in self.items.iter().as_slice() {
@@ -211,11 +626,25 @@ impl FuzzySelect<'_> {
             size_vec.push(*size);
         }
 
+        let frecency_scores = self
+            .frecency
+            .as_ref()
+            .map(Frecency::scores)
+            .unwrap_or_default();
+
+        // Items of the item source for the latest search text it answered
+        let mut source_items = Vec::new();
+        let mut last_query = None;
+        let mut loading = false;
+
         // Fuzzy matcher
-        let matcher = fuzzy_matcher::skim::SkimMatcherV2::default();
+        let matcher = SkimMatcherV2::default();
 
-        // Subtract -2 because we need space to render the prompt.
-        let visible_term_rows = (term.size().0 as usize).max(3) - 2;
+        // Subtract -2 because we need space to render the prompt and
+        // another line for the loading indicator of an item source
+        // and the key help of the full screen mode.
+        let reserved_rows = 2 + usize::from(source.is_some()) + usize::from(self.fullscreen);
+        let visible_term_rows = (term.size().0 as usize).max(reserved_rows + 1) - reserved_rows;
         let visible_term_rows = self
             .max_length
             .unwrap_or(visible_term_rows)
@@ -224,10 +653,19 @@ impl FuzzySelect<'_> {
         let mut starting_row = 0;
 
         term.hide_cursor()?;
+        let _paste = BracketedPaste::enable(term)?;
 
         let mut vim_mode = false;
 
         loop {
+            if let Some(ref source) = source {
+                if last_query.as_ref() != Some(&search_term) {
+                    source.request_items(&search_term)?;
+                    last_query = Some(search_term.clone());
+                    loading = true;
+                }
+            }
+
             let mut byte_indices = search_term
                 .char_indices()
                 .map(|(index, _)| index)
@@ -235,21 +673,84 @@ impl FuzzySelect<'_> {
 
             byte_indices.push(search_term.len());
 
-            render.clear()?;
-            render.fuzzy_select_prompt(self.prompt.as_str(), &search_term, byte_indices[cursor])?;
-
-            // Maps all items to a tuple of item and its match score.
-            let mut filtered_list = self
-                .items
+            // Maps all items to a tuple of item index, item and its match score.
+            // The items of an item source already match, so they are taken as they are.
+            let items = if source.is_some() {
+                &source_items
+            } else {
+                &self.items
+            };
+            let mut filtered_list = items
                 .iter()
-                .map(|item| (item, matcher.fuzzy_match(item, &search_term)))
-                .filter_map(|(item, score)| score.map(|s| (item, s)))
+                .enumerate()
+                .map(|(idx, item)| match source {
+                    Some(_) => (idx, item, Some(0)),
+                    None => (
+                        idx,
+                        item,
+                        self.match_item(&matcher, idx, item, &search_term),
+                    ),
+                })
+                .filter_map(|(idx, item, score)| score.map(|s| (idx, item, s)))
+                .filter(|(_, _, score)| search_term.is_empty() || *score >= self.min_score)
                 .collect::<Vec<_>>();
 
-            // Renders all matching items, from best match to worst.
-            filtered_list.sort_unstable_by(|(_, s1), (_, s2)| s2.cmp(s1));
+            // Renders all matching items, from best match to worst. The sort is stable,
+            // so items with the same score keep their original order.
+            if self.sort_by_score {
+                filtered_list.sort_by_key(|(_, item, score)| {
+                    Reverse(score + frecency_boost(&frecency_scores, item))
+                });
+            }
+
+            if initial_sel {
+                initial_sel = false;
+
+                sel = self.default.and_then(|default| {
+                    filtered_list.iter().position(|(idx, _, _)| *idx == default)
+                });
 
-            for (idx, (item, _)) in filtered_list
+                // Like typing the initial text, it highlights the best match
+                if sel.is_none() && !search_term.is_empty() && !filtered_list.is_empty() {
+                    sel = Some(0);
+                }
+
+                if let Some(sel) = sel {
+                    starting_row = (sel + 1).max(visible_term_rows) - visible_term_rows;
+                }
+            }
+
+            // Matches of an extended search can only be highlighted for a single plain token
+            let (highlight_matches, highlight_term) = if self.extended_search {
+                let mut tokens = search_term.split_whitespace();
+
+                match (tokens.next(), tokens.next()) {
+                    (Some(token), None) if !token.starts_with(['!', '\'']) => {
+                        (self.highlight_matches, token)
+                    }
+                    (None, _) => (self.highlight_matches, ""),
+                    _ => (false, ""),
+                }
+            } else {
+                (self.highlight_matches, search_term.as_str())
+            };
+
+            // The number of all items of an item source is unknown
+            let match_count = match source {
+                None if self.match_count => Some((filtered_list.len(), self.items.len())),
+                _ => None,
+            };
+
+            render.begin_frame();
+            render.clear()?;
+            render.fuzzy_select_prompt(
+                self.prompt.as_str(),
+                &search_term,
+                byte_indices[cursor],
+                match_count,
+            )?;
+
+            for (idx, (_, item, _)) in filtered_list
                 .iter()
                 .enumerate()
                 .skip(starting_row)
@@ -258,18 +759,89 @@ impl FuzzySelect<'_> {
                 render.fuzzy_select_prompt_item(
                     item,
                     Some(idx) == sel,
-                    self.highlight_matches,
+                    highlight_matches,
                     &matcher,
-                    &search_term,
+                    highlight_term,
                 )?;
             }
-            term.flush()?;
 
-            match (term.read_key()?, sel, vim_mode) {
+            if loading {
+                render.fuzzy_select_loading()?;
+            }
+
+            if self.fullscreen {
+                render.key_help(&translate::text(
+                    "help-fuzzy-select",
+                    "Type to search · ↑/↓ move · Enter select",
+                ))?;
+            }
+
+            render.end_frame()?;
+
+            let state = PromptState::FuzzySelect {
+                text: &search_term,
+                cursor,
+                highlighted: sel
+                    .and_then(|sel| filtered_list.get(sel))
+                    .map(|(idx, _, _)| *idx),
+            };
+            self.observers.rendered(state);
+
+            let mut received_items = None;
+            let key = match source {
+                Some(ref mut source) => match source.next_event()? {
+                    Event::Key(key) => {
+                        let key = key?;
+                        self.observers.key_pressed(&key, state);
+                        key
+                    }
+                    Event::Items(query, items) => {
+                        // Results for an outdated search text are superseded by a pending request
+                        if query == search_term {
+                            received_items = Some(items);
+                        }
+
+                        // Nothing to handle, but the new items are rendered
+                        Key::Unknown
+                    }
+                },
+                None => {
+                    let key = self.abort_keys.read_key(term, allow_quit)?;
+                    self.observers.key_pressed(&key, state);
+                    key
+                }
+            };
+
+            match (key, sel, vim_mode) {
+                (key, _, _) if self.watch_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                        term.flush()?;
+                    }
+                    term.show_cursor()?;
+                    let highlighted = sel
+                        .and_then(|sel| filtered_list.get(sel))
+                        .map(|(idx, _, _)| *idx);
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
+                }
+                (key, _, _) if key == CTRL_Z && cfg!(unix) => {
+                    // The prompt is rendered again from scratch
+                    render.clear()?;
+                    suspend(term)?;
+                }
                 (Key::Escape, _, false) if self.enable_vim_mode => {
                     vim_mode = true;
                 }
-                (Key::Escape, _, false) | (Key::Char('q'), _, true) if allow_quit => {
+                // In vim mode 'q' quits, otherwise the abort keys do
+                (key, _, vim_mode)
+                    if allow_quit
+                        && if vim_mode {
+                            key == Key::Char('q')
+                        } else {
+                            self.abort_keys.contains(&key)
+                        } =>
+                {
                     if self.clear {
                         render.clear()?;
                         term.flush()?;
@@ -332,15 +904,14 @@ impl FuzzySelect<'_> {
 
                     if self.report {
                         render
-                            .input_prompt_selection(self.prompt.as_str(), filtered_list[sel].0)?;
+                            .input_prompt_selection(self.prompt.as_str(), filtered_list[sel].1)?;
                     }
 
-                    let sel_string = filtered_list[sel].0;
-                    let sel_string_pos_in_items =
-                        self.items.iter().position(|item| item.eq(sel_string));
-
                     term.show_cursor()?;
-                    return Ok(sel_string_pos_in_items);
+                    return Ok(Some((
+                        filtered_list[sel].0,
+                        filtered_list[sel].1.to_string(),
+                    )));
                 }
                 (Key::Backspace, _, _) if cursor > 0 => {
                     cursor -= 1;
@@ -351,6 +922,14 @@ impl FuzzySelect<'_> {
                     search_term.remove(byte_indices[cursor]);
                     term.flush()?;
                 }
+                (Key::UnknownEscSeq(seq), _, _) if seq == PASTE_START => {
+                    if let Some(text) = read_paste(term)? {
+                        search_term.insert_str(byte_indices[cursor], &text);
+                        cursor += text.chars().count();
+                        sel = Some(0);
+                        starting_row = 0;
+                    }
+                }
                 (Key::Char(chr), _, _) if !chr.is_ascii_control() => {
                     search_term.insert(byte_indices[cursor], chr);
                     cursor += 1;
@@ -359,15 +938,152 @@ impl FuzzySelect<'_> {
                     starting_row = 0;
                 }
 
-                _ => {}
+                // Rendering new items of an item source is no key press
+                (Key::Unknown, _, _) => {}
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
             }
 
+            render.begin_frame();
             render.clear_preserve_prompt(&size_vec)?;
+
+            if let Some(items) = received_items {
+                size_vec = items.iter().map(|item| item.len()).collect();
+                sel = if search_term.is_empty() || items.is_empty() {
+                    None
+                } else {
+                    Some(0)
+                };
+                starting_row = 0;
+                source_items = items.into_iter().map(Cow::Owned).collect();
+                loading = false;
+            }
         }
     }
 }
 
 impl<'a> FuzzySelect<'a> {
+    /// Adds multiple items without copying them.
+    ///
+    /// Unlike [`items`](Self::items), which copies each item into a `String`, this
+    /// keeps borrowed items as they are. This matters for prompts built from large
+    /// in-memory datasets.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FuzzySelect;
+    ///
+    /// fn main() {
+    ///     let names: Vec<String> = (0..100_000).map(|idx| format!("Item {}", idx)).collect();
+    ///
+    ///     let selection = FuzzySelect::new()
+    ///         .items_borrowed(names.iter().map(String::as_str))
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn items_borrowed<T, I>(mut self, items: I) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.items.extend(items.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Sets a source which provides the items for the current search text.
+    ///
+    /// Instead of fuzzy matching the items given upfront, the search text is passed
+    /// to `source`, which returns the items to choose from, e.g. by querying a
+    /// package registry. The source is called on a background thread once the
+    /// search text stayed unchanged for the [`debounce`](Self::debounce) duration.
+    /// A loading indicator is shown while the items are fetched.
+    ///
+    /// As the items are not known upfront, use [`interact_item`](Self::interact_item)
+    /// to get the selected item.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FuzzySelect;
+    ///
+    /// fn main() {
+    ///     let packages = vec!["serde", "serde_json", "tokio", "console"];
+    ///
+    ///     let package = FuzzySelect::new()
+    ///         .with_prompt("Package")
+    ///         .item_source(move |search| {
+    ///             packages
+    ///                 .iter()
+    ///                 .filter(|package| package.contains(search))
+    ///                 .map(|package| package.to_string())
+    ///                 .collect()
+    ///         })
+    ///         .interact_item()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn item_source<F>(mut self, source: F) -> Self
+    where
+        F: FnMut(&str) -> Vec<String> + Send + 'a,
+    {
+        self.item_source = Some(Arc::new(Mutex::new(source)));
+        self
+    }
+
+    /// Sets a function which fetches the items when the prompt is shown.
+    ///
+    /// The function is called on a background thread while the prompt shows a
+    /// spinner in place of the items, like [`Select::fetch_items`](crate::Select::fetch_items).
+    /// The fetched items are added after the ones given upfront, then the search
+    /// starts. Unlike an [`item_source`](Self::item_source) the items are fetched
+    /// once and matched against the search text as usual.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::FuzzySelect;
+    ///
+    /// fn main() {
+    ///     let selection = FuzzySelect::new()
+    ///         .with_prompt("Repository")
+    ///         .fetch_items(|| {
+    ///             // e.g. a request to the API of a code host
+    ///             vec!["dialoguer", "console", "indicatif"]
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn fetch_items<F, I, S>(mut self, fetch: F) -> Self
+    where
+        F: FnMut() -> I + Send + 'a,
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let fetch = Mutex::new(fetch);
+
+        self.fetch = Some(Arc::new(Mutex::new(
+            move |tick: &mut dyn FnMut(usize) -> Result| {
+                fetch_on_thread(
+                    || {
+                        (fetch.lock().unwrap())()
+                            .into_iter()
+                            .map(|item| item.to_string())
+                            .collect()
+                    },
+                    tick,
+                )
+            },
+        )));
+        self
+    }
+
     /// Creates a fuzzy select prompt with a specific theme.
     ///
     /// ## Example
@@ -386,16 +1102,171 @@ impl<'a> FuzzySelect<'a> {
         Self {
             default: None,
             items: vec![],
+            keywords: HashMap::new(),
             prompt: "".into(),
             report: true,
             clear: true,
             highlight_matches: true,
+            match_count: false,
+            min_score: 0,
+            sort_by_score: true,
+            extended_search: false,
             enable_vim_mode: false,
             max_length: None,
+            fullscreen: false,
+            item_source: None,
+            fetch: None,
+            debounce: Duration::from_millis(200),
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, CTRL_D]),
+            watch_keys: WatchKeys::default(),
+            output: Target::default(),
+            memory: None,
+            frecency: None,
+            observers: Observers::default(),
             theme,
             initial_text: "".into(),
         }
     }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl FuzzySelect<'_> {
+    /// Matches the item at `idx` and its keywords against the search term and
+    /// returns the best score.
+    fn match_item(
+        &self,
+        matcher: &SkimMatcherV2,
+        idx: usize,
+        item: &str,
+        search_term: &str,
+    ) -> Option<i64> {
+        let mut texts = vec![item];
+        if let Some(keywords) = self.keywords.get(&idx) {
+            texts.extend(keywords.iter().map(String::as_str));
+        }
+
+        if self.extended_search {
+            extended_match(matcher, &texts, search_term)
+        } else {
+            texts
+                .iter()
+                .filter_map(|text| matcher.fuzzy_match(text, search_term))
+                .max()
+        }
+    }
+}
+
+/// Returns the boost of the match score of an item which was chosen before.
+///
+/// A choice in the last hour gives a boost of 32, which is about the score of two
+/// more matching characters.
+fn frecency_boost(scores: &HashMap<String, f64>, item: &str) -> i64 {
+    scores.get(item).map_or(0, |score| (score * 8.0) as i64)
+}
+
+/// Matches the texts of an item against all tokens of an extended search and returns
+/// the summed score.
+///
+/// A token matches if any text matches it, a negated token if no text contains it.
+fn extended_match(matcher: &SkimMatcherV2, texts: &[&str], search_term: &str) -> Option<i64> {
+    search_term
+        .split_whitespace()
+        .try_fold(0, |score, token| match token.strip_prefix('!') {
+            Some(negated) => {
+                let negated = negated.strip_prefix('\'').unwrap_or(negated);
+                let excluded = texts.iter().any(|text| contains_smart_case(text, negated));
+
+                (negated.is_empty() || !excluded).then_some(score)
+            }
+            None => match token.strip_prefix('\'') {
+                Some(exact) => texts
+                    .iter()
+                    .any(|text| contains_smart_case(text, exact))
+                    .then_some(score),
+                None => texts
+                    .iter()
+                    .filter_map(|text| matcher.fuzzy_match(text, token))
+                    .max()
+                    .map(|s| score + s),
+            },
+        })
+}
+
+/// Checks whether the text contains the pattern, ignoring case for lowercase patterns.
+fn contains_smart_case(text: &str, pattern: &str) -> bool {
+    if pattern.chars().any(char::is_uppercase) {
+        text.contains(pattern)
+    } else {
+        text.to_lowercase().contains(&pattern.to_lowercase())
+    }
+}
+
+enum Event {
+    Key(io::Result<Key>),
+    Items(String, Vec<String>),
+}
+
+/// Connects the prompt with the threads reading keys and fetching items of an item source.
+struct SourceChannels {
+    events: Receiver<Event>,
+    queries: Sender<String>,
+    key_requests: Sender<()>,
+    key_requested: bool,
+}
+
+impl SourceChannels {
+    fn request_items(&self, query: &str) -> io::Result<()> {
+        self.queries
+            .send(query.to_owned())
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "Item source stopped"))
+    }
+
+    /// Waits for the next key or the items of an item source, whichever comes first.
+    fn next_event(&mut self) -> io::Result<Event> {
+        if !self.key_requested {
+            self.key_requests
+                .send(())
+                .map_err(|_| io::Error::new(io::ErrorKind::Other, "Key reader stopped"))?;
+            self.key_requested = true;
+        }
+
+        let event = self
+            .events
+            .recv()
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "Item source stopped"))?;
+
+        if let Event::Key(_) = event {
+            self.key_requested = false;
+        }
+
+        Ok(event)
+    }
+}
+
+impl Prompt for FuzzySelect<'_> {
+    type Output = usize;
+
+    fn interact_on(&mut self, term: &Term) -> Result<usize> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<usize> {
+        self.clone().interact()
+    }
 }
 
 #[cfg(test)]
@@ -416,4 +1287,48 @@ mod tests {
 
         assert_eq!(FuzzySelect::new().items(iterator).items, &items[1..]);
     }
+
+    #[test]
+    fn test_fetch_items() {
+        let fuzzy_select = FuzzySelect::new().fetch_items(|| ["a", "b"]);
+        let fetch = fuzzy_select.fetch.unwrap();
+        let fetched = fetch.lock().unwrap()(&mut |_| Ok(())).unwrap();
+
+        assert_eq!(fetched, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_keywords() {
+        let matcher = SkimMatcherV2::default();
+        let fuzzy_select = FuzzySelect::new()
+            .item("Worker")
+            .item_with_keywords("API Server", ["production"]);
+
+        assert!(fuzzy_select
+            .match_item(&matcher, 1, "API Server", "prod")
+            .is_some());
+        assert!(fuzzy_select
+            .match_item(&matcher, 0, "Worker", "prod")
+            .is_none());
+    }
+
+    #[test]
+    fn test_extended_match() {
+        let matcher = SkimMatcherV2::default();
+
+        assert!(extended_match(&matcher, &["rust-http-client"], "").is_some());
+        assert!(extended_match(&matcher, &["rust-http-client"], "client rust").is_some());
+        assert!(
+            extended_match(&matcher, &["rust-http-client"], "rust client !deprecated").is_some()
+        );
+        assert!(
+            extended_match(&matcher, &["rust-client-deprecated"], "rust !deprecated").is_none()
+        );
+        assert!(extended_match(&matcher, &["rust-http-client"], "'http").is_some());
+        assert!(extended_match(&matcher, &["rust-http-client"], "'htp").is_none());
+        assert!(extended_match(&matcher, &["rust-http-client"], "'HTTP").is_none());
+        assert!(extended_match(&matcher, &["rust-http-client"], "rust python").is_none());
+        assert!(extended_match(&matcher, &["API Server", "production"], "api prod").is_some());
+        assert!(extended_match(&matcher, &["API Server", "production"], "!prod").is_none());
+    }
 }
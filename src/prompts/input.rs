@@ -1,23 +1,55 @@
 use std::{
+    borrow::Cow,
     cmp::Ordering,
-    io, iter,
+    iter, mem,
     str::FromStr,
     sync::{Arc, Mutex},
 };
 
-use console::{Key, Term};
+use console::{measure_text_width, Key, Term};
+#[cfg(feature = "password")]
+use zeroize::Zeroizing;
 
 #[cfg(feature = "completion")]
 use crate::completion::Completion;
 #[cfg(feature = "history")]
 use crate::history::History;
+#[cfg(feature = "clipboard")]
 use crate::{
+    clipboard,
+    prompts::{is_paste_key, single_line},
+};
+use crate::{
+    observe::{Observers, PromptState},
+    prompts::{read_paste, AbortKeys, BracketedPaste, Prompt, Target, CTRL_D, PASTE_START},
+    signals::Cleanup,
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    trace,
     validate::InputValidator,
-    Result,
+    Error, NumberFormat, Result,
 };
 
-type InputValidatorCallback<'a, T> = Arc<Mutex<dyn FnMut(&T) -> Option<String> + 'a>>;
+type InputValidatorCallback<'a, T> = Arc<Mutex<dyn FnMut(&T) -> Option<Rejection> + 'a>>;
+
+/// The characters of an input, which are zeroized once dropped if possible.
+#[cfg(feature = "password")]
+type Chars = Zeroizing<Vec<char>>;
+#[cfg(not(feature = "password"))]
+type Chars = Vec<char>;
+
+/// The text of an input, which is zeroized once dropped if possible.
+#[cfg(feature = "password")]
+type Text = Zeroizing<String>;
+#[cfg(not(feature = "password"))]
+type Text = String;
+
+/// The outcome of a validation which did not pass.
+enum Rejection {
+    /// The input is rejected.
+    Error(String),
+    /// The input is accepted if the user confirms it.
+    Warning(String),
+}
 
 /// Renders an input prompt.
 ///
@@ -52,19 +84,28 @@ type InputValidatorCallback<'a, T> = Arc<Mutex<dyn FnMut(&T) -> Option<String> +
 /// ```
 #[derive(Clone)]
 pub struct Input<'a, T> {
-    prompt: String,
+    pub(crate) prompt: String,
     post_completion_text: Option<String>,
     report: bool,
     default: Option<T>,
     show_default: bool,
     initial_text: Option<String>,
+    observers: Observers<'a>,
     theme: &'a dyn Theme,
     permit_empty: bool,
     validator: Option<InputValidatorCallback<'a, T>>,
+    max_retries: Option<usize>,
+    allowed_chars: Option<Arc<dyn Fn(char) -> bool + 'a>>,
+    bell: bool,
+    pub(crate) abort_keys: AbortKeys,
+    output: Target,
     #[cfg(feature = "history")]
-    history: Option<Arc<Mutex<&'a mut dyn History<T>>>>,
+    history: Option<Arc<Mutex<dyn History<T> + 'a>>>,
     #[cfg(feature = "completion")]
     completion: Option<&'a dyn Completion>,
+    #[cfg(any(feature = "completion", feature = "history"))]
+    ghost_text: bool,
+    number_format: Option<NumberFormat>,
 }
 
 impl<T> Default for Input<'static, T> {
@@ -91,6 +132,46 @@ impl<T> Input<'_, T> {
         self
     }
 
+    /// Sets the number of rejected inputs after which the prompt gives up.
+    ///
+    /// An input is rejected if it can't be parsed or fails validation. Once the
+    /// limit is reached, [`Error::ValidationFailed`] is returned with the last
+    /// error message instead of asking again.
+    ///
+    /// The default is to ask until a valid input is given.
+    pub fn max_retries(mut self, val: usize) -> Self {
+        self.max_retries = Some(val);
+        self
+    }
+
+    /// Indicates whether to ring the terminal bell when a key press is ignored
+    /// or the input is rejected, e.g. by a validator.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc' and Ctrl-D, which
+    /// only cancels on an empty line.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
     /// Indicates whether to report the input value after interaction.
     ///
     /// The default is to report the input value.
@@ -133,6 +214,16 @@ impl<T> Input<'_, T> {
         self.show_default = val;
         self
     }
+
+    /// Sets the separators of the numbers which are typed and shown.
+    ///
+    /// The input is converted to the format of `FromStr` before it's parsed, e.g.
+    /// "1.234,56" to "1234.56" with a decimal comma, and the default and the reported
+    /// value are shown in the format. See [`NumberFormat`] for an example.
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = Some(format);
+        self
+    }
 }
 
 impl<'a, T> Input<'a, T> {
@@ -157,16 +248,39 @@ impl<'a, T> Input<'a, T> {
             default: None,
             show_default: true,
             initial_text: None,
+            observers: Observers::default(),
             theme,
             permit_empty: false,
             validator: None,
+            max_retries: None,
+            allowed_chars: None,
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, CTRL_D]),
+            output: Target::default(),
             #[cfg(feature = "history")]
             history: None,
             #[cfg(feature = "completion")]
             completion: None,
+            #[cfg(any(feature = "completion", feature = "history"))]
+            ghost_text: false,
+            number_format: None,
         }
     }
 
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+
     /// Enable history processing
     ///
     /// ## Example
@@ -217,6 +331,40 @@ impl<'a, T> Input<'a, T> {
         self
     }
 
+    /// Enable history processing with a history which is shared with other prompts.
+    ///
+    /// Unlike [`history_with`](Self::history_with) the history isn't borrowed, so
+    /// prompts which are created in different places, e.g. in the loop of a REPL,
+    /// can share it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use dialoguer::{BasicHistory, Input};
+    ///
+    /// fn main() {
+    ///     let history = Arc::new(Mutex::new(BasicHistory::new().no_duplicates(true)));
+    ///
+    ///     loop {
+    ///         let command = Input::<String>::new()
+    ///             .with_prompt("repl")
+    ///             .shared_history(history.clone())
+    ///             .interact_text()
+    ///             .unwrap();
+    ///
+    ///         if command == "exit" {
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "history")]
+    pub fn shared_history(mut self, history: Arc<Mutex<dyn History<T> + 'a>>) -> Self {
+        self.history = Some(history);
+        self
+    }
+
     /// Enable completion
     #[cfg(feature = "completion")]
     pub fn completion_with<C>(mut self, completion: &'a C) -> Self
@@ -226,6 +374,148 @@ impl<'a, T> Input<'a, T> {
         self.completion = Some(completion);
         self
     }
+
+    /// Indicates whether to suggest the continuation of the input after the cursor.
+    ///
+    /// While the cursor is at the end of the input, the continuation suggested by the
+    /// [completion](Self::completion_with), or else by the most recent matching
+    /// [history](Self::history_with) entry, is shown dimmed after the cursor. 'Right'
+    /// or 'End' accept it. This only applies to [`interact_text`](Self::interact_text).
+    ///
+    /// The default is to show no suggestion.
+    #[cfg(any(feature = "completion", feature = "history"))]
+    pub fn ghost_text(mut self, val: bool) -> Self {
+        self.ghost_text = val;
+        self
+    }
+
+    /// Restricts the characters which can be entered.
+    ///
+    /// Characters for which `allowed` returns `false` are ignored when typed and
+    /// removed from pasted text, so the input can't contain them in the first place.
+    /// This only applies to [`interact_text`](Self::interact_text).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Input;
+    ///
+    /// fn main() {
+    ///     let color = Input::<String>::new()
+    ///         .with_prompt("Color (hex)")
+    ///         .allowed_chars(|chr| chr.is_ascii_hexdigit())
+    ///         .interact_text()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn allowed_chars<F>(mut self, allowed: F) -> Self
+    where
+        F: Fn(char) -> bool + 'a,
+    {
+        self.allowed_chars = Some(Arc::new(allowed));
+        self
+    }
+
+    /// Removes the characters which are not allowed from the text.
+    fn allowed_text(&self, text: &str) -> String {
+        match self.allowed_chars {
+            Some(ref allowed) => text.chars().filter(|&chr| allowed(chr)).collect(),
+            None => text.to_owned(),
+        }
+    }
+
+    /// Returns the input in the format of `FromStr`, see [`number_format`](Self::number_format).
+    fn parsable_text<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        match self.number_format {
+            Some(ref format) => Cow::Owned(format.normalize(text)),
+            None => Cow::Borrowed(text),
+        }
+    }
+
+    /// Returns a value or input as it's shown, see [`number_format`](Self::number_format).
+    fn shown_text<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        match self.number_format {
+            Some(ref format) => Cow::Owned(format.format(&format.normalize(text))),
+            None => Cow::Borrowed(text),
+        }
+    }
+
+    /// Returns the continuation of the input suggested by the completion or history.
+    fn suggestion(&self, chars: &[char]) -> Option<String> {
+        #[cfg(any(feature = "completion", feature = "history"))]
+        {
+            if !self.ghost_text || chars.is_empty() {
+                return None;
+            }
+
+            let input: String = chars.iter().collect();
+            let continuation = |text: String| match text.strip_prefix(input.as_str()) {
+                Some(rest) if !rest.is_empty() => Some(rest.to_owned()),
+                _ => None,
+            };
+
+            #[cfg(feature = "completion")]
+            if let Some(suggestion) = self
+                .completion
+                .and_then(|completion| completion.get(&input))
+                .and_then(continuation)
+            {
+                return Some(suggestion);
+            }
+
+            #[cfg(feature = "history")]
+            if let Some(history) = &self.history {
+                let history = history.lock().unwrap();
+
+                return (0..)
+                    .map_while(|pos| history.read(pos))
+                    .find_map(continuation);
+            }
+
+            None
+        }
+
+        #[cfg(not(any(feature = "completion", feature = "history")))]
+        {
+            let _ = chars;
+            None
+        }
+    }
+
+    /// Shows the suggested continuation of the input after the cursor, if the cursor is
+    /// at the end and it fits on the line, and returns it.
+    fn show_ghost_text(
+        &self,
+        render: &mut TermThemeRenderer,
+        prompt_len: usize,
+        chars: &[char],
+        position: usize,
+    ) -> Result<String> {
+        let term = render.term().clone();
+        let suggestion = match self.suggestion(chars) {
+            Some(suggestion) if position == chars.len() => suggestion,
+            _ => return Ok(String::new()),
+        };
+
+        let end = prompt_len + width(chars) + measure_text_width(&suggestion);
+        if suggestion.chars().any(char::is_control) || end >= term.size().1 as usize {
+            return Ok(String::new());
+        }
+
+        let ghost_width = render.input_ghost_text(&suggestion)?;
+        term.move_cursor_left(ghost_width)?;
+        term.flush()?;
+
+        Ok(suggestion)
+    }
+
+    /// Notifies the render observer of the current text.
+    fn observe_render(&self, chars: &[char], position: usize) {
+        self.observers.rendered(PromptState::Input {
+            text: &Text::from(chars.iter().collect::<String>()),
+            cursor: position,
+        });
+    }
 }
 
 impl<'a, T> Input<'a, T>
@@ -260,18 +550,76 @@ where
     {
         let mut old_validator_func = self.validator.take();
 
-        self.validator = Some(Arc::new(Mutex::new(move |value: &T| -> Option<String> {
-            if let Some(old) = old_validator_func.as_mut() {
-                if let Some(err) = old.lock().unwrap()(value) {
-                    return Some(err);
+        self.validator = Some(Arc::new(Mutex::new(
+            move |value: &T| -> Option<Rejection> {
+                let old = old_validator_func
+                    .as_mut()
+                    .and_then(|old| old.lock().unwrap()(value));
+
+                if let Some(Rejection::Error(_)) = old {
+                    return old;
                 }
-            }
 
-            match validator.validate(value) {
-                Ok(()) => None,
-                Err(err) => Some(err.to_string()),
-            }
-        })));
+                match validator.validate(value) {
+                    Ok(()) => old,
+                    Err(err) => Some(Rejection::Error(err.to_string())),
+                }
+            },
+        )));
+
+        self
+    }
+
+    /// Registers a validator which only warns about the input.
+    ///
+    /// If the validator fails, its error is shown as a warning and the user has
+    /// to press 'Enter' or 'y' again to accept the input anyway. Any other key
+    /// goes back to editing the input, and is handled as if pressed there. Errors of validators registered with
+    /// [`validate_with`](Self::validate_with) take precedence over warnings.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::net::ToSocketAddrs;
+    ///
+    /// use dialoguer::Input;
+    ///
+    /// fn main() {
+    ///     let host: String = Input::new()
+    ///         .with_prompt("Hostname")
+    ///         .warn_with(|input: &String| -> Result<(), &str> {
+    ///             match (input.as_str(), 443).to_socket_addrs() {
+    ///                 Ok(_) => Ok(()),
+    ///                 Err(_) => Err("This hostname does not resolve"),
+    ///             }
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn warn_with<V>(mut self, mut validator: V) -> Self
+    where
+        V: InputValidator<T> + 'a,
+        V::Err: ToString,
+    {
+        let mut old_validator_func = self.validator.take();
+
+        self.validator = Some(Arc::new(Mutex::new(
+            move |value: &T| -> Option<Rejection> {
+                let old = old_validator_func
+                    .as_mut()
+                    .and_then(|old| old.lock().unwrap()(value));
+
+                if old.is_some() {
+                    return old;
+                }
+
+                match validator.validate(value) {
+                    Ok(()) => None,
+                    Err(err) => Some(Rejection::Warning(err.to_string())),
+                }
+            },
+        )));
 
         self
     }
@@ -287,21 +635,79 @@ where
     /// Its difference from [`interact`](Self::interact) is that it only allows ascii characters for string,
     /// while [`interact`](Self::interact) allows virtually any character to be used e.g arrow keys.
     ///
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_text_opt`](Self::interact_text_opt) does not allow to quit with 'Esc'.
     pub fn interact_text(self) -> Result<T> {
-        self.interact_text_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_text_on(&term)
+    }
+
+    /// Enables the user to enter a printable ascii sequence and returns the result.
+    ///
+    /// Like [`interact_text`](Self::interact_text) but the user can cancel the prompt
+    /// with 'Esc'. Result contains `Some(value)` if the user submitted the input
+    /// using 'Enter' or `None` if the user cancelled with 'Esc'.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Input;
+    ///
+    /// fn main() {
+    ///     let name = Input::<String>::new()
+    ///         .with_prompt("Your name")
+    ///         .interact_text_opt()
+    ///         .unwrap();
+    ///
+    ///     match name {
+    ///         Some(name) => println!("Hello {}!", name),
+    ///         None => println!("You did not enter a name."),
+    ///     }
+    /// }
+    /// ```
+    pub fn interact_text_opt(self) -> Result<Option<T>> {
+        let term = self.output.term()?;
+        self.interact_text_on_opt(&term)
     }
 
     /// Like [`interact_text`](Self::interact_text) but allows a specific terminal to be set.
-    pub fn interact_text_on(mut self, term: &Term) -> Result<T> {
+    pub fn interact_text_on(self, term: &Term) -> Result<T> {
+        self._interact_text_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_text_opt`](Self::interact_text_opt) but allows a specific terminal to be set.
+    pub fn interact_text_on_opt(self, term: &Term) -> Result<Option<T>> {
+        self._interact_text_on(term, true)
+    }
+
+    /// Like `interact_text_on` but allows to cancel the prompt with 'Esc' if `allow_quit` is set.
+    fn _interact_text_on(self, term: &Term, allow_quit: bool) -> Result<Option<T>> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
         }
 
+        let _span = trace::shown("input", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+        let secret = self.observers.is_redacted();
+
+        trace::answered(self._interact_text(term, allow_quit), |value| {
+            (!secret).then(|| value.to_string())
+        })
+    }
+
+    fn _interact_text(mut self, term: &Term, allow_quit: bool) -> Result<Option<T>> {
         let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut failures = 0;
+        let _paste = BracketedPaste::enable(term)?;
+        // The input the user declined after a warning, and the key which declined it
+        let mut declined: Option<(Chars, Key)> = None;
 
         loop {
-            let default_string = self.default.as_ref().map(ToString::to_string);
+            let default_string = self
+                .default
+                .as_ref()
+                .map(|default| self.shown_text(&default.to_string()).into_owned());
 
             let prompt_len = render.input_prompt(
                 &self.prompt,
@@ -312,31 +718,90 @@ where
                 },
             )?;
 
-            let mut chars: Vec<char> = Vec::new();
+            let mut chars = Chars::default();
             let mut position = 0;
+            let mut undo: Vec<(Chars, usize)> = Vec::new();
+            let mut redo: Vec<(Chars, usize)> = Vec::new();
             #[cfg(feature = "history")]
             let mut hist_pos = 0;
+            let mut pending = None;
 
-            if let Some(initial) = self.initial_text.as_ref() {
+            if let Some((text, key)) = declined.take() {
+                term.write_str(&Text::from(text.iter().collect::<String>()))?;
+                position = text.len();
+                chars = text;
+                pending = Some(key);
+            } else if let Some(initial) = self.initial_text.as_ref() {
                 term.write_str(initial)?;
-                chars = initial.chars().collect();
+                chars = Chars::from(initial.chars().collect::<Vec<_>>());
                 position = chars.len();
             }
             term.flush()?;
+            let mut ghost = self.show_ghost_text(&mut render, prompt_len, &chars, position)?;
+            self.observe_render(&chars, position);
 
             loop {
-                match term.read_key()? {
+                // The key which declined a warning is handled like it was pressed here
+                let key = match pending.take() {
+                    Some(key) => key,
+                    None => self.abort_keys.read_key(term, allow_quit)?,
+                };
+                // The suggestion is cleared and shown again for the changed input
+                let suggestion = mem::take(&mut ghost);
+                if !suggestion.is_empty() {
+                    // The suggestion fits on the line, so it ends where the line is cleared
+                    let ghost_width = measure_text_width(&suggestion);
+                    term.move_cursor_right(ghost_width)?;
+                    term.clear_chars(ghost_width)?;
+                }
+                self.observers.key_pressed(
+                    &key,
+                    PromptState::Input {
+                        text: &Text::from(chars.iter().collect::<String>()),
+                        cursor: position,
+                    },
+                );
+                // Undoing and redoing keep the edit history as it is
+                let undoable = !matches!(key, Key::Char('\x1a' | '\x1f' | '\x19'));
+                let before = (chars.clone(), position);
+
+                match key {
+                    key if allow_quit
+                        && self.abort_keys.contains(&key)
+                        && (key != CTRL_D || chars.is_empty()) =>
+                    {
+                        term.clear_line()?;
+                        render.clear()?;
+                        term.flush()?;
+
+                        return Ok(None);
+                    }
+                    // Ctrl-Z or Ctrl-_ undo the last edit, Ctrl-Y redoes it
+                    Key::Char('\x1a' | '\x1f') if !undo.is_empty() => {
+                        let (text, pos) = undo.pop().unwrap();
+                        replace_text(term, prompt_len, (&chars, position), (&text, pos))?;
+                        redo.push((mem::replace(&mut chars, text), position));
+                        position = pos;
+                    }
+                    Key::Char('\x19') if !redo.is_empty() => {
+                        let (text, pos) = redo.pop().unwrap();
+                        replace_text(term, prompt_len, (&chars, position), (&text, pos))?;
+                        undo.push((mem::replace(&mut chars, text), position));
+                        position = pos;
+                    }
                     Key::Backspace if position > 0 => {
                         position -= 1;
+                        let chr_width = width(&chars[position..=position]);
                         chars.remove(position);
                         let line_size = term.size().1 as usize;
+                        let cursor = prompt_len + width(&chars[..position]);
                         // Case we want to delete last char of a line so the cursor is at the beginning of the next line
-                        if (position + prompt_len) % (line_size - 1) == 0 {
+                        if cursor % (line_size - 1) == 0 {
                             term.clear_line()?;
                             term.move_cursor_up(1)?;
                             term.move_cursor_right(line_size + 1)?;
                         } else {
-                            term.clear_chars(1)?;
+                            term.clear_chars(chr_width)?;
                         }
 
                         let tail: String = chars[position..].iter().collect();
@@ -344,42 +809,68 @@ where
                         if !tail.is_empty() {
                             term.write_str(&tail)?;
 
-                            let total = position + prompt_len + tail.chars().count();
+                            let total = cursor + measure_text_width(&tail);
                             let total_line = total / line_size;
-                            let line_cursor = (position + prompt_len) / line_size;
+                            let line_cursor = cursor / line_size;
                             term.move_cursor_up(total_line - line_cursor)?;
 
                             term.move_cursor_left(line_size)?;
-                            term.move_cursor_right((position + prompt_len) % line_size)?;
+                            term.move_cursor_right(cursor % line_size)?;
                         }
 
                         term.flush()?;
                     }
-                    Key::Char(chr) if !chr.is_ascii_control() => {
+                    Key::Char(chr)
+                        if !chr.is_ascii_control()
+                            && self
+                                .allowed_chars
+                                .as_ref()
+                                .map_or(true, |allowed| allowed(chr)) =>
+                    {
                         chars.insert(position, chr);
                         position += 1;
                         let tail: String =
                             iter::once(&chr).chain(chars[position..].iter()).collect();
                         term.write_str(&tail)?;
-                        term.move_cursor_left(tail.chars().count() - 1)?;
+                        term.move_cursor_left(width(&chars[position..]))?;
                         term.flush()?;
                     }
+                    Key::UnknownEscSeq(seq) if seq == PASTE_START => {
+                        if let Some(text) = read_paste(term)? {
+                            let text = self.allowed_text(&text);
+                            insert_text(term, &mut chars, &mut position, &text)?;
+                        }
+                    }
+                    #[cfg(feature = "clipboard")]
+                    key if is_paste_key(term, &key)? => match clipboard::read() {
+                        Some(text) => {
+                            let text = self.allowed_text(&single_line(&text));
+                            insert_text(term, &mut chars, &mut position, &text)?;
+                        }
+                        None if self.bell => render.bell()?,
+                        None => {}
+                    },
                     Key::ArrowLeft if position > 0 => {
-                        if (position + prompt_len) % term.size().1 as usize == 0 {
+                        if (prompt_len + width(&chars[..position])) % term.size().1 as usize == 0 {
                             term.move_cursor_up(1)?;
                             term.move_cursor_right(term.size().1 as usize)?;
                         } else {
-                            term.move_cursor_left(1)?;
+                            term.move_cursor_left(width(&chars[position - 1..position]))?;
                         }
                         position -= 1;
                         term.flush()?;
                     }
+                    Key::ArrowRight | Key::End if !suggestion.is_empty() => {
+                        insert_text(term, &mut chars, &mut position, &suggestion)?;
+                    }
                     Key::ArrowRight if position < chars.len() => {
-                        if (position + prompt_len) % (term.size().1 as usize - 1) == 0 {
+                        if (prompt_len + width(&chars[..position])) % (term.size().1 as usize - 1)
+                            == 0
+                        {
                             term.move_cursor_down(1)?;
                             term.move_cursor_left(term.size().1 as usize)?;
                         } else {
-                            term.move_cursor_right(1)?;
+                            term.move_cursor_right(width(&chars[position..=position]))?;
                         }
                         position += 1;
                         term.flush()?;
@@ -399,15 +890,18 @@ where
                         if let Some(mut last_space) = find_last_space {
                             if last_space < position {
                                 last_space += 1;
-                                let new_line = (prompt_len + last_space) / line_size;
-                                let old_line = (prompt_len + position) / line_size;
+                                let new_line =
+                                    (prompt_len + width(&chars[..last_space])) / line_size;
+                                let old_line = (prompt_len + width(&chars[..position])) / line_size;
                                 let diff_line = old_line - new_line;
                                 if diff_line != 0 {
                                     term.move_cursor_up(old_line - new_line)?;
                                 }
 
-                                let new_pos_x = (prompt_len + last_space) % line_size;
-                                let old_pos_x = (prompt_len + position) % line_size;
+                                let new_pos_x =
+                                    (prompt_len + width(&chars[..last_space])) % line_size;
+                                let old_pos_x =
+                                    (prompt_len + width(&chars[..position])) % line_size;
                                 let diff_pos_x = new_pos_x as i64 - old_pos_x as i64;
                                 //println!("new_pos_x = {}, old_pos_x = {}, diff = {}", new_pos_x, old_pos_x, diff_pos_x);
                                 if diff_pos_x < 0 {
@@ -418,7 +912,7 @@ where
                                 position = last_space;
                             }
                         } else {
-                            term.move_cursor_left(position)?;
+                            term.move_cursor_left(width(&chars[..position]))?;
                             position = 0;
                         }
 
@@ -436,12 +930,14 @@ where
                                 .take_while(|c| c.is_whitespace())
                                 .count();
                             next_space += nb_space;
-                            let new_line = (prompt_len + position + next_space) / line_size;
-                            let old_line = (prompt_len + position) / line_size;
+                            let new_line =
+                                (prompt_len + width(&chars[..position + next_space])) / line_size;
+                            let old_line = (prompt_len + width(&chars[..position])) / line_size;
                             term.move_cursor_down(new_line - old_line)?;
 
-                            let new_pos_x = (prompt_len + position + next_space) % line_size;
-                            let old_pos_x = (prompt_len + position) % line_size;
+                            let new_pos_x =
+                                (prompt_len + width(&chars[..position + next_space])) % line_size;
+                            let old_pos_x = (prompt_len + width(&chars[..position])) % line_size;
                             let diff_pos_x = new_pos_x as i64 - old_pos_x as i64;
                             if diff_pos_x < 0 {
                                 term.move_cursor_left(-diff_pos_x as usize)?;
@@ -450,12 +946,12 @@ where
                             }
                             position += next_space;
                         } else {
-                            let new_line = (prompt_len + chars.len()) / line_size;
-                            let old_line = (prompt_len + position) / line_size;
+                            let new_line = (prompt_len + width(&chars)) / line_size;
+                            let old_line = (prompt_len + width(&chars[..position])) / line_size;
                             term.move_cursor_down(new_line - old_line)?;
 
-                            let new_pos_x = (prompt_len + chars.len()) % line_size;
-                            let old_pos_x = (prompt_len + position) % line_size;
+                            let new_pos_x = (prompt_len + width(&chars)) % line_size;
+                            let old_pos_x = (prompt_len + width(&chars[..position])) % line_size;
                             let diff_pos_x = new_pos_x as i64 - old_pos_x as i64;
                             match diff_pos_x.cmp(&0) {
                                 Ordering::Less => {
@@ -474,9 +970,9 @@ where
                     #[cfg(feature = "completion")]
                     Key::ArrowRight | Key::Tab => {
                         if let Some(completion) = &self.completion {
-                            let input: String = chars.clone().into_iter().collect();
+                            let input: String = chars.iter().collect();
                             if let Some(x) = completion.get(&input) {
-                                term.clear_chars(chars.len())?;
+                                term.clear_chars(width(&chars))?;
                                 chars.clear();
                                 position = 0;
                                 for ch in x.chars() {
@@ -494,7 +990,7 @@ where
                         if let Some(history) = &self.history {
                             if let Some(previous) = history.lock().unwrap().read(hist_pos) {
                                 hist_pos += 1;
-                                let mut chars_len = chars.len();
+                                let mut chars_len = width(&chars);
                                 while ((prompt_len + chars_len) / line_size) > 0 {
                                     term.clear_chars(chars_len)?;
                                     if (prompt_len + chars_len) % line_size == 0 {
@@ -526,7 +1022,7 @@ where
                     Key::ArrowDown => {
                         let line_size = term.size().1 as usize;
                         if let Some(history) = &self.history {
-                            let mut chars_len = chars.len();
+                            let mut chars_len = width(&chars);
                             while ((prompt_len + chars_len) / line_size) > 0 {
                                 term.clear_chars(chars_len)?;
                                 if (prompt_len + chars_len) % line_size == 0 {
@@ -564,10 +1060,22 @@ where
                         }
                     }
                     Key::Enter => break,
-                    _ => (),
+                    _ => {
+                        if self.bell {
+                            render.bell()?;
+                        }
+                    }
                 }
+
+                if undoable && chars != before.0 {
+                    undo.push(before);
+                    redo.clear();
+                }
+
+                ghost = self.show_ghost_text(&mut render, prompt_len, &chars, position)?;
+                self.observe_render(&chars, position);
             }
-            let input = chars.iter().collect::<String>();
+            let input = Text::from(chars.iter().collect::<String>());
 
             term.clear_line()?;
             render.clear()?;
@@ -575,23 +1083,42 @@ where
             if chars.is_empty() {
                 if let Some(ref default) = self.default {
                     if let Some(ref mut validator) = self.validator {
-                        if let Some(err) = validator.lock().unwrap()(default) {
-                            render.error(&err)?;
-                            continue;
+                        match validator.lock().unwrap()(default) {
+                            Some(Rejection::Error(err)) => {
+                                if self.bell {
+                                    render.bell()?;
+                                }
+                                render.error(&err)?;
+                                count_failure(&mut failures, self.max_retries, err)?;
+                                continue;
+                            }
+                            Some(Rejection::Warning(warning)) => {
+                                if let Some(key) = decline_warning(&mut render, term, &warning)? {
+                                    declined = Some((chars, key));
+                                    continue;
+                                }
+                            }
+                            _ => {}
                         }
                     }
 
                     if self.report {
-                        render.input_prompt_selection(&self.prompt, &default.to_string())?;
+                        render.input_prompt_selection(
+                            &self.prompt,
+                            &self.shown_text(&default.to_string()),
+                        )?;
                     }
                     term.flush()?;
-                    return Ok(default.clone());
+                    return Ok(Some(default.clone()));
                 } else if !self.permit_empty {
+                    if self.bell {
+                        render.bell()?;
+                    }
                     continue;
                 }
             }
 
-            match input.parse::<T>() {
+            match self.parsable_text(&input).parse::<T>() {
                 Ok(value) => {
                     #[cfg(feature = "history")]
                     if let Some(history) = &mut self.history {
@@ -599,25 +1126,46 @@ where
                     }
 
                     if let Some(ref mut validator) = self.validator {
-                        if let Some(err) = validator.lock().unwrap()(&value) {
-                            render.error(&err)?;
-                            continue;
+                        match validator.lock().unwrap()(&value) {
+                            Some(Rejection::Error(err)) => {
+                                if self.bell {
+                                    render.bell()?;
+                                }
+                                render.error(&err)?;
+                                count_failure(&mut failures, self.max_retries, err)?;
+                                continue;
+                            }
+                            Some(Rejection::Warning(warning)) => {
+                                if let Some(key) = decline_warning(&mut render, term, &warning)? {
+                                    declined = Some((chars, key));
+                                    continue;
+                                }
+                            }
+                            _ => {}
                         }
                     }
 
                     if self.report {
                         if let Some(post_completion_text) = &self.post_completion_text {
-                            render.input_prompt_selection(post_completion_text, &input)?;
+                            render.input_prompt_selection(
+                                post_completion_text,
+                                &self.shown_text(&input),
+                            )?;
                         } else {
-                            render.input_prompt_selection(&self.prompt, &input)?;
+                            render
+                                .input_prompt_selection(&self.prompt, &self.shown_text(&input))?;
                         }
                     }
                     term.flush()?;
 
-                    return Ok(value);
+                    return Ok(Some(value));
                 }
                 Err(err) => {
+                    if self.bell {
+                        render.bell()?;
+                    }
                     render.error(&err.to_string())?;
+                    count_failure(&mut failures, self.max_retries, err.to_string())?;
                     continue;
                 }
             }
@@ -631,21 +1179,39 @@ where
     /// For more limited version, see [`interact_text`](Self::interact_text).
     ///
     /// If the user confirms the result is `true`, `false` otherwise.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     pub fn interact(self) -> Result<T> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
-    pub fn interact_on(mut self, term: &Term) -> Result<T> {
+    pub fn interact_on(self, term: &Term) -> Result<T> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
         }
 
+        let _span = trace::shown("input", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact_line(term).map(Some), |value| {
+            Some(value.to_string())
+        })?
+        .ok_or(Error::Cancelled)
+    }
+
+    fn _interact_line(mut self, term: &Term) -> Result<T> {
         let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut failures = 0;
+        // The input the user declined after a warning, which is edited again
+        let mut declined: Option<String> = None;
 
         loop {
-            let default_string = self.default.as_ref().map(ToString::to_string);
+            let default_string = self
+                .default
+                .as_ref()
+                .map(|default| self.shown_text(&default.to_string()).into_owned());
 
             render.input_prompt(
                 &self.prompt,
@@ -657,10 +1223,19 @@ where
             )?;
             term.flush()?;
 
-            let input = if let Some(initial_text) = self.initial_text.as_ref() {
-                term.read_line_initial_text(initial_text)?
-            } else {
+            let initial_text = match declined.take() {
+                Some(text) => text,
+                None => self.initial_text.clone().unwrap_or_default(),
+            };
+            self.observers.rendered(PromptState::Input {
+                text: &initial_text,
+                cursor: initial_text.chars().count(),
+            });
+
+            let input = if initial_text.is_empty() {
                 term.read_line()?
+            } else {
+                term.read_line_initial_text(&initial_text)?
             };
 
             render.add_line();
@@ -670,40 +1245,76 @@ where
             if input.is_empty() {
                 if let Some(ref default) = self.default {
                     if let Some(ref mut validator) = self.validator {
-                        if let Some(err) = validator.lock().unwrap()(default) {
-                            render.error(&err)?;
-                            continue;
+                        match validator.lock().unwrap()(default) {
+                            Some(Rejection::Error(err)) => {
+                                if self.bell {
+                                    render.bell()?;
+                                }
+                                render.error(&err)?;
+                                count_failure(&mut failures, self.max_retries, err)?;
+                                continue;
+                            }
+                            Some(Rejection::Warning(warning)) => {
+                                if let Some(key) = decline_warning(&mut render, term, &warning)? {
+                                    declined = Some(retried_text(&input, &key));
+                                    continue;
+                                }
+                            }
+                            _ => {}
                         }
                     }
 
                     if self.report {
-                        render.input_prompt_selection(&self.prompt, &default.to_string())?;
+                        render.input_prompt_selection(
+                            &self.prompt,
+                            &self.shown_text(&default.to_string()),
+                        )?;
                     }
                     term.flush()?;
                     return Ok(default.clone());
                 } else if !self.permit_empty {
+                    if self.bell {
+                        render.bell()?;
+                    }
                     continue;
                 }
             }
 
-            match input.parse::<T>() {
+            match self.parsable_text(&input).parse::<T>() {
                 Ok(value) => {
                     if let Some(ref mut validator) = self.validator {
-                        if let Some(err) = validator.lock().unwrap()(&value) {
-                            render.error(&err)?;
-                            continue;
+                        match validator.lock().unwrap()(&value) {
+                            Some(Rejection::Error(err)) => {
+                                if self.bell {
+                                    render.bell()?;
+                                }
+                                render.error(&err)?;
+                                count_failure(&mut failures, self.max_retries, err)?;
+                                continue;
+                            }
+                            Some(Rejection::Warning(warning)) => {
+                                if let Some(key) = decline_warning(&mut render, term, &warning)? {
+                                    declined = Some(retried_text(&input, &key));
+                                    continue;
+                                }
+                            }
+                            _ => {}
                         }
                     }
 
                     if self.report {
-                        render.input_prompt_selection(&self.prompt, &input)?;
+                        render.input_prompt_selection(&self.prompt, &self.shown_text(&input))?;
                     }
                     term.flush()?;
 
                     return Ok(value);
                 }
                 Err(err) => {
+                    if self.bell {
+                        render.bell()?;
+                    }
                     render.error(&err.to_string())?;
+                    count_failure(&mut failures, self.max_retries, err.to_string())?;
                     continue;
                 }
             }
@@ -711,6 +1322,162 @@ where
     }
 }
 
+#[cfg(feature = "password")]
+impl Input<'_, String> {
+    /// Enables the user to enter sensitive text like an API key and returns it.
+    ///
+    /// Like [`interact_text`](Self::interact_text), but the text is hidden in the
+    /// report and not written to the history. The buffers holding the text are
+    /// zeroized once they are no longer needed, like the returned text.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Input;
+    ///
+    /// fn main() {
+    ///     let token = Input::new()
+    ///         .with_prompt("API token")
+    ///         .interact_secret()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn interact_secret(self) -> Result<Zeroizing<String>> {
+        let term = self.output.term()?;
+        self.interact_secret_on(&term)
+    }
+
+    /// Like [`interact_secret`](Self::interact_secret) but allows a specific terminal to be set.
+    pub fn interact_secret_on(mut self, term: &Term) -> Result<Zeroizing<String>> {
+        let report = mem::replace(&mut self.report, false);
+        #[cfg(feature = "history")]
+        {
+            self.history = None;
+        }
+        self.observers.redact();
+
+        let prompt = self.prompt.clone();
+        let theme = self.theme;
+        let text = Zeroizing::new(self.interact_text_on(term)?);
+
+        if report {
+            TermThemeRenderer::new(term, theme).password_prompt_selection(&prompt)?;
+            term.flush()?;
+        }
+
+        Ok(text)
+    }
+}
+
+/// Shows a validation warning and returns the key with which the user declined
+/// the input, or `None` if the user accepts it anyway.
+fn decline_warning(
+    render: &mut TermThemeRenderer,
+    term: &Term,
+    warning: &str,
+) -> Result<Option<Key>> {
+    render.warning(warning)?;
+    term.flush()?;
+
+    match term.read_key()? {
+        Key::Enter | Key::Char('y' | 'Y') => {
+            render.clear()?;
+            Ok(None)
+        }
+        key => Ok(Some(key)),
+    }
+}
+
+/// Returns the text to edit again after the user declined `input` with `key`.
+///
+/// A line is read as a whole, so only a typed character is kept from the key.
+fn retried_text(input: &str, key: &Key) -> String {
+    match key {
+        Key::Char(chr) if !chr.is_ascii_control() => format!("{input}{chr}"),
+        _ => input.to_string(),
+    }
+}
+
+/// Inserts text at the cursor position and moves the cursor behind it.
+fn insert_text(term: &Term, chars: &mut Vec<char>, position: &mut usize, text: &str) -> Result {
+    let start = *position;
+    chars.splice(start..start, text.chars());
+    *position += text.chars().count();
+
+    let tail: String = chars[start..].iter().collect();
+    term.write_str(&tail)?;
+    term.move_cursor_left(width(&chars[*position..]))?;
+    term.flush()?;
+
+    Ok(())
+}
+
+/// Replaces the rendered text of the input and moves the cursor to its new position.
+///
+/// Both the old and the new text are given with the position of the cursor in it.
+fn replace_text(
+    term: &Term,
+    prompt_len: usize,
+    old: (&[char], usize),
+    new: (&[char], usize),
+) -> Result {
+    let line_size = term.size().1 as usize;
+
+    // Moves the cursor to the start of the text and clears the lines the text wrapped onto
+    let cursor = prompt_len + width(&old.0[..old.1]);
+    term.move_cursor_up(cursor / line_size)?;
+    term.move_cursor_left(line_size)?;
+    term.move_cursor_right(prompt_len)?;
+    term.clear_to_end_of_screen()?;
+
+    let text: String = new.0.iter().collect();
+    term.write_str(&text)?;
+
+    let end = prompt_len + width(new.0);
+    let cursor = prompt_len + width(&new.0[..new.1]);
+    term.move_cursor_up(end / line_size - cursor / line_size)?;
+    term.move_cursor_left(line_size)?;
+    term.move_cursor_right(cursor % line_size)?;
+    term.flush()?;
+
+    Ok(())
+}
+
+/// Returns the number of terminal columns the characters take up, e.g. two for
+/// a wide CJK character.
+fn width(chars: &[char]) -> usize {
+    measure_text_width(&chars.iter().collect::<String>())
+}
+
+/// Counts a rejected input and fails once the maximum number of retries is reached.
+fn count_failure(failures: &mut usize, max_retries: Option<usize>, err: String) -> Result {
+    trace::validation_failed(&err);
+    *failures += 1;
+
+    match max_retries {
+        Some(max_retries) if *failures >= max_retries => Err(Error::ValidationFailed(err)),
+        _ => Ok(()),
+    }
+}
+
+/// Interaction uses [`interact_text`](Input::interact_text) and
+/// [`interact_text_on`](Input::interact_text_on).
+impl<T> Prompt for Input<'_, T>
+where
+    T: Clone + ToString + FromStr,
+    <T as FromStr>::Err: ToString,
+{
+    type Output = T;
+
+    fn interact_on(&mut self, term: &Term) -> Result<T> {
+        self.clone().interact_text_on(term)
+    }
+
+    fn interact(&mut self) -> Result<T> {
+        self.clone().interact_text()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -721,4 +1488,50 @@ mod tests {
 
         let _ = input.clone();
     }
+
+    #[test]
+    fn test_warn_with() {
+        let input = Input::<u16>::new()
+            .warn_with(|port: &u16| {
+                if *port < 1024 {
+                    Err("privileged")
+                } else {
+                    Ok(())
+                }
+            })
+            .validate_with(|port: &u16| if *port == 0 { Err("invalid") } else { Ok(()) });
+        let mut validator = input.validator.as_ref().unwrap().lock().unwrap();
+
+        assert!(matches!(validator(&0), Some(Rejection::Error(err)) if err == "invalid"));
+        assert!(matches!(validator(&80), Some(Rejection::Warning(w)) if w == "privileged"));
+        assert!(validator(&8080).is_none());
+    }
+
+    #[test]
+    fn test_allowed_chars() {
+        let input = Input::<String>::new().allowed_chars(|chr| chr.is_ascii_digit());
+
+        assert_eq!(input.allowed_text("+1 (555) 0123"), "15550123");
+        assert_eq!(Input::<String>::new().allowed_text("a b"), "a b");
+    }
+
+    #[cfg(feature = "completion")]
+    #[test]
+    fn test_suggestion() {
+        struct Fruits;
+
+        impl Completion for Fruits {
+            fn get(&self, input: &str) -> Option<String> {
+                "orange".starts_with(input).then(|| "orange".to_owned())
+            }
+        }
+
+        let input = Input::<String>::new().completion_with(&Fruits);
+        assert_eq!(input.suggestion(&['o', 'r']), None);
+
+        let input = input.ghost_text(true);
+        assert_eq!(input.suggestion(&['o', 'r']), Some("ange".to_owned()));
+        assert_eq!(input.suggestion(&['o', 'x']), None);
+        assert_eq!(input.suggestion(&[]), None);
+    }
 }
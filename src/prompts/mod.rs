@@ -1,13 +1,467 @@
 #![allow(clippy::needless_doctest_main)]
 
+#[cfg(unix)]
+use std::fs;
+use std::{
+    env, io, panic,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use console::{Key, Term};
+
+use crate::{
+    signals::Cleanup,
+    theme::{render::TermThemeRenderer, Theme},
+    Result,
+};
+
+pub mod cascade_select;
 pub mod confirm;
+pub mod currency_input;
+pub mod file_picker;
 pub mod input;
 pub mod multi_select;
+pub mod range_slider;
 pub mod select;
 pub mod sort;
+pub mod text_area;
 
 #[cfg(feature = "fuzzy-select")]
 pub mod fuzzy_select;
 
 #[cfg(feature = "password")]
 pub mod password;
+
+/// A prompt which can be interacted with on a terminal.
+///
+/// This trait is implemented by all prompts, which allows to handle them uniformly,
+/// e.g. in generic helpers or as trait objects. Unlike the inherent methods of the
+/// prompts, [`interact_on`](Self::interact_on) takes the prompt by reference, so it
+/// can be interacted with again.
+///
+/// Every interaction starts from a fresh copy of the prompt as it was built, so
+/// changes made while it is shown don't carry over to the next interaction, e.g.
+/// items pinned in a [`Select`](crate::Select), loaded with
+/// [`load_more`](crate::Select::load_more) or changed by key actions. State which is
+/// shared with the application, like the history of an [`Input`](crate::Input), is
+/// kept, so changes can be carried over with callbacks like
+/// [`on_pins_changed`](crate::Select::on_pins_changed).
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{console::Term, Confirm, Prompt, Select};
+///
+/// fn main() {
+///     let mut prompts: Vec<Box<dyn Prompt<Output = usize>>> = vec![
+///         Box::new(Select::new().with_prompt("Pick a fruit").items(["Apple", "Pear"])),
+///         Box::new(Select::new().with_prompt("Pick a color").items(["Red", "Green"])),
+///     ];
+///
+///     let term = Term::stderr();
+///     for prompt in &mut prompts {
+///         println!("You picked #{}", prompt.interact_on(&term).unwrap());
+///     }
+///
+///     while Confirm::new().with_prompt("Pick another color?").interact().unwrap() {
+///         prompts[1].interact_on(&term).unwrap();
+///     }
+/// }
+/// ```
+pub trait Prompt {
+    /// The value which is returned on interaction.
+    type Output;
+
+    /// Enables user interaction on the given terminal and returns the result.
+    fn interact_on(&mut self, term: &Term) -> Result<Self::Output>;
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// The dialog is rendered on the output of the prompt, stderr by default.
+    fn interact(&mut self) -> Result<Self::Output>;
+}
+
+/// The terminal a prompt is rendered on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Target {
+    /// Renders the prompt on stderr.
+    #[default]
+    Stderr,
+    /// Renders the prompt on stdout.
+    Stdout,
+    /// Renders the prompt on the controlling terminal, even if stdout and stderr
+    /// are redirected.
+    ///
+    /// Only supported on unix, other platforms render on stderr.
+    Tty,
+}
+
+/// The outcome of a prompt which watches keys.
+///
+/// Returned by the `interact_outcome` methods of list prompts, e.g.
+/// [`Select::interact_outcome`](crate::Select::interact_outcome).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// The user answered the prompt.
+    Answered(T),
+    /// The user pressed a watched key while the item at `highlighted` was highlighted, if any.
+    Key {
+        key: Key,
+        highlighted: Option<usize>,
+    },
+    /// The user cancelled the prompt.
+    Cancelled,
+}
+
+impl Target {
+    /// Opens the terminal of the target.
+    pub fn term(self) -> io::Result<Term> {
+        match self {
+            Target::Stderr => Ok(Term::stderr()),
+            Target::Stdout => Ok(Term::stdout()),
+            #[cfg(unix)]
+            Target::Tty => {
+                let tty = fs::OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open("/dev/tty")?;
+
+                Ok(Term::read_write_pair(tty.try_clone()?, tty))
+            }
+            #[cfg(not(unix))]
+            Target::Tty => Ok(Term::stderr()),
+        }
+    }
+}
+
+/// The start of a bracketed paste `ESC [ 200 ~`, which [`Term::read_key`] reads as
+/// an unknown escape sequence followed by the characters '0' and '~'.
+pub(crate) const PASTE_START: [char; 3] = ['[', '2', '0'];
+
+/// Ctrl-D, which cancels prompts by default like the end of input in shells.
+pub(crate) const CTRL_D: Key = Key::Char('\x04');
+
+/// Ctrl-Z, which suspends the process like in shells.
+///
+/// Prompts read keys in raw mode, so they receive it as a key instead of the
+/// terminal sending `SIGTSTP`.
+pub(crate) const CTRL_Z: Key = Key::Char('\x1a');
+
+/// Shows the prompt on the alternate screen of the terminal while it is alive,
+/// which leaves the previous output untouched.
+pub(crate) struct AltScreen<'a>(&'a Term);
+
+impl<'a> AltScreen<'a> {
+    pub(crate) fn enable(term: &'a Term) -> io::Result<Self> {
+        term.write_str("\x1b[?1049h\x1b[H")?;
+
+        Ok(Self(term))
+    }
+}
+
+impl Drop for AltScreen<'_> {
+    fn drop(&mut self) {
+        let _ = self.0.write_str("\x1b[?1049l");
+        let _ = self.0.flush();
+    }
+}
+
+/// Enables bracketed paste while it is alive, so that pasted text can be told
+/// apart from typed keys.
+pub(crate) struct BracketedPaste<'a>(&'a Term);
+
+impl<'a> BracketedPaste<'a> {
+    pub(crate) fn enable(term: &'a Term) -> io::Result<Self> {
+        if cfg!(unix) {
+            term.write_str("\x1b[?2004h")?;
+        }
+
+        Ok(Self(term))
+    }
+}
+
+impl Drop for BracketedPaste<'_> {
+    fn drop(&mut self) {
+        if cfg!(unix) {
+            let _ = self.0.write_str("\x1b[?2004l");
+            let _ = self.0.flush();
+        }
+    }
+}
+
+/// Reads the text of a bracketed paste after [`PASTE_START`] was read.
+///
+/// The text is turned into a [`single_line`]. Returns `None` if the keys turn out
+/// not to start a paste.
+pub(crate) fn read_paste(term: &Term) -> io::Result<Option<String>> {
+    Ok(read_paste_lines(term)?.map(|text| single_line(&text)))
+}
+
+/// Like [`read_paste`], but keeps the line breaks and tabs of the text.
+pub(crate) fn read_paste_lines(term: &Term) -> io::Result<Option<String>> {
+    if term.read_key()? != Key::Char('0') || term.read_key()? != Key::Char('~') {
+        return Ok(None);
+    }
+
+    let mut text = String::new();
+
+    loop {
+        match term.read_key()? {
+            // The end of the paste `ESC [ 201 ~`
+            Key::UnknownEscSeq(seq)
+                if seq == PASTE_START
+                    && term.read_key()? == Key::Char('1')
+                    && term.read_key()? == Key::Char('~') =>
+            {
+                break;
+            }
+            Key::Enter => text.push('\n'),
+            Key::Tab => text.push('\t'),
+            Key::Char(chr) => text.push(chr),
+            _ => {}
+        }
+    }
+
+    Ok(Some(text))
+}
+
+/// Turns pasted text into a single line, since prompts take a single line of text.
+///
+/// A trailing line break is dropped, other line breaks and tabs are replaced by
+/// spaces and control characters are removed.
+pub(crate) fn single_line(text: &str) -> String {
+    single_line_chars(text).collect()
+}
+
+/// Like [`single_line`], but returns the characters without collecting them.
+pub(crate) fn single_line_chars(text: &str) -> impl Iterator<Item = char> + '_ {
+    let text = text.strip_suffix('\n').unwrap_or(text);
+    let text = text.strip_suffix('\r').unwrap_or(text);
+
+    text.chars().filter_map(|chr| match chr {
+        '\n' | '\t' => Some(' '),
+        chr if chr.is_control() => None,
+        chr => Some(chr),
+    })
+}
+
+/// Checks whether `key` pastes the clipboard, i.e. is Ctrl-V or Shift-Insert.
+///
+/// Shift-Insert `ESC [ 2 ; 2 ~` is read by [`Term::read_key`] as an unknown escape
+/// sequence followed by the characters '2' and '~', which are read here.
+#[cfg(feature = "clipboard")]
+pub(crate) fn is_paste_key(term: &Term, key: &Key) -> io::Result<bool> {
+    match key {
+        Key::Char('\x16') => Ok(true),
+        Key::UnknownEscSeq(seq) if seq == &['[', '2', ';'] => {
+            Ok(term.read_key()? == Key::Char('2') && term.read_key()? == Key::Char('~'))
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Runs `fetch` on a background thread and calls `tick` with the tick of a
+/// spinner until it is done.
+pub(crate) fn fetch_on_thread<R, F>(fetch: F, tick: &mut dyn FnMut(usize) -> Result) -> Result<R>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    thread::scope(|scope| {
+        let fetching = scope.spawn(fetch);
+
+        let mut ticks = 0;
+        while !fetching.is_finished() {
+            tick(ticks)?;
+            ticks += 1;
+            thread::sleep(Duration::from_millis(80));
+        }
+
+        Ok(fetching
+            .join()
+            .unwrap_or_else(|err| panic::resume_unwind(err)))
+    })
+}
+
+/// Renders `prompt` with a loading spinner in place of the items while `fetch`
+/// fetches them, and clears it afterwards.
+///
+/// `fetch` gets the function which renders a tick of the spinner.
+pub(crate) fn render_fetching<R, F>(
+    term: &Term,
+    theme: &dyn Theme,
+    prompt: Option<&str>,
+    fetch: F,
+) -> Result<R>
+where
+    F: FnOnce(&mut dyn FnMut(usize) -> Result) -> Result<R>,
+{
+    let _cleanup = Cleanup::install(term);
+    let mut render = TermThemeRenderer::new(term, theme);
+
+    term.hide_cursor()?;
+
+    let fetched = fetch(&mut |tick| {
+        render.begin_frame();
+        render.clear()?;
+
+        if let Some(prompt) = prompt {
+            render.select_prompt(prompt, None)?;
+        }
+
+        render.loading_spinner(tick)?;
+        render.end_frame()
+    });
+
+    render.clear()?;
+    term.show_cursor()?;
+    term.flush()?;
+
+    fetched
+}
+
+/// Checks whether the accessible mode of list prompts is requested by the
+/// `DIALOGUER_ACCESSIBLE` environment variable.
+pub(crate) fn accessible_from_env() -> bool {
+    env::var_os("DIALOGUER_ACCESSIBLE").map_or(false, |value| !value.is_empty() && value != "0")
+}
+
+/// The keys which cancel a prompt.
+#[derive(Clone)]
+pub(crate) struct AbortKeys(Vec<Key>);
+
+impl AbortKeys {
+    pub(crate) fn new<I: IntoIterator<Item = Key>>(keys: I) -> Self {
+        Self(keys.into_iter().collect())
+    }
+
+    pub(crate) fn contains(&self, key: &Key) -> bool {
+        self.0.contains(key)
+    }
+
+    /// Adds a key which cancels the prompt, unless it already does.
+    pub(crate) fn add(&mut self, key: Key) {
+        if !self.contains(&key) {
+            self.0.push(key);
+        }
+    }
+
+    /// Reads a key from the terminal.
+    ///
+    /// If the prompt can be cancelled with Ctrl-C, it is read as [`Key::CtrlC`]
+    /// instead of interrupting the process.
+    pub(crate) fn read_key(&self, term: &Term, allow_quit: bool) -> io::Result<Key> {
+        if allow_quit && self.contains(&Key::CtrlC) {
+            term.read_key_raw()
+        } else {
+            term.read_key()
+        }
+    }
+}
+
+/// A watched key and the highlighted item when it was pressed.
+type PressedKey = (Key, Option<usize>);
+
+/// The keys which end a prompt with [`Outcome::Key`].
+///
+/// The keys are only watched once the outcome is asked for, so that the other
+/// `interact` methods are unaffected.
+#[derive(Clone, Default)]
+pub(crate) struct WatchKeys {
+    keys: Vec<Key>,
+    active: bool,
+    pressed: Arc<Mutex<Option<PressedKey>>>,
+}
+
+impl WatchKeys {
+    pub(crate) fn new<I: IntoIterator<Item = Key>>(keys: I) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Starts watching the keys and returns a handle for the [`outcome`](Self::outcome),
+    /// which outlives prompts that are consumed by the interaction.
+    pub(crate) fn activate(&mut self) -> Self {
+        self.active = true;
+        self.clone()
+    }
+
+    pub(crate) fn contains(&self, key: &Key) -> bool {
+        self.active && self.keys.contains(key)
+    }
+
+    /// Records the watched key which ended the prompt.
+    pub(crate) fn record(&self, key: Key, highlighted: Option<usize>) {
+        *self.pressed.lock().unwrap() = Some((key, highlighted));
+    }
+
+    /// Returns the outcome of a prompt which returned `answer`.
+    pub(crate) fn outcome<T>(&self, answer: Option<T>) -> Outcome<T> {
+        match (answer, self.pressed.lock().unwrap().take()) {
+            (Some(answer), _) => Outcome::Answered(answer),
+            (None, Some((key, highlighted))) => Outcome::Key { key, highlighted },
+            (None, None) => Outcome::Cancelled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Confirm, Error, Select};
+
+    #[test]
+    fn test_trait_object() {
+        let mut prompts: Vec<Box<dyn Prompt<Output = usize>>> = vec![
+            Box::new(Select::new().items(["a", "b"])),
+            Box::new(Select::new().items(["c", "d"])),
+        ];
+        prompts.push(Box::new(Select::new().items(["e"])));
+
+        let term = Term::buffered_stderr();
+        for prompt in &mut prompts {
+            assert!(matches!(
+                prompt.interact_on(&term),
+                Err(Error::NotATerminal)
+            ));
+        }
+
+        let mut confirm = Confirm::new();
+        assert!(matches!(
+            Prompt::interact_on(&mut confirm, &term),
+            Err(Error::NotATerminal)
+        ));
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(single_line("token\n"), "token");
+        assert_eq!(single_line("one\r\ntwo\tthree\r\n"), "one two three");
+        assert_eq!(single_line("a\x07b"), "ab");
+    }
+
+    #[test]
+    fn test_watch_keys() {
+        let mut watch_keys = WatchKeys::new([Key::Char('d')]);
+        assert!(!watch_keys.contains(&Key::Char('d')));
+
+        let handle = watch_keys.activate();
+        assert!(watch_keys.contains(&Key::Char('d')));
+        assert!(!watch_keys.contains(&Key::Char('e')));
+
+        watch_keys.record(Key::Char('d'), Some(2));
+        assert_eq!(
+            handle.outcome::<usize>(None),
+            Outcome::Key {
+                key: Key::Char('d'),
+                highlighted: Some(2)
+            }
+        );
+        assert_eq!(handle.outcome::<usize>(None), Outcome::Cancelled);
+        assert_eq!(handle.outcome(Some(1)), Outcome::Answered(1));
+    }
+}
@@ -1,12 +1,35 @@
-use std::{io, iter::repeat, ops::Rem};
+use std::{
+    borrow::Cow,
+    iter::repeat,
+    mem,
+    ops::Rem,
+    path::Path,
+    sync::{Arc, Mutex},
+};
 
-use console::{Key, Term};
+use console::{measure_text_width, Key, Term};
 
 use crate::{
+    filter::filter_items,
+    item_list::{rearrange, ItemList},
+    memory::Memory,
+    observe::{Observers, PromptState},
+    prompts::{
+        accessible_from_env, AbortKeys, AltScreen, Outcome, Prompt, Target, WatchKeys, CTRL_D,
+        CTRL_Z,
+    },
+    signals::{suspend, Cleanup},
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
-    Paging, Result,
+    trace, translate,
+    validate::InputValidator,
+    Error, Paging, Result, ScrollMode,
 };
 
+type ReportCallback<'a> = Arc<dyn Fn(&[&str]) -> String + 'a>;
+type SelectionsValidatorCallback<'a> = Arc<Mutex<dyn FnMut(&[usize]) -> Option<String> + 'a>>;
+type MultiSelectKeyActionCallback<'a, T> =
+    Arc<Mutex<dyn FnMut(&mut ItemList<'a, T>) -> Result + 'a>>;
+
 /// Renders a multi select prompt.
 ///
 /// ## Example
@@ -30,31 +53,66 @@ use crate::{
 ///     }
 /// }
 /// ```
+///
+/// Items can also carry a value which is returned instead of the index, see
+/// [`item_with_value`](Self::item_with_value).
 #[derive(Clone)]
-pub struct MultiSelect<'a> {
+pub struct MultiSelect<'a, T = ()> {
     defaults: Vec<bool>,
-    items: Vec<String>,
-    prompt: Option<String>,
+    pub(crate) items: Vec<Cow<'a, str>>,
+    values: Vec<T>,
+    descriptions: Vec<Option<String>>,
+    pub(crate) prompt: Option<String>,
     report: bool,
+    report_with: Option<ReportCallback<'a>>,
+    preserve_selection_order: bool,
     clear: bool,
     max_length: Option<usize>,
+    scroll_mode: ScrollMode,
+    scroll_margin: usize,
+    grid: bool,
+    filter_mode: bool,
+    invert_key: Option<Key>,
+    invert_page_only: bool,
+    accessible: bool,
+    bell: bool,
+    pub(crate) abort_keys: AbortKeys,
+    watch_keys: WatchKeys,
+    output: Target,
+    memory: Option<Memory>,
+    observers: Observers<'a>,
     theme: &'a dyn Theme,
+    validator: Option<SelectionsValidatorCallback<'a>>,
+    required: bool,
+    fullscreen: bool,
+    status: bool,
+    key_actions: Vec<(Key, MultiSelectKeyActionCallback<'a, T>)>,
 }
 
-impl Default for MultiSelect<'static> {
+impl Default for MultiSelect<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl MultiSelect<'static> {
+impl MultiSelect<'_> {
     /// Creates a multi select prompt with default theme.
     pub fn new() -> Self {
         Self::with_theme(&SimpleTheme)
     }
 }
 
-impl MultiSelect<'_> {
+impl<T> MultiSelect<'_, T> {
+    /// Creates a multi select prompt whose items carry values, with default theme.
+    ///
+    /// The type of the values is usually inferred from
+    /// [`item_with_value`](Self::item_with_value).
+    pub fn with_values() -> Self {
+        Self::with_values_and_theme(&SimpleTheme)
+    }
+}
+
+impl<T> MultiSelect<'_, T> {
     /// Sets the clear behavior of the menu.
     ///
     /// The default is to clear the menu.
@@ -87,6 +145,102 @@ impl MultiSelect<'_> {
         self
     }
 
+    /// Sets how the items are scrolled if they don't fit on the terminal.
+    ///
+    /// [`ScrollMode::Window`] slides the visible items line by line with the cursor
+    /// and shows the position of the cursor, like "Item 37/120", instead of the page.
+    /// A [`grid`](Self::grid) always flips pages.
+    /// The default is [`ScrollMode::Page`], which flips whole pages.
+    pub fn scroll_mode(mut self, val: ScrollMode) -> Self {
+        self.scroll_mode = val;
+        self
+    }
+
+    /// Sets the number of items kept visible above and below the cursor.
+    ///
+    /// This implies [`ScrollMode::Window`]: the list scrolls line by line as soon as
+    /// the cursor comes within `val` items of the top or bottom edge, like editors do.
+    /// The default is 0, i.e. to scroll when the cursor leaves the visible items.
+    pub fn scroll_margin(mut self, val: usize) -> Self {
+        self.scroll_mode = ScrollMode::Window;
+        self.scroll_margin = val;
+        self
+    }
+
+    /// Indicates whether to lay out the items in columns which fill the terminal width, like `ls`.
+    ///
+    /// The arrows move the cursor in all four directions. This suits many short items,
+    /// e.g. feature flags, which then need a lot fewer pages. Filtering, descriptions
+    /// and [key actions](Self::on_key) are not available in a grid. The default is one
+    /// item per line.
+    pub fn grid(mut self, val: bool) -> Self {
+        self.grid = val;
+        self
+    }
+
+    /// Indicates whether to return the selected items in the order they were checked.
+    ///
+    /// This also applies to the reported values after interaction. Items checked
+    /// all at once with 'a' are added in the order they are listed.
+    ///
+    /// The default is to return the selected items in the order they are listed.
+    pub fn preserve_selection_order(mut self, val: bool) -> Self {
+        self.preserve_selection_order = val;
+        self
+    }
+
+    /// Indicates whether at least one item must be checked.
+    ///
+    /// If nothing is checked on 'Enter', an error is shown and the prompt stays open.
+    /// The default is to accept an empty selection.
+    pub fn required(mut self, val: bool) -> Self {
+        self.required = val;
+        self
+    }
+
+    /// Indicates whether to show a status line below the items.
+    ///
+    /// The status line shows how many items are checked, like "4 selected / 120 items",
+    /// and is updated as items are checked. The default is to hide it.
+    pub fn status(mut self, val: bool) -> Self {
+        self.status = val;
+        self
+    }
+
+    /// Indicates whether the items can be filtered.
+    ///
+    /// When enabled, pressing '/' opens a filter line and typing narrows the visible items
+    /// to the ones containing the filter (case-insensitive). Checked items keep their state
+    /// while they are hidden. 'Enter' returns to the navigation of the filtered items and
+    /// 'Esc' clears the filter.
+    ///
+    /// The default is to disable filtering.
+    pub fn filter_mode(mut self, val: bool) -> Self {
+        self.filter_mode = val;
+        self
+    }
+
+    /// Sets the key which inverts the checked state of the items.
+    ///
+    /// Unlike 'a', which checks or unchecks all items, this checks the unchecked items
+    /// and unchecks the checked ones. Only the items matching the filter are inverted.
+    ///
+    /// The default is `None`, so no key inverts the items.
+    pub fn invert_key(mut self, key: Option<Key>) -> Self {
+        self.invert_key = key;
+        self
+    }
+
+    /// Indicates whether inverting only applies to the items of the current page.
+    ///
+    /// The default is to invert the items of all pages.
+    pub fn invert_page_only(mut self, val: bool) -> Self {
+        self.invert_page_only = val;
+        self
+    }
+}
+
+impl MultiSelect<'_> {
     /// Add a single item to the selector.
     #[inline]
     pub fn item<T: ToString>(self, item: T) -> Self {
@@ -95,11 +249,42 @@ impl MultiSelect<'_> {
 
     /// Add a single item to the selector with a default checked state.
     pub fn item_checked<T: ToString>(mut self, item: T, checked: bool) -> Self {
-        self.items.push(item.to_string());
+        self.items.push(item.to_string().into());
+        self.values.push(());
+        self.descriptions.push(None);
         self.defaults.push(checked);
         self
     }
 
+    /// Add a single item with a description to the selector.
+    ///
+    /// The description is rendered below the item while it is highlighted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let selection = MultiSelect::new()
+    ///         .item_with_description("serde", "Serialization framework")
+    ///         .item_with_description("tokio", "Asynchronous runtime")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn item_with_description<T: ToString, D: ToString>(
+        mut self,
+        item: T,
+        description: D,
+    ) -> Self {
+        self.items.push(item.to_string().into());
+        self.values.push(());
+        self.descriptions.push(Some(description.to_string()));
+        self.defaults.push(false);
+        self
+    }
+
     /// Adds multiple items to the selector.
     pub fn items<T, I>(self, items: I) -> Self
     where
@@ -116,11 +301,43 @@ impl MultiSelect<'_> {
         I: IntoIterator<Item = (T, bool)>,
     {
         for (item, checked) in items.into_iter() {
-            self.items.push(item.to_string());
+            self.items.push(item.to_string().into());
+            self.values.push(());
+            self.descriptions.push(None);
             self.defaults.push(checked);
         }
         self
     }
+}
+
+impl<T> MultiSelect<'_, T> {
+    /// Adds an item which carries a value.
+    ///
+    /// The values of the selected items are returned by
+    /// [`interact_values`](Self::interact_values), while the item is rendered and
+    /// reported.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let regions: Vec<&str> = MultiSelect::with_values()
+    ///         .with_prompt("Regions")
+    ///         .item_with_value("US East (N. Virginia)", "us-east-1")
+    ///         .item_with_value("Europe (Ireland)", "eu-west-1")
+    ///         .interact_values()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn item_with_value<S: ToString>(mut self, item: S, value: T) -> Self {
+        self.items.push(item.to_string().into());
+        self.values.push(value);
+        self.descriptions.push(None);
+        self.defaults.push(false);
+        self
+    }
 
     /// Prefaces the menu with a prompt.
     ///
@@ -139,21 +356,110 @@ impl MultiSelect<'_> {
         self
     }
 
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc', 'q' and Ctrl-D.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets keys which end the prompt, so that the caller can react to them, e.g.
+    /// show help on '?' or details of the highlighted item on 'd'.
+    ///
+    /// Watched keys take precedence over the usual meaning of the keys. They are only
+    /// watched by [`interact_outcome`](Self::interact_outcome), the other `interact`
+    /// methods ignore them.
+    pub fn watch_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.watch_keys = WatchKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Remembers the checked items in a file and checks them by default the next time.
+    ///
+    /// The file can be shared by several prompts with different ids. Items are
+    /// recognized by their text, so the memory survives reordered or new items. Errors
+    /// when reading or writing the file are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let selection = MultiSelect::new()
+    ///         .with_prompt("Which region?")
+    ///         .items(&["us-east-1", "eu-west-1", "ap-south-1"])
+    ///         .remember_as("region", "/tmp/my-cli/answers")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn remember_as<I: Into<String>, P: AsRef<Path>>(mut self, id: I, path: P) -> Self {
+        self.memory = Some(Memory::new(id.into(), path));
+        self
+    }
+
+    /// Indicates whether to show the prompt on the whole terminal screen.
+    ///
+    /// The prompt is shown on the alternate screen with a line explaining the keys,
+    /// which gives long lists as much room as possible. The previous output is shown
+    /// again afterwards, followed by the report. This has no effect in
+    /// [`accessible`](Self::accessible) mode. The default is to render the prompt
+    /// below the previous output.
+    pub fn fullscreen(mut self, val: bool) -> Self {
+        self.fullscreen = val;
+        self
+    }
+
+    /// Indicates whether to render the prompt for screen readers.
+    ///
+    /// In accessible mode the prompt is never redrawn. Instead, every change of
+    /// the highlighted item or its checkbox is announced on a new line, like
+    /// "Highlighted: option 3 of 7, Banana, checked". The filter mode is not
+    /// available in accessible mode.
+    ///
+    /// The default is to enable it if the `DIALOGUER_ACCESSIBLE` environment
+    /// variable is set to a value other than `0`.
+    pub fn accessible(mut self, val: bool) -> Self {
+        self.accessible = val;
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// The user can select the items with the 'Space' bar and on 'Enter' the indices of selected items will be returned.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `Vec<index>` if user hit 'Enter'.
     /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
     #[inline]
     pub fn interact(self) -> Result<Vec<usize>> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
     }
 
     /// Enables user interaction and returns the result.
     ///
     /// The user can select the items with the 'Space' bar and on 'Enter' the indices of selected items will be returned.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `Some(Vec<index>)` if user hit 'Enter' or `None` if user cancelled with 'Esc' or 'q'.
     ///
     /// ## Example
@@ -183,177 +489,861 @@ impl MultiSelect<'_> {
     /// ```
     #[inline]
     pub fn interact_opt(self) -> Result<Option<Vec<usize>>> {
-        self.interact_on_opt(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
     #[inline]
-    pub fn interact_on(self, term: &Term) -> Result<Vec<usize>> {
-        Ok(self
-            ._interact_on(term, false)?
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))?)
+    pub fn interact_on(mut self, term: &Term) -> Result<Vec<usize>> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
     }
 
     /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
     #[inline]
-    pub fn interact_on_opt(self, term: &Term) -> Result<Option<Vec<usize>>> {
+    pub fn interact_on_opt(mut self, term: &Term) -> Result<Option<Vec<usize>>> {
         self._interact_on(term, true)
     }
 
-    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
+    /// Enables user interaction and returns the outcome.
+    ///
+    /// Like [`interact_opt`](Self::interact_opt), but the prompt also ends when the user
+    /// presses one of the [`watch_keys`](Self::watch_keys), see
+    /// [`Select::interact_outcome`](crate::Select::interact_outcome).
+    pub fn interact_outcome(self) -> Result<Outcome<Vec<usize>>> {
+        let term = self.output.term()?;
+        self.interact_outcome_on(&term)
+    }
+
+    /// Like [`interact_outcome`](Self::interact_outcome) but allows a specific terminal to be set.
+    pub fn interact_outcome_on(mut self, term: &Term) -> Result<Outcome<Vec<usize>>> {
+        let watch_keys = self.watch_keys.activate();
+        let answer = self._interact_on(term, true)?;
+
+        Ok(watch_keys.outcome(answer))
+    }
+
+    /// Like [`interact`](Self::interact) but returns the values of the selected items.
+    #[inline]
+    pub fn interact_values(self) -> Result<Vec<T>> {
+        self._interact_values(false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but returns the values of the selected
+    /// items.
+    #[inline]
+    pub fn interact_values_opt(self) -> Result<Option<Vec<T>>> {
+        self._interact_values(true)
+    }
+
+    fn _interact_values(mut self, allow_quit: bool) -> Result<Option<Vec<T>>> {
+        let term = self.output.term()?;
+        let selections = self._interact_on(&term, allow_quit)?;
+        let mut values: Vec<Option<T>> =
+            mem::take(&mut self.values).into_iter().map(Some).collect();
+
+        Ok(selections.map(|selections| {
+            selections
+                .into_iter()
+                .filter_map(|idx| values[idx].take())
+                .collect()
+        }))
+    }
+
+    fn _interact_on(&mut self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
         }
 
         if self.items.is_empty() {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Empty list of items given to `MultiSelect`",
-            ))?;
+            return Err(Error::EmptyItems);
+        }
+
+        if let Some(remembered) = self.memory.as_ref().and_then(Memory::recall) {
+            self.defaults = self
+                .items
+                .iter()
+                .map(|item| remembered.iter().any(|it| it == item))
+                .collect();
+        }
+
+        let _span = trace::shown("multi_select", self.prompt.as_deref());
+
+        let _cleanup = Cleanup::install(term);
+
+        let selections = if self.accessible {
+            self._interact_accessible(term, allow_quit)
+        } else if self.fullscreen {
+            self._interact_fullscreen(term, allow_quit)
+        } else if self.grid {
+            self._interact_grid(term, allow_quit)
+        } else {
+            self._interact_list(term, allow_quit)
+        };
+        let selections = trace::answered(selections, |selections| {
+            let items: Vec<&str> = selections.iter().map(|&idx| &*self.items[idx]).collect();
+
+            Some(items.join(", "))
+        })?;
+
+        if let (Some(memory), Some(selections)) = (&self.memory, &selections) {
+            let items: Vec<&str> = selections.iter().map(|&idx| &*self.items[idx]).collect();
+            memory.remember(&items);
+        }
+
+        Ok(selections)
+    }
+
+    /// Like `_interact_on` but renders the prompt on the alternate screen and
+    /// reports the selections on the main screen.
+    fn _interact_fullscreen(
+        &mut self,
+        term: &Term,
+        allow_quit: bool,
+    ) -> Result<Option<Vec<usize>>> {
+        let report = mem::replace(&mut self.report, false);
+        let alt_screen = AltScreen::enable(term)?;
+        let selections = if self.grid {
+            self._interact_grid(term, allow_quit)
+        } else {
+            self._interact_list(term, allow_quit)
+        };
+
+        drop(alt_screen);
+        self.report = report;
+        let selections = selections?;
+
+        if let Some(ref selections) = selections {
+            self.report_selections(&mut TermThemeRenderer::new(term, self.theme), selections)?;
+            term.flush()?;
         }
 
+        Ok(selections)
+    }
+
+    /// Like `_interact_on` but renders one item per line.
+    fn _interact_list(&mut self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
         let mut paging = Paging::new(term, self.items.len(), self.max_length);
+
+        paging.set_scroll_mode(self.scroll_mode);
+        paging.set_scroll_margin(self.scroll_margin);
         let mut render = TermThemeRenderer::new(term, self.theme);
         let mut sel = 0;
 
-        let mut size_vec = Vec::new();
+        // Lines rendered in addition to the items
+        let reserved_lines = usize::from(self.filter_mode)
+            + usize::from(self.descriptions.iter().any(Option::is_some))
+            + usize::from(self.validator.is_some() || self.required)
+            + usize::from(self.fullscreen)
+            + usize::from(self.status);
+        paging.reserve_lines(reserved_lines);
 
-        for items in self
-            .items
-            .iter()
-            .flat_map(|i| i.split('\n'))
-            .collect::<Vec<_>>()
-        {
-            let size = &items.len();
-            size_vec.push(*size);
-        }
+        // Indices of the items which match the current filter
+        let mut visible: Vec<usize> = (0..self.items.len()).collect();
+        let mut filter = String::new();
+        let mut filtering = false;
+        let mut error: Option<String> = None;
+
+        // Widths of the rendered lines, used to clear wrapped lines
+        let mut size_vec = Vec::new();
 
         let mut checked: Vec<bool> = self.defaults.clone();
+        // Indices of the checked items in the order they were checked
+        let mut selection_order = checked_indices(&checked);
 
         term.hide_cursor()?;
 
         loop {
+            render.begin_frame();
+
             if let Some(ref prompt) = self.prompt {
                 paging
                     .render_prompt(|paging_info| render.multi_select_prompt(prompt, paging_info))?;
             }
 
-            for (idx, item) in self
-                .items
+            if filtering || !filter.is_empty() {
+                render.filter_prompt(&filter, filtering)?;
+            }
+
+            size_vec.clear();
+
+            for (idx, &item_idx) in visible
                 .iter()
                 .enumerate()
-                .skip(paging.current_page * paging.capacity)
+                .skip(paging.first_item())
                 .take(paging.capacity)
             {
-                render.multi_select_prompt_item(item, checked[idx], sel == idx)?;
+                let item = &self.items[item_idx];
+                render.multi_select_prompt_item(item, checked[item_idx], sel == idx)?;
+                size_vec.extend(item.split('\n').map(str::len));
+
+                if sel == idx {
+                    if let Some(description) = &self.descriptions[item_idx] {
+                        render.multi_select_prompt_item_description(description)?;
+                        size_vec.extend(description.split('\n').map(measure_text_width));
+                    }
+                }
             }
 
-            term.flush()?;
+            if self.status {
+                let checked = checked.iter().filter(|&&checked| checked).count();
+                render.multi_select_status(checked, self.items.len())?;
+            }
 
-            match term.read_key()? {
-                Key::ArrowDown | Key::Tab | Key::Char('j') => {
-                    if sel == !0 {
-                        sel = 0;
+            if self.fullscreen {
+                render.key_help(&translate::text(
+                    "help-multi-select",
+                    "Arrows move · Space check · Enter confirm",
+                ))?;
+            }
+
+            if let Some(err) = error.take() {
+                render.error(&err)?;
+            }
+
+            render.end_frame()?;
+
+            let state = PromptState::MultiSelect {
+                highlighted: visible.get(sel).copied(),
+                checked: &checked,
+            };
+            self.observers.rendered(state);
+
+            let mut filter_changed = false;
+            let mut items_changed = false;
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+
+            match key {
+                Key::Char('/') if self.filter_mode && !filtering => {
+                    filtering = true;
+                }
+                Key::Escape if filtering => {
+                    filtering = false;
+
+                    if !filter.is_empty() {
+                        filter.clear();
+                        filter_changed = true;
+                    }
+                }
+                Key::Enter if filtering => {
+                    filtering = false;
+                }
+                Key::Backspace if filtering => {
+                    filter_changed = filter.pop().is_some();
+                }
+                Key::Char(chr) if filtering && !chr.is_ascii_control() => {
+                    filter.push(chr);
+                    filter_changed = true;
+                }
+                key if !filtering && self.watch_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
                     } else {
-                        sel = (sel as u64 + 1).rem(self.items.len() as u64) as usize;
+                        term.clear_last_lines(paging.capacity)?;
                     }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    let highlighted = visible.get(sel).copied();
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
                 }
-                Key::ArrowUp | Key::BackTab | Key::Char('k') => {
+                key if !filtering
+                    && self
+                        .key_actions
+                        .iter()
+                        .any(|(action_key, _)| *action_key == key) =>
+                {
+                    render.clear()?;
+                    term.show_cursor()?;
+                    let highlighted = visible.get(sel).copied().unwrap_or(0);
+                    let highlighted =
+                        self.run_key_action(&key, highlighted, &mut checked, &mut selection_order)?;
+                    term.hide_cursor()?;
+
+                    if self.items.is_empty() {
+                        return Err(Error::EmptyItems);
+                    }
+
+                    visible = filter_items(&self.items, &filter);
+                    sel = visible
+                        .iter()
+                        .position(|&idx| idx == highlighted)
+                        .unwrap_or(0);
+                    items_changed = true;
+                }
+                key if key == CTRL_Z && cfg!(unix) => {
+                    render.clear()?;
+                    suspend(term)?;
+                    items_changed = true;
+                }
+                Key::ArrowDown | Key::Tab | Key::Char('j') if !visible.is_empty() => {
                     if sel == !0 {
-                        sel = self.items.len() - 1;
+                        sel = 0;
                     } else {
-                        sel = ((sel as i64 - 1 + self.items.len() as i64)
-                            % (self.items.len() as i64)) as usize;
+                        sel = (sel as u64 + 1).rem(visible.len() as u64) as usize;
                     }
                 }
-                Key::ArrowLeft | Key::Char('h') => {
-                    if paging.active {
-                        sel = paging.previous_page();
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
                     }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
                 }
-                Key::ArrowRight | Key::Char('l') => {
-                    if paging.active {
-                        sel = paging.next_page();
+                Key::ArrowUp | Key::BackTab | Key::Char('k') if !visible.is_empty() => {
+                    if sel == !0 {
+                        sel = visible.len() - 1;
+                    } else {
+                        sel = ((sel as i64 - 1 + visible.len() as i64) % (visible.len() as i64))
+                            as usize;
                     }
                 }
-                Key::Char(' ') => {
-                    checked[sel] = !checked[sel];
+                Key::ArrowLeft | Key::Char('h') if paging.active => {
+                    sel = paging.previous_page();
+                }
+                Key::ArrowRight | Key::Char('l') if paging.active => {
+                    sel = paging.next_page();
+                }
+                Key::Home if paging.active => {
+                    sel = paging.first_page();
+                }
+                Key::End if paging.active => {
+                    sel = paging.last_page();
+                }
+                Key::Char(' ') if !visible.is_empty() => {
+                    let idx = visible[sel];
+                    checked[idx] = !checked[idx];
+
+                    if checked[idx] {
+                        selection_order.push(idx);
+                    } else {
+                        selection_order.retain(|&selected| selected != idx);
+                    }
                 }
                 Key::Char('a') => {
-                    if checked.iter().all(|&item_checked| item_checked) {
-                        checked.fill(false);
+                    let all_checked = visible.iter().all(|&idx| checked[idx]);
+
+                    if all_checked {
+                        selection_order.retain(|selected| !visible.contains(selected));
                     } else {
-                        checked.fill(true);
+                        selection_order.extend(visible.iter().filter(|&&idx| !checked[idx]));
+                    }
+
+                    for &idx in &visible {
+                        checked[idx] = !all_checked;
                     }
                 }
-                Key::Escape | Key::Char('q') => {
-                    if allow_quit {
+                key if self.invert_key.as_ref() == Some(&key) => {
+                    let (skip, take) = if self.invert_page_only {
+                        (paging.first_item(), paging.capacity)
+                    } else {
+                        (0, visible.len())
+                    };
+
+                    invert(
+                        &mut checked,
+                        &mut selection_order,
+                        visible.iter().copied().skip(skip).take(take),
+                    );
+                }
+                Key::Enter => {
+                    let selections = self.selections(&checked, &selection_order);
+
+                    if let Some(err) = self.validate(&selections) {
+                        error = Some(err);
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+                    } else {
                         if self.clear {
                             render.clear()?;
-                        } else {
-                            term.clear_last_lines(paging.capacity)?;
                         }
 
+                        self.report_selections(&mut render, &selections)?;
+
                         term.show_cursor()?;
                         term.flush()?;
 
-                        return Ok(None);
+                        return Ok(Some(selections));
                     }
                 }
-                Key::Enter => {
-                    if self.clear {
-                        render.clear()?;
-                    }
-
-                    if let Some(ref prompt) = self.prompt {
-                        if self.report {
-                            let selections: Vec<_> = checked
-                                .iter()
-                                .enumerate()
-                                .filter_map(|(idx, &checked)| {
-                                    if checked {
-                                        Some(self.items[idx].as_str())
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect();
-
-                            render.multi_select_prompt_selection(prompt, &selections[..])?;
-                        }
+                _ => {
+                    if self.bell {
+                        render.bell()?;
                     }
-
-                    term.show_cursor()?;
-                    term.flush()?;
-
-                    return Ok(Some(
-                        checked
-                            .into_iter()
-                            .enumerate()
-                            .filter_map(|(idx, checked)| if checked { Some(idx) } else { None })
-                            .collect(),
-                    ));
                 }
-                _ => {}
             }
 
-            paging.update(sel)?;
+            if filter_changed {
+                visible = filter_items(&self.items, &filter);
+                sel = 0;
+            }
+
+            render.begin_frame();
 
-            if paging.active {
+            if filter_changed || items_changed {
+                // The amount of items changed, so the whole prompt is rendered again
+                paging.set_items_len(visible.len());
+                paging.update_page(sel);
                 render.clear()?;
             } else {
-                render.clear_preserve_prompt(&size_vec)?;
+                paging.update(sel)?;
+
+                if paging.active {
+                    render.clear()?;
+                } else {
+                    render.clear_preserve_prompt(&size_vec)?;
+                }
             }
         }
     }
-}
 
-impl<'a> MultiSelect<'a> {
-    /// Creates a multi select prompt with a specific theme.
-    ///
-    /// ## Example
-    ///
-    /// ```rust,no_run
+    /// Like `_interact_on` but renders the items in columns.
+    fn _interact_grid(&self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
+        let len = self.items.len();
+        let cell_width = self
+            .items
+            .iter()
+            .flat_map(|item| [(item, false), (item, true)])
+            .map(|(item, active)| {
+                let mut cell = String::new();
+                let _ = self
+                    .theme
+                    .format_multi_select_prompt_item(&mut cell, item, true, active);
+                measure_text_width(&cell)
+            })
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let cols = (term.size().1 as usize / cell_width).max(1);
+        let rows = (len + cols - 1) / cols;
+
+        let mut paging = Paging::new(term, rows, self.max_length);
+
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut sel = 0;
+        let mut error: Option<String> = None;
+
+        let mut checked: Vec<bool> = self.defaults.clone();
+        // Indices of the checked items in the order they were checked
+        let mut selection_order = checked_indices(&checked);
+
+        paging.reserve_lines(
+            usize::from(self.validator.is_some() || self.required)
+                + usize::from(self.fullscreen)
+                + usize::from(self.status),
+        );
+
+        term.hide_cursor()?;
+
+        loop {
+            render.begin_frame();
+
+            if let Some(ref prompt) = self.prompt {
+                paging
+                    .render_prompt(|paging_info| render.multi_select_prompt(prompt, paging_info))?;
+            }
+
+            for row in (0..rows).skip(paging.first_item()).take(paging.capacity) {
+                render.formatted_line(|theme, buf| {
+                    let cells = self.items.iter().zip(&checked).enumerate();
+
+                    for (idx, (item, &checked)) in cells.take((row + 1) * cols).skip(row * cols) {
+                        let mut cell = String::new();
+                        theme.format_multi_select_prompt_item(
+                            &mut cell,
+                            item,
+                            checked,
+                            sel == idx,
+                        )?;
+
+                        if idx % cols == cols - 1 || idx == len - 1 {
+                            write!(buf, "{}", cell)?;
+                        } else {
+                            let padding = cell_width - measure_text_width(&cell);
+                            write!(buf, "{}{:padding$}", cell, "", padding = padding)?;
+                        }
+                    }
+
+                    Ok(())
+                })?;
+            }
+
+            if self.status {
+                let checked = checked.iter().filter(|&&checked| checked).count();
+                render.multi_select_status(checked, len)?;
+            }
+
+            if self.fullscreen {
+                render.key_help(&translate::text(
+                    "help-multi-select",
+                    "Arrows move · Space check · Enter confirm",
+                ))?;
+            }
+
+            if let Some(err) = error.take() {
+                render.error(&err)?;
+            }
+
+            render.end_frame()?;
+
+            let state = PromptState::MultiSelect {
+                highlighted: Some(sel),
+                checked: &checked,
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+            let mut suspended = false;
+
+            match key {
+                key if self.watch_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    self.watch_keys.record(key, Some(sel));
+                    return Ok(None);
+                }
+                key if key == CTRL_Z && cfg!(unix) => {
+                    render.clear()?;
+                    suspend(term)?;
+                    suspended = true;
+                }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
+                Key::ArrowRight | Key::Tab | Key::Char('l') => {
+                    sel = (sel + 1) % len;
+                }
+                Key::ArrowLeft | Key::BackTab | Key::Char('h') => {
+                    sel = (sel + len - 1) % len;
+                }
+                Key::ArrowDown | Key::Char('j') => {
+                    sel = if sel + cols < len {
+                        sel + cols
+                    } else {
+                        sel % cols
+                    };
+                }
+                Key::ArrowUp | Key::Char('k') => {
+                    if sel >= cols {
+                        sel -= cols;
+                    } else {
+                        sel += (rows - 1) * cols;
+
+                        if sel >= len {
+                            sel -= cols;
+                        }
+                    }
+                }
+                Key::Home if paging.active => {
+                    sel = paging.first_page() * cols;
+                }
+                Key::End if paging.active => {
+                    sel = paging.last_page() * cols;
+                }
+                Key::Char(' ') => {
+                    checked[sel] = !checked[sel];
+
+                    if checked[sel] {
+                        selection_order.push(sel);
+                    } else {
+                        selection_order.retain(|&selected| selected != sel);
+                    }
+                }
+                Key::Char('a') => {
+                    let all_checked = checked.iter().all(|&checked| checked);
+
+                    if all_checked {
+                        selection_order.clear();
+                    } else {
+                        selection_order.extend((0..len).filter(|&idx| !checked[idx]));
+                    }
+
+                    checked = vec![!all_checked; len];
+                }
+                key if self.invert_key.as_ref() == Some(&key) => {
+                    let (skip, take) = if self.invert_page_only {
+                        (paging.first_item() * cols, paging.capacity * cols)
+                    } else {
+                        (0, len)
+                    };
+
+                    invert(
+                        &mut checked,
+                        &mut selection_order,
+                        (0..len).skip(skip).take(take),
+                    );
+                }
+                Key::Enter => {
+                    let selections = self.selections(&checked, &selection_order);
+
+                    if let Some(err) = self.validate(&selections) {
+                        error = Some(err);
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+                    } else {
+                        if self.clear {
+                            render.clear()?;
+                        }
+
+                        self.report_selections(&mut render, &selections)?;
+
+                        term.show_cursor()?;
+                        term.flush()?;
+
+                        return Ok(Some(selections));
+                    }
+                }
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+
+            render.begin_frame();
+
+            if suspended {
+                // The prompt was cleared, so it is rendered again from scratch
+                paging.set_items_len(rows);
+                paging.update_page(sel / cols);
+            } else {
+                paging.update(sel / cols)?;
+
+                if paging.active {
+                    render.clear()?;
+                } else {
+                    render.clear_preserve_prompt(&[])?;
+                }
+            }
+        }
+    }
+
+    /// Like `_interact_on` but announces changes on new lines instead of redrawing.
+    fn _interact_accessible(&self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut sel = 0;
+        let mut changed = true;
+
+        let mut checked: Vec<bool> = self.defaults.clone();
+        let mut selection_order = checked_indices(&checked);
+
+        if let Some(ref prompt) = self.prompt {
+            render.multi_select_prompt(prompt, None)?;
+        }
+
+        loop {
+            if changed {
+                render.accessible_item(
+                    &self.items[sel],
+                    sel + 1,
+                    self.items.len(),
+                    Some(checked[sel]),
+                )?;
+
+                if let Some(description) = &self.descriptions[sel] {
+                    render.multi_select_prompt_item_description(description)?;
+                }
+            }
+
+            term.flush()?;
+            changed = true;
+
+            let state = PromptState::MultiSelect {
+                highlighted: Some(sel),
+                checked: &checked,
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+
+            match key {
+                key if self.watch_keys.contains(&key) => {
+                    let highlighted = Some(sel);
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
+                }
+                Key::ArrowDown | Key::Tab | Key::Char('j') => {
+                    sel = (sel as u64 + 1).rem(self.items.len() as u64) as usize;
+                }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    return Ok(None);
+                }
+                Key::ArrowUp | Key::BackTab | Key::Char('k') => {
+                    sel = ((sel as i64 - 1 + self.items.len() as i64) % (self.items.len() as i64))
+                        as usize;
+                }
+                Key::Char(' ') => {
+                    checked[sel] = !checked[sel];
+
+                    if checked[sel] {
+                        selection_order.push(sel);
+                    } else {
+                        selection_order.retain(|&selected| selected != sel);
+                    }
+                }
+                Key::Char('a') => {
+                    let all_checked = checked.iter().all(|&checked| checked);
+
+                    if all_checked {
+                        selection_order.clear();
+                    } else {
+                        selection_order.extend((0..checked.len()).filter(|&idx| !checked[idx]));
+                    }
+
+                    checked
+                        .iter_mut()
+                        .for_each(|checked| *checked = !all_checked);
+                }
+                key if self.invert_key.as_ref() == Some(&key) => {
+                    invert(&mut checked, &mut selection_order, 0..self.items.len());
+                }
+                Key::Enter => {
+                    let selections = self.selections(&checked, &selection_order);
+
+                    if let Some(err) = self.validate(&selections) {
+                        changed = false;
+                        render.error(&err)?;
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+
+                        continue;
+                    }
+
+                    self.report_selections(&mut render, &selections)?;
+                    term.flush()?;
+
+                    return Ok(Some(selections));
+                }
+                _ => {
+                    changed = false;
+
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the indices of the checked items.
+    fn selections(&self, checked: &[bool], selection_order: &[usize]) -> Vec<usize> {
+        if self.preserve_selection_order {
+            selection_order.to_vec()
+        } else {
+            checked_indices(checked)
+        }
+    }
+
+    /// Checks that the [`required`](Self::required) items are checked and runs the validator.
+    fn validate(&self, selections: &[usize]) -> Option<String> {
+        let err = if self.required && selections.is_empty() {
+            Some(translate::text("select-at-least-one", "Select at least one item").into_owned())
+        } else {
+            self.validator
+                .as_ref()
+                .and_then(|validator| validator.lock().unwrap()(selections))
+        };
+
+        if let Some(ref err) = err {
+            trace::validation_failed(err);
+        }
+
+        err
+    }
+
+    /// Reports the checked items after interaction.
+    fn report_selections(&self, render: &mut TermThemeRenderer, selections: &[usize]) -> Result {
+        if let Some(ref prompt) = self.prompt {
+            if self.report {
+                let selections: Vec<_> = selections.iter().map(|&idx| &*self.items[idx]).collect();
+
+                if let Some(ref report_with) = self.report_with {
+                    let report = report_with(&selections[..]);
+                    render.multi_select_prompt_selection(prompt, &[report.as_str()])?;
+                } else {
+                    render.multi_select_prompt_selection(prompt, &selections[..])?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> MultiSelect<'a> {
+    /// Adds multiple items without copying them.
+    ///
+    /// Unlike [`items`](Self::items), which copies each item into a `String`, this
+    /// keeps borrowed items as they are. This matters for prompts built from large
+    /// in-memory datasets.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let names: Vec<String> = (0..100_000).map(|idx| format!("Item {}", idx)).collect();
+    ///
+    ///     let selection = MultiSelect::new()
+    ///         .items_borrowed(names.iter().map(String::as_str))
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn items_borrowed<T, I>(mut self, items: I) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        for item in items {
+            self.items.push(item.into());
+            self.values.push(());
+            self.descriptions.push(None);
+            self.defaults.push(false);
+        }
+
+        self
+    }
+
+    /// Creates a multi select prompt with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
     /// use dialoguer::{theme::ColorfulTheme, MultiSelect};
     ///
     /// fn main() {
@@ -364,18 +1354,260 @@ impl<'a> MultiSelect<'a> {
     /// }
     /// ```
     pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self::with_values_and_theme(theme)
+    }
+}
+
+impl<'a, T> MultiSelect<'a, T> {
+    /// Sets a custom text to report the selected values after interaction.
+    ///
+    /// The closure receives the selected items and its result is reported instead
+    /// of the list of all selected items.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let selection = MultiSelect::new()
+    ///         .with_prompt("Which packages do you want to install?")
+    ///         .items(&["serde", "tokio", "rand"])
+    ///         .report_with(|selected: &[&str]| format!("{} packages selected", selected.len()))
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn report_with<F>(mut self, report_with: F) -> Self
+    where
+        F: Fn(&[&str]) -> String + 'a,
+    {
+        self.report_with = Some(Arc::new(report_with));
+        self
+    }
+
+    /// Registers a validator for the checked items.
+    ///
+    /// The validator receives the indices of the checked items when the user
+    /// submits the selection, in the order they are returned by the prompt. If it
+    /// returns an error, the error is shown below the items and the prompt stays
+    /// open.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let selection = MultiSelect::new()
+    ///         .with_prompt("Which features do you want to enable?")
+    ///         .items(&["native-tls", "rustls", "json"])
+    ///         .validate_with(|selections: &[usize]| -> Result<(), &str> {
+    ///             if selections.contains(&0) && selections.contains(&1) {
+    ///                 Err("native-tls and rustls are mutually exclusive")
+    ///             } else {
+    ///                 Ok(())
+    ///             }
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn validate_with<V>(mut self, mut validator: V) -> Self
+    where
+        V: InputValidator<[usize]> + 'a,
+        V::Err: ToString,
+    {
+        let mut old_validator_func = self.validator.take();
+
+        self.validator = Some(Arc::new(Mutex::new(
+            move |value: &[usize]| -> Option<String> {
+                if let Some(old) = old_validator_func.as_mut() {
+                    if let Some(err) = old.lock().unwrap()(value) {
+                        return Some(err);
+                    }
+                }
+
+                match validator.validate(value) {
+                    Ok(()) => None,
+                    Err(err) => Some(err.to_string()),
+                }
+            },
+        )));
+
+        self
+    }
+
+    /// Registers an action which changes the items when `key` is pressed.
+    ///
+    /// The prompt is cleared while the action runs, so it can ask for input itself,
+    /// e.g. for the name of a new item. Afterwards the prompt shows the changed items,
+    /// which keep whether they are checked, and highlights the item the action left
+    /// highlighted. Registered keys take precedence over the keys of the prompt, except
+    /// while typing a filter. If the action removes all items, the prompt fails with
+    /// [`Error::EmptyItems`]. Actions are not available in
+    /// [`accessible`](Self::accessible) mode.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use console::Key;
+    /// use dialoguer::MultiSelect;
+    ///
+    /// fn main() {
+    ///     let profiles = MultiSelect::new()
+    ///         .with_prompt("Profiles to sync")
+    ///         .items(&["default", "staging", "production"])
+    ///         .on_key(Key::Char('d'), |items| {
+    ///             items.remove(items.highlighted());
+    ///             Ok(())
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn on_key<F>(mut self, key: Key, action: F) -> Self
+    where
+        F: FnMut(&mut ItemList<'a, T>) -> Result + 'a,
+    {
+        self.key_actions.push((key, Arc::new(Mutex::new(action))));
+        self
+    }
+
+    /// Runs the action registered for `key`, carries the checked items over to the
+    /// changed items and returns the highlighted item.
+    fn run_key_action(
+        &mut self,
+        key: &Key,
+        sel: usize,
+        checked: &mut Vec<bool>,
+        selection_order: &mut Vec<usize>,
+    ) -> Result<usize> {
+        let action = match self
+            .key_actions
+            .iter()
+            .find(|(action_key, _)| action_key == key)
+        {
+            Some((_, action)) => action.clone(),
+            None => return Ok(sel),
+        };
+
+        let len = self.items.len();
+        let mut list = ItemList::new(mem::take(&mut self.items), mem::take(&mut self.values), sel);
+        let result = action.lock().unwrap()(&mut list);
+        let (items, values, origins, highlighted) = list.into_parts();
+
+        self.items = items;
+        self.values = values;
+        self.defaults = rearrange(&self.defaults, &origins);
+        self.descriptions = rearrange(&self.descriptions, &origins);
+        *checked = rearrange(checked, &origins);
+
+        // Indices of the items after the action by their former index
+        let mut new_indices = vec![None; len];
+        for (idx, origin) in origins.iter().enumerate() {
+            if let Some(origin) = origin {
+                new_indices[*origin] = Some(idx);
+            }
+        }
+        *selection_order = selection_order
+            .iter()
+            .filter_map(|&idx| new_indices[idx])
+            .collect();
+
+        result?;
+
+        Ok(highlighted)
+    }
+
+    /// Creates a multi select prompt whose items carry values, with a specific theme.
+    pub fn with_values_and_theme(theme: &'a dyn Theme) -> Self {
         Self {
             items: vec![],
+            values: vec![],
+            descriptions: vec![],
             defaults: vec![],
             clear: true,
             prompt: None,
             report: true,
+            report_with: None,
+            preserve_selection_order: false,
             max_length: None,
+            scroll_mode: ScrollMode::Page,
+            scroll_margin: 0,
+            grid: false,
+            filter_mode: false,
+            invert_key: None,
+            invert_page_only: false,
+            accessible: accessible_from_env(),
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, Key::Char('q'), CTRL_D]),
+            watch_keys: WatchKeys::default(),
+            output: Target::default(),
+            memory: None,
+            observers: Observers::default(),
             theme,
+            validator: None,
+            required: false,
+            fullscreen: false,
+            status: false,
+            key_actions: vec![],
+        }
+    }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+/// Inverts the checked state of the given items, keeping the selection order of
+/// the items which stay checked.
+fn invert<I>(checked: &mut [bool], selection_order: &mut Vec<usize>, items: I)
+where
+    I: Iterator<Item = usize>,
+{
+    for idx in items {
+        checked[idx] = !checked[idx];
+
+        if checked[idx] {
+            selection_order.push(idx);
+        } else {
+            selection_order.retain(|&selected| selected != idx);
         }
     }
 }
 
+/// Returns the indices of the checked items.
+fn checked_indices(checked: &[bool]) -> Vec<usize> {
+    checked
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, &checked)| if checked { Some(idx) } else { None })
+        .collect()
+}
+
+impl<T: Clone> Prompt for MultiSelect<'_, T> {
+    type Output = Vec<usize>;
+
+    fn interact_on(&mut self, term: &Term) -> Result<Vec<usize>> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<Vec<usize>> {
+        self.clone().interact()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,4 +1626,68 @@ mod tests {
 
         assert_eq!(MultiSelect::new().items(iterator).items, &items[1..]);
     }
+
+    #[test]
+    fn test_values() {
+        let multi_select = MultiSelect::new()
+            .item("a")
+            .item_with_description("b", "B")
+            .items_borrowed(["c"]);
+        assert_eq!(multi_select.values.len(), multi_select.items.len());
+
+        let multi_select = MultiSelect::with_values()
+            .item_with_value("US East", "us-east-1")
+            .item_with_value("Europe", "eu-west-1");
+        assert_eq!(multi_select.items, ["US East", "Europe"]);
+        assert_eq!(multi_select.values, ["us-east-1", "eu-west-1"]);
+        assert_eq!(multi_select.defaults, [false, false]);
+    }
+
+    #[test]
+    fn test_validate_with() {
+        let multi_select = MultiSelect::new()
+            .validate_with(|selections: &[usize]| {
+                if selections.is_empty() {
+                    Err("Select at least one item")
+                } else {
+                    Ok(())
+                }
+            })
+            .validate_with(|selections: &[usize]| {
+                if selections.len() > 2 {
+                    Err("Select at most two items")
+                } else {
+                    Ok(())
+                }
+            });
+
+        assert_eq!(
+            multi_select.validate(&[]),
+            Some("Select at least one item".to_string())
+        );
+        assert_eq!(multi_select.validate(&[0, 2]), None);
+        assert_eq!(
+            multi_select.validate(&[0, 1, 2]),
+            Some("Select at most two items".to_string())
+        );
+    }
+
+    #[test]
+    fn test_required() {
+        let multi_select = MultiSelect::new().items(["a", "b"]).required(true);
+
+        assert!(multi_select.validate(&[]).is_some());
+        assert!(multi_select.validate(&[1]).is_none());
+    }
+
+    #[test]
+    fn test_invert() {
+        let mut checked = vec![true, false, true, false];
+        let mut selection_order = vec![2, 0];
+
+        invert(&mut checked, &mut selection_order, 1..4);
+
+        assert_eq!(checked, [true, true, false, true]);
+        assert_eq!(selection_order, [0, 1, 3]);
+    }
 }
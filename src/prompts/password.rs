@@ -1,13 +1,26 @@
-use std::{io, sync::Arc};
+#[cfg(feature = "keyring")]
+use std::io;
+use std::{mem, sync::Arc};
 
-use console::Term;
+use console::{Key, Term};
 use zeroize::Zeroizing;
 
+#[cfg(feature = "clipboard")]
 use crate::{
+    clipboard,
+    prompts::{is_paste_key, single_line_chars},
+};
+use crate::{
+    observe::{Observers, PromptState},
+    prompts::{AbortKeys, Prompt, Target, CTRL_D},
+    signals::Cleanup,
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
-    validate::PasswordValidator,
-    Result,
+    trace,
+    validate::{PasswordPolicy, PasswordValidator},
+    Error, Result,
 };
+#[cfg(feature = "keyring")]
+use crate::{translate, Confirm};
 
 type PasswordValidatorCallback<'a> = Arc<dyn Fn(&String) -> Option<String> + 'a>;
 
@@ -30,12 +43,18 @@ type PasswordValidatorCallback<'a> = Arc<dyn Fn(&String) -> Option<String> + 'a>
 /// ```
 #[derive(Clone)]
 pub struct Password<'a> {
-    prompt: String,
+    pub(crate) prompt: String,
     report: bool,
+    observers: Observers<'a>,
     theme: &'a dyn Theme,
     allow_empty_password: bool,
     confirmation_prompt: Option<(String, String)>,
     validator: Option<PasswordValidatorCallback<'a>>,
+    bell: bool,
+    pub(crate) abort_keys: AbortKeys,
+    output: Target,
+    #[cfg(feature = "keyring")]
+    keyring: Option<(String, String)>,
 }
 
 impl Default for Password<'static> {
@@ -58,6 +77,33 @@ impl Password<'_> {
         self
     }
 
+    /// Indicates whether to ring the terminal bell when the input is rejected.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc' and Ctrl-D, which
+    /// only cancels on an empty line.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
     /// Indicates whether to report confirmation after interaction.
     ///
     /// The default is to report.
@@ -76,6 +122,39 @@ impl Password<'_> {
         self
     }
 
+    /// Stores the password in the keyring of the operating system.
+    ///
+    /// If a password is saved for `user` of `service`, the prompt first asks whether
+    /// to use it. Otherwise, or if the user declines, a password is entered as usual
+    /// and the prompt asks whether to save it, which replaces the saved one. A
+    /// saved password which is rejected by the validators or the
+    /// [`policy`](Self::policy) is ignored, so a new one is entered instead. A
+    /// keyring which can't be read is treated like a keyring without a password,
+    /// an error when saving the password is returned as [`Error::IO`].
+    ///
+    /// On Linux the password is stored with the Secret Service, like GNOME Keyring
+    /// or KWallet, so it persists across logins. This requires a running Secret
+    /// Service; without one the prompt behaves as if no password was saved.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Password;
+    ///
+    /// fn main() {
+    ///     let token = Password::new()
+    ///         .with_prompt("API token")
+    ///         .with_keyring("my-cli", "alice")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "keyring")]
+    pub fn with_keyring<S: Into<String>, U: Into<String>>(mut self, service: S, user: U) -> Self {
+        self.keyring = Some((service.into(), user.into()));
+        self
+    }
+
     /// Allows/Disables empty password.
     ///
     /// By default this setting is set to false (i.e. password is not empty).
@@ -87,34 +166,127 @@ impl Password<'_> {
     /// Enables user interaction and returns the result.
     ///
     /// If the user confirms the result is `Ok()`, `Err()` otherwise.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc'.
     pub fn interact(self) -> Result<String> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
+    }
+
+    /// Enables user interaction and returns the result.
+    ///
+    /// Result contains `Some(password)` if the user entered a password or `None`
+    /// if the user cancelled with 'Esc'.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Password;
+    ///
+    /// fn main() {
+    ///     let passphrase = Password::new()
+    ///         .with_prompt("Passphrase (Esc to skip decryption)")
+    ///         .interact_opt()
+    ///         .unwrap();
+    ///
+    ///     match passphrase {
+    ///         Some(passphrase) => println!("Decrypting with a passphrase of {} characters", passphrase.len()),
+    ///         None => println!("Skipping decryption"),
+    ///     }
+    /// }
+    /// ```
+    pub fn interact_opt(self) -> Result<Option<String>> {
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
     pub fn interact_on(self, term: &Term) -> Result<String> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
+    pub fn interact_on_opt(self, term: &Term) -> Result<Option<String>> {
+        self._interact_on(term, true)
+    }
+
+    /// Like `interact_on` but allows to cancel the prompt with 'Esc' if `allow_quit` is set.
+    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<String>> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
         }
 
+        let _span = trace::shown("password", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact(term, allow_quit), |_| None)
+    }
+
+    fn _interact(self, term: &Term, allow_quit: bool) -> Result<Option<String>> {
         let mut render = TermThemeRenderer::new(term, self.theme);
         render.set_prompts_reset_height(false);
 
+        #[cfg(feature = "keyring")]
+        let entry = match self.keyring {
+            Some((ref service, ref user)) => keyring::Entry::new(service, user).ok(),
+            None => None,
+        };
+
+        #[cfg(feature = "keyring")]
+        if let Some(saved) = entry
+            .as_ref()
+            .and_then(|entry| entry.get_password().ok())
+            .filter(|saved| {
+                self.validator
+                    .as_ref()
+                    .map_or(true, |validator| validator(saved).is_none())
+            })
+        {
+            let prompt = translate::text("use-saved-password", "Use the saved password?");
+            match self.ask(term, allow_quit, &prompt)? {
+                Some(true) => {
+                    if self.report {
+                        render.password_prompt_selection(&self.prompt)?;
+                    }
+                    term.flush()?;
+
+                    return Ok(Some(saved));
+                }
+                Some(false) => {}
+                None => return Ok(None),
+            }
+        }
+
         loop {
-            let password = Zeroizing::new(self.prompt_password(&mut render, &self.prompt)?);
+            let password = match self.prompt_password(&mut render, &self.prompt, allow_quit)? {
+                Some(password) => Zeroizing::new(password),
+                None => return Ok(None),
+            };
 
             if let Some(ref validator) = self.validator {
                 if let Some(err) = validator(&password) {
+                    trace::validation_failed(&err);
+                    if self.bell {
+                        render.bell()?;
+                    }
                     render.error(&err)?;
                     continue;
                 }
             }
 
             if let Some((ref prompt, ref err)) = self.confirmation_prompt {
-                let pw2 = Zeroizing::new(self.prompt_password(&mut render, prompt)?);
+                let pw2 = match self.prompt_password(&mut render, prompt, allow_quit)? {
+                    Some(password) => Zeroizing::new(password),
+                    None => return Ok(None),
+                };
 
                 if *password != *pw2 {
+                    trace::validation_failed(err);
+                    if self.bell {
+                        render.bell()?;
+                    }
                     render.error(err)?;
                     continue;
                 }
@@ -122,26 +294,129 @@ impl Password<'_> {
 
             render.clear()?;
 
+            #[cfg(feature = "keyring")]
+            if let Some(ref entry) = entry {
+                let prompt = translate::text("save-password", "Save the password?");
+                match self.ask(term, allow_quit, &prompt)? {
+                    Some(true) => entry
+                        .set_password(&password)
+                        .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?,
+                    Some(false) => {}
+                    None => return Ok(None),
+                }
+            }
+
             if self.report {
                 render.password_prompt_selection(&self.prompt)?;
             }
             term.flush()?;
 
-            return Ok((*password).clone());
+            return Ok(Some((*password).clone()));
+        }
+    }
+
+    /// Asks a question about the keyring, which is answered with yes by default.
+    #[cfg(feature = "keyring")]
+    fn ask(&self, term: &Term, allow_quit: bool, prompt: &str) -> Result<Option<bool>> {
+        let confirm = Confirm::with_theme(self.theme)
+            .with_prompt(prompt)
+            .default(true)
+            .report(false);
+
+        if allow_quit {
+            confirm.interact_on_opt(term)
+        } else {
+            confirm.interact_on(term).map(Some)
         }
     }
 
-    fn prompt_password(&self, render: &mut TermThemeRenderer, prompt: &str) -> Result<String> {
+    fn prompt_password(
+        &self,
+        render: &mut TermThemeRenderer,
+        prompt: &str,
+        allow_quit: bool,
+    ) -> Result<Option<String>> {
         loop {
             render.password_prompt(prompt)?;
             render.term().flush()?;
-
-            let input = render.term().read_secure_line()?;
+            self.observers.rendered(PromptState::Password { len: 0 });
+
+            // Reading the password key by key allows to paste from the clipboard
+            let input = if allow_quit || cfg!(feature = "clipboard") {
+                match self.read_password(render, allow_quit)? {
+                    Some(input) => input,
+                    None => {
+                        render.term().clear_line()?;
+                        render.clear()?;
+                        render.term().flush()?;
+
+                        return Ok(None);
+                    }
+                }
+            } else {
+                render.term().read_secure_line()?
+            };
 
             render.add_line();
 
             if !input.is_empty() || self.allow_empty_password {
-                return Ok(input);
+                return Ok(Some(input));
+            }
+
+            if self.bell {
+                render.bell()?;
+            }
+        }
+    }
+
+    /// Reads a password key by key and returns `None` if the user pressed 'Esc'.
+    fn read_password(
+        &self,
+        render: &mut TermThemeRenderer,
+        allow_quit: bool,
+    ) -> Result<Option<String>> {
+        let mut input = Zeroizing::new(String::new());
+
+        loop {
+            let key = self.abort_keys.read_key(render.term(), allow_quit)?;
+            self.observers.key_pressed(
+                &key,
+                PromptState::Password {
+                    len: input.chars().count(),
+                },
+            );
+
+            match key {
+                Key::Enter => {
+                    render.term().write_line("")?;
+
+                    return Ok(Some(mem::take(&mut *input)));
+                }
+                key if allow_quit
+                    && self.abort_keys.contains(&key)
+                    && (key != CTRL_D || input.is_empty()) =>
+                {
+                    return Ok(None)
+                }
+                Key::Backspace => {
+                    input.pop();
+                }
+                #[cfg(feature = "clipboard")]
+                key if is_paste_key(render.term(), &key)? => {
+                    match clipboard::read().map(Zeroizing::new) {
+                        Some(text) => {
+                            single_line_chars(&text).for_each(|chr| push_secret(&mut input, chr))
+                        }
+                        None if self.bell => render.bell()?,
+                        None => {}
+                    }
+                }
+                Key::Char(chr) if !chr.is_ascii_control() => push_secret(&mut input, chr),
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
             }
         }
     }
@@ -192,6 +467,28 @@ impl<'a> Password<'a> {
         self
     }
 
+    /// Registers a password policy, which rejects weak passwords.
+    ///
+    /// Like [`validate_with`](Self::validate_with) this can be combined with further
+    /// validators.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{Password, PasswordPolicy};
+    ///
+    /// fn main() {
+    ///     let password = Password::new()
+    ///         .with_prompt("New password")
+    ///         .policy(PasswordPolicy::new().min_len(12).require_symbol(true))
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn policy(self, policy: PasswordPolicy) -> Self {
+        self.validate_with(policy)
+    }
+
     /// Creates a password input prompt with a specific theme.
     ///
     /// ## Example
@@ -209,12 +506,58 @@ impl<'a> Password<'a> {
         Self {
             prompt: "".into(),
             report: true,
+            observers: Observers::default(),
             theme,
             allow_empty_password: false,
             confirmation_prompt: None,
             validator: None,
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, CTRL_D]),
+            output: Target::default(),
+            #[cfg(feature = "keyring")]
+            keyring: None,
         }
     }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for Password<'_> {
+    type Output = String;
+
+    fn interact_on(&mut self, term: &Term) -> Result<String> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<String> {
+        self.clone().interact()
+    }
+}
+
+/// Appends a character to a secret input.
+///
+/// Growing a string in place leaves the old buffer in memory, so a full input is
+/// moved into a larger buffer first and the old one is zeroized.
+fn push_secret(input: &mut Zeroizing<String>, chr: char) {
+    if input.capacity() - input.len() < chr.len_utf8() {
+        let mut grown = String::with_capacity((2 * input.capacity()).max(32));
+        grown.push_str(input);
+        *input = Zeroizing::new(grown);
+    }
+
+    input.push(chr);
 }
 
 #[cfg(test)]
@@ -227,4 +570,17 @@ mod tests {
 
         let _ = password.clone();
     }
+
+    #[test]
+    fn test_push_secret() {
+        let mut input = Zeroizing::new(String::new());
+        "pässword"
+            .chars()
+            .cycle()
+            .take(100)
+            .for_each(|chr| push_secret(&mut input, chr));
+
+        assert_eq!(input.chars().count(), 100);
+        assert!(input.starts_with("pässwordpäss"));
+    }
 }
@@ -0,0 +1,374 @@
+use console::{Key, Term};
+
+use crate::{
+    observe::{Observers, PromptState},
+    prompts::{AbortKeys, Prompt, Target, CTRL_D},
+    signals::Cleanup,
+    theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    trace, Error, Result,
+};
+
+/// Renders a slider with two handles to select a range of numbers.
+///
+/// 'Tab' switches between the handles of the lower and the upper bound, the arrow
+/// keys move the active handle by one step and 'Home' and 'End' as far as possible.
+/// The lower bound can't be moved above the upper bound and vice versa.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::RangeSlider;
+///
+/// fn main() {
+///     let (low, high) = RangeSlider::new()
+///         .with_prompt("Port range")
+///         .bounds(1024, 65535)
+///         .step(100)
+///         .default(8000, 9000)
+///         .interact()
+///         .unwrap();
+///
+///     println!("Listening on ports {} to {}", low, high);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RangeSlider<'a> {
+    prompt: String,
+    report: bool,
+    bounds: (i64, i64),
+    step: i64,
+    default: Option<(i64, i64)>,
+    width: usize,
+    bell: bool,
+    abort_keys: AbortKeys,
+    output: Target,
+    observers: Observers<'a>,
+    theme: &'a dyn Theme,
+}
+
+impl Default for RangeSlider<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RangeSlider<'static> {
+    /// Creates a range slider with default theme.
+    pub fn new() -> Self {
+        Self::with_theme(&SimpleTheme)
+    }
+}
+
+impl RangeSlider<'_> {
+    /// Sets the range slider prompt.
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Indicates whether to report the selected range after interaction.
+    ///
+    /// The default is to report the selected range.
+    pub fn report(mut self, val: bool) -> Self {
+        self.report = val;
+        self
+    }
+
+    /// Sets the smallest and the largest number which can be selected.
+    ///
+    /// The default is 0 to 100.
+    pub fn bounds(mut self, min: i64, max: i64) -> Self {
+        self.bounds = (min.min(max), min.max(max));
+        self
+    }
+
+    /// Sets the amount the arrow keys move a handle by.
+    ///
+    /// The default is 1.
+    pub fn step(mut self, val: i64) -> Self {
+        self.step = val.max(1);
+        self
+    }
+
+    /// Sets the range which is selected initially.
+    ///
+    /// The default is the whole range between the [`bounds`](Self::bounds).
+    pub fn default(mut self, low: i64, high: i64) -> Self {
+        self.default = Some((low.min(high), low.max(high)));
+        self
+    }
+
+    /// Sets the width of the bar in cells.
+    ///
+    /// The default is 40.
+    pub fn width(mut self, val: usize) -> Self {
+        self.width = val;
+        self
+    }
+
+    /// Indicates whether to ring the terminal bell when a key press is ignored
+    /// or a handle can't be moved further.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning. If [`Key::CtrlC`]
+    /// is in the list, Ctrl-C cancels the prompt instead of interrupting the process.
+    /// The default is 'Esc', 'q' and Ctrl-D.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Enables user interaction and returns the selected range as `(low, high)`.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
+    pub fn interact(self) -> Result<(i64, i64)> {
+        let term = self.output.term()?;
+        self.interact_on(&term)
+    }
+
+    /// Enables user interaction and returns the selected range as `(low, high)`.
+    ///
+    /// Result contains `Some((low, high))` if the user hit 'Enter' or `None` if
+    /// the user cancelled with 'Esc' or 'q'.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    pub fn interact_opt(self) -> Result<Option<(i64, i64)>> {
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
+    }
+
+    /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
+    pub fn interact_on(self, term: &Term) -> Result<(i64, i64)> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
+    pub fn interact_on_opt(self, term: &Term) -> Result<Option<(i64, i64)>> {
+        self._interact_on(term, true)
+    }
+
+    /// Like `interact_on` but allows to cancel the prompt with 'Esc' if `allow_quit` is set.
+    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<(i64, i64)>> {
+        if !term.is_term() {
+            return Err(Error::NotATerminal);
+        }
+
+        let _span = trace::shown("range_slider", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact(term, allow_quit), |(low, high)| {
+            Some(format!("{}..={}", low, high))
+        })
+    }
+
+    fn _interact(self, term: &Term, allow_quit: bool) -> Result<Option<(i64, i64)>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let (min, max) = self.bounds;
+        let mut range = match self.default {
+            Some((low, high)) => (low.clamp(min, max), high.clamp(min, max)),
+            None => self.bounds,
+        };
+        let mut low_active = true;
+
+        term.hide_cursor()?;
+
+        loop {
+            render.begin_frame();
+            render.clear()?;
+
+            if !self.prompt.is_empty() {
+                render.prompt(&self.prompt)?;
+            }
+
+            render.range_slider_bar(
+                &range.0.to_string(),
+                &range.1.to_string(),
+                (self.cell(range.0), self.cell(range.1)),
+                self.width,
+                low_active,
+            )?;
+
+            render.end_frame()?;
+
+            let state = PromptState::RangeSlider {
+                low: range.0,
+                high: range.1,
+                low_active,
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+
+            let moved = match key {
+                Key::Enter => {
+                    render.clear()?;
+
+                    if self.report {
+                        render.range_slider_prompt_selection(
+                            &self.prompt,
+                            &range.0.to_string(),
+                            &range.1.to_string(),
+                        )?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(Some(range));
+                }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    render.clear()?;
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
+                Key::Tab | Key::BackTab => {
+                    low_active = !low_active;
+                    continue;
+                }
+                Key::ArrowLeft | Key::ArrowDown | Key::Char('h') => {
+                    self.moved(range, low_active, -self.step)
+                }
+                Key::ArrowRight | Key::ArrowUp | Key::Char('l') => {
+                    self.moved(range, low_active, self.step)
+                }
+                Key::Home => self.moved(range, low_active, i64::MIN),
+                Key::End => self.moved(range, low_active, i64::MAX),
+                _ => range,
+            };
+
+            if moved == range && self.bell {
+                render.bell()?;
+            }
+            range = moved;
+        }
+    }
+
+    /// Returns the range after moving the active handle by `delta`, which stops at
+    /// the bounds and at the other handle.
+    fn moved(&self, (low, high): (i64, i64), low_active: bool, delta: i64) -> (i64, i64) {
+        let (min, max) = self.bounds;
+
+        if low_active {
+            (low.saturating_add(delta).clamp(min, high), high)
+        } else {
+            (low, high.saturating_add(delta).clamp(low, max))
+        }
+    }
+
+    /// Returns the cell of the bar which shows `value`.
+    fn cell(&self, value: i64) -> usize {
+        let (min, max) = self.bounds;
+        if max == min {
+            return 0;
+        }
+
+        let cells = self.width.saturating_sub(1) as i128;
+        let offset = (value as i128 - min as i128) * cells / (max as i128 - min as i128);
+        offset as usize
+    }
+}
+
+impl<'a> RangeSlider<'a> {
+    /// Creates a range slider with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{theme::ColorfulTheme, RangeSlider};
+    ///
+    /// fn main() {
+    ///     let (low, high) = RangeSlider::with_theme(&ColorfulTheme::default())
+    ///         .with_prompt("Price between")
+    ///         .bounds(0, 500)
+    ///         .step(10)
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self {
+            prompt: "".into(),
+            report: true,
+            bounds: (0, 100),
+            step: 1,
+            default: None,
+            width: 40,
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, Key::Char('q'), CTRL_D]),
+            output: Target::default(),
+            observers: Observers::default(),
+            theme,
+        }
+    }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for RangeSlider<'_> {
+    type Output = (i64, i64);
+
+    fn interact_on(&mut self, term: &Term) -> Result<(i64, i64)> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<(i64, i64)> {
+        self.clone().interact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moved() {
+        let slider = RangeSlider::new().bounds(0, 100);
+
+        assert_eq!(slider.moved((10, 20), true, 5), (15, 20));
+        assert_eq!(slider.moved((10, 20), true, 50), (20, 20));
+        assert_eq!(slider.moved((10, 20), false, -50), (10, 10));
+        assert_eq!(slider.moved((10, 20), true, i64::MIN), (0, 20));
+        assert_eq!(slider.moved((10, 20), false, i64::MAX), (10, 100));
+    }
+
+    #[test]
+    fn test_cell() {
+        let slider = RangeSlider::new().bounds(-50, 50).width(11);
+
+        assert_eq!(slider.cell(-50), 0);
+        assert_eq!(slider.cell(0), 5);
+        assert_eq!(slider.cell(50), 10);
+        assert_eq!(RangeSlider::new().bounds(1, 1).cell(1), 0);
+    }
+}
@@ -1,12 +1,38 @@
-use std::{io, ops::Rem};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    mem,
+    ops::Rem,
+    path::Path,
+    sync::{Arc, Mutex},
+};
 
-use console::{Key, Term};
+use console::{measure_text_width, Key, Term};
 
 use crate::{
+    filter::matches_filter,
+    item_list::ItemList,
+    memory::Memory,
+    observe::{Observers, PromptState},
+    prompts::{
+        accessible_from_env, fetch_on_thread, render_fetching, AbortKeys, AltScreen, Outcome,
+        Prompt, Target, WatchKeys, CTRL_D, CTRL_Z,
+    },
+    signals::{suspend, Cleanup},
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
-    Paging, Result,
+    trace, translate,
+    validate::InputValidator,
+    Error, Frecency, Paging, Result, ScrollMode,
 };
 
+type SelectValidatorCallback<'a> = Arc<Mutex<dyn FnMut(&usize) -> Option<String> + 'a>>;
+type SelectLoaderCallback<'a, T> = Arc<Mutex<dyn FnMut() -> Vec<(String, T)> + 'a>>;
+type SelectKeyActionCallback<'a, T> = Arc<Mutex<dyn FnMut(&mut ItemList<'a, T>) -> Result + 'a>>;
+type SelectPinsCallback<'a> = Arc<Mutex<dyn FnMut(&[String]) + 'a>>;
+// Fetches the items while calling the callback with the tick of the spinner
+type SelectFetchCallback<'a, T> =
+    Arc<Mutex<dyn FnMut(&mut dyn FnMut(usize) -> Result) -> Result<Vec<(String, T)>> + 'a>>;
+
 /// Renders a select prompt.
 ///
 /// User can select from one or more options.
@@ -29,31 +55,66 @@ use crate::{
 ///     println!("You chose: {}", items[selection]);
 /// }
 /// ```
+///
+/// Items can also carry a value which is returned instead of the index, see
+/// [`item_with_value`](Self::item_with_value).
 #[derive(Clone)]
-pub struct Select<'a> {
+pub struct Select<'a, T = ()> {
     default: usize,
-    items: Vec<String>,
-    prompt: Option<String>,
+    pub(crate) items: Vec<Cow<'a, str>>,
+    values: Vec<T>,
+    pub(crate) prompt: Option<String>,
     report: bool,
     clear: bool,
+    accessible: bool,
+    bell: bool,
+    pub(crate) abort_keys: AbortKeys,
+    watch_keys: WatchKeys,
+    output: Target,
+    memory: Option<Memory>,
+    frecency: Option<Frecency>,
+    scores: HashMap<String, f64>,
+    pub(crate) observers: Observers<'a>,
     theme: &'a dyn Theme,
     max_length: Option<usize>,
+    scroll_mode: ScrollMode,
+    scroll_margin: usize,
+    grid: bool,
+    fullscreen: bool,
+    filter_mode: bool,
+    validator: Option<SelectValidatorCallback<'a>>,
+    loader: Option<SelectLoaderCallback<'a, T>>,
+    fetch: Option<SelectFetchCallback<'a, T>>,
+    key_actions: Vec<(Key, SelectKeyActionCallback<'a, T>)>,
+    pin_key: Option<Key>,
+    pins: Vec<String>,
+    pins_changed: Option<SelectPinsCallback<'a>>,
 }
 
-impl Default for Select<'static> {
+impl Default for Select<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Select<'static> {
+impl Select<'_> {
     /// Creates a select prompt with default theme.
     pub fn new() -> Self {
         Self::with_theme(&SimpleTheme)
     }
 }
 
-impl Select<'_> {
+impl<T> Select<'_, T> {
+    /// Creates a select prompt whose items carry values, with default theme.
+    ///
+    /// The type of the values is usually inferred from
+    /// [`item_with_value`](Self::item_with_value).
+    pub fn with_values() -> Self {
+        Self::with_values_and_theme(&SimpleTheme)
+    }
+}
+
+impl<T> Select<'_, T> {
     /// Indicates whether select menu should be erased from the screen after interaction.
     ///
     /// The default is to clear the menu.
@@ -82,6 +143,66 @@ impl Select<'_> {
         self
     }
 
+    /// Sets how the items are scrolled if they don't fit on the terminal.
+    ///
+    /// [`ScrollMode::Window`] slides the visible items line by line with the cursor
+    /// and shows the position of the cursor, like "Item 37/120", instead of the page.
+    /// A [`grid`](Self::grid) always flips pages.
+    /// The default is [`ScrollMode::Page`], which flips whole pages.
+    pub fn scroll_mode(mut self, val: ScrollMode) -> Self {
+        self.scroll_mode = val;
+        self
+    }
+
+    /// Sets the number of items kept visible above and below the cursor.
+    ///
+    /// This implies [`ScrollMode::Window`]: the list scrolls line by line as soon as
+    /// the cursor comes within `val` items of the top or bottom edge, like editors do.
+    /// The default is 0, i.e. to scroll when the cursor leaves the visible items.
+    pub fn scroll_margin(mut self, val: usize) -> Self {
+        self.scroll_mode = ScrollMode::Window;
+        self.scroll_margin = val;
+        self
+    }
+
+    /// Indicates whether to lay out the items in columns which fill the terminal width, like `ls`.
+    ///
+    /// The arrows move the cursor in all four directions. This suits many short items,
+    /// which then need a lot fewer pages. The default is one item per line.
+    pub fn grid(mut self, val: bool) -> Self {
+        self.grid = val;
+        self
+    }
+
+    /// Indicates whether to show the prompt on the whole terminal screen.
+    ///
+    /// The prompt is shown on the alternate screen with a line explaining the keys,
+    /// which gives long lists as much room as possible. The previous output is shown
+    /// again afterwards, followed by the report. This has no effect in
+    /// [`accessible`](Self::accessible) mode. The default is to render the prompt
+    /// below the previous output.
+    pub fn fullscreen(mut self, val: bool) -> Self {
+        self.fullscreen = val;
+        self
+    }
+
+    /// Indicates whether the items can be filtered.
+    ///
+    /// When enabled, pressing '/' opens a filter line and typing narrows the visible items
+    /// to the ones containing the filter (case-insensitive). The arrows move through the
+    /// filtered items, 'Enter' selects the highlighted one and 'Esc' clears the filter.
+    /// The index of the item in the whole list is returned. This has no effect on a
+    /// [`grid`](Self::grid) or in [`accessible`](Self::accessible) mode, `FuzzySelect`
+    /// matches fuzzily instead.
+    ///
+    /// The default is to disable filtering.
+    pub fn filter_mode(mut self, val: bool) -> Self {
+        self.filter_mode = val;
+        self
+    }
+}
+
+impl Select<'_> {
     /// Add a single item to the selector.
     ///
     /// ## Example
@@ -98,7 +219,8 @@ impl Select<'_> {
     /// }
     /// ```
     pub fn item<T: ToString>(mut self, item: T) -> Self {
-        self.items.push(item.to_string());
+        self.items.push(item.to_string().into());
+        self.values.push(());
 
         self
     }
@@ -110,7 +232,36 @@ impl Select<'_> {
         I: IntoIterator<Item = T>,
     {
         self.items
-            .extend(items.into_iter().map(|item| item.to_string()));
+            .extend(items.into_iter().map(|item| item.to_string().into()));
+        self.values.resize(self.items.len(), ());
+
+        self
+    }
+}
+
+impl<T> Select<'_, T> {
+    /// Adds an item which carries a value.
+    ///
+    /// The value of the selected item is returned by
+    /// [`interact_value`](Self::interact_value).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let user_id: u32 = Select::with_values()
+    ///         .with_prompt("Assign to")
+    ///         .item_with_value("Alice", 7)
+    ///         .item_with_value("Bob", 42)
+    ///         .interact_value()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn item_with_value<S: ToString>(mut self, item: S, value: T) -> Self {
+        self.items.push(item.to_string().into());
+        self.values.push(value);
 
         self
     }
@@ -133,21 +284,108 @@ impl Select<'_> {
         self
     }
 
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc', 'q' and Ctrl-D.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets keys which end the prompt, so that the caller can react to them, e.g.
+    /// show help on '?' or details of the highlighted item on 'd'.
+    ///
+    /// Watched keys take precedence over the usual meaning of the keys. They are only
+    /// watched by [`interact_outcome`](Self::interact_outcome), the other `interact`
+    /// methods ignore them.
+    pub fn watch_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.watch_keys = WatchKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Remembers the selected item in a file and selects it by default the next time.
+    ///
+    /// The file can be shared by several prompts with different ids. Items are
+    /// recognized by their text, so the memory survives reordered or new items. Errors
+    /// when reading or writing the file are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let selection = Select::new()
+    ///         .with_prompt("Which region?")
+    ///         .items(&["us-east-1", "eu-west-1", "ap-south-1"])
+    ///         .remember_as("region", "/tmp/my-cli/answers")
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn remember_as<I: Into<String>, P: AsRef<Path>>(mut self, id: I, path: P) -> Self {
+        self.memory = Some(Memory::new(id.into(), path));
+        self
+    }
+
+    /// Lists the items which were chosen most frequently and recently first, and
+    /// records the chosen item.
+    ///
+    /// Pinned items are still listed above. Interaction returns the index of the item
+    /// as it was added. The order is not applied in [`grid`](Self::grid) and
+    /// [`accessible`](Self::accessible) mode. See [`Frecency`] for an example.
+    pub fn frecency(mut self, frecency: Frecency) -> Self {
+        self.frecency = Some(frecency);
+        self
+    }
+
+    /// Indicates whether to render the prompt for screen readers.
+    ///
+    /// In accessible mode the prompt is never redrawn. Instead, every change of
+    /// the highlighted item is announced on a new line, like
+    /// "Highlighted: option 3 of 7, Banana".
+    ///
+    /// The default is to enable it if the `DIALOGUER_ACCESSIBLE` environment
+    /// variable is set to a value other than `0`.
+    pub fn accessible(mut self, val: bool) -> Self {
+        self.accessible = val;
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// The user can select the items with the 'Space' bar or 'Enter' and the index of selected item will be returned.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `index` if user selected one of items using 'Enter'.
     /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
     #[inline]
     pub fn interact(self) -> Result<usize> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
     }
 
     /// Enables user interaction and returns the result.
     ///
     /// The user can select the items with the 'Space' bar or 'Enter' and the index of selected item will be returned.
-    /// The dialog is rendered on stderr.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `Some(index)` if user selected one of items using 'Enter' or `None` if user cancelled with 'Esc' or 'q'.
     ///
     /// ## Example
@@ -172,151 +410,924 @@ impl Select<'_> {
     ///```
     #[inline]
     pub fn interact_opt(self) -> Result<Option<usize>> {
-        self.interact_on_opt(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
     #[inline]
-    pub fn interact_on(self, term: &Term) -> Result<usize> {
-        Ok(self
-            ._interact_on(term, false)?
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))?)
+    pub fn interact_on(mut self, term: &Term) -> Result<usize> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
     }
 
     /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
     #[inline]
-    pub fn interact_on_opt(self, term: &Term) -> Result<Option<usize>> {
+    pub fn interact_on_opt(mut self, term: &Term) -> Result<Option<usize>> {
         self._interact_on(term, true)
     }
 
+    /// Enables user interaction and returns the outcome.
+    ///
+    /// Like [`interact_opt`](Self::interact_opt), but the prompt also ends when the user
+    /// presses one of the [`watch_keys`](Self::watch_keys), which is returned with the
+    /// index of the highlighted item.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{console::Key, Outcome, Select};
+    ///
+    /// fn main() {
+    ///     let items = ["foo", "bar", "baz"];
+    ///     let mut default = 0;
+    ///
+    ///     loop {
+    ///         let outcome = Select::new()
+    ///             .items(&items)
+    ///             .default(default)
+    ///             .watch_keys([Key::Char('d')])
+    ///             .interact_outcome()
+    ///             .unwrap();
+    ///
+    ///         match outcome {
+    ///             Outcome::Answered(index) => break println!("You chose: {}", items[index]),
+    ///             Outcome::Key {
+    ///                 highlighted: Some(index),
+    ///                 ..
+    ///             } => {
+    ///                 println!("{} is item #{}", items[index], index + 1);
+    ///                 default = index;
+    ///             }
+    ///             _ => break,
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn interact_outcome(self) -> Result<Outcome<usize>> {
+        let term = self.output.term()?;
+        self.interact_outcome_on(&term)
+    }
+
+    /// Like [`interact_outcome`](Self::interact_outcome) but allows a specific terminal to be set.
+    pub fn interact_outcome_on(mut self, term: &Term) -> Result<Outcome<usize>> {
+        let watch_keys = self.watch_keys.activate();
+        let answer = self._interact_on(term, true)?;
+
+        Ok(watch_keys.outcome(answer))
+    }
+
+    /// Like [`interact`](Self::interact) but returns the value of the selected item.
+    #[inline]
+    pub fn interact_value(self) -> Result<T> {
+        self._interact_value(false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but returns the value of the selected item.
+    #[inline]
+    pub fn interact_value_opt(self) -> Result<Option<T>> {
+        self._interact_value(true)
+    }
+
+    fn _interact_value(mut self, allow_quit: bool) -> Result<Option<T>> {
+        let term = self.output.term()?;
+
+        Ok(self
+            ._interact_on(&term, allow_quit)?
+            .map(|sel| self.values.swap_remove(sel)))
+    }
+
     /// Like `interact` but allows a specific terminal to be set.
-    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
+    fn _interact_on(&mut self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
+        }
+
+        if let Some(fetch) = self.fetch.clone() {
+            self.fetch_items_on(term, &fetch)?;
         }
 
         if self.items.is_empty() {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Empty list of items given to `Select`",
-            ))?;
+            self.load_batch();
         }
 
-        let mut paging = Paging::new(term, self.items.len(), self.max_length);
-        let mut render = TermThemeRenderer::new(term, self.theme);
-        let mut sel = self.default;
+        if self.items.is_empty() {
+            return Err(Error::EmptyItems);
+        }
 
-        let mut size_vec = Vec::new();
+        if self.accessible || self.grid {
+            while self.load_batch() {}
+        }
 
-        for items in self
-            .items
-            .iter()
-            .flat_map(|i| i.split('\n'))
-            .collect::<Vec<_>>()
-        {
-            let size = &items.len();
-            size_vec.push(*size);
+        if let Some(remembered) = self.memory.as_ref().and_then(Memory::recall) {
+            if let Some(idx) = remembered
+                .first()
+                .and_then(|item| self.items.iter().position(|it| it == item))
+            {
+                self.default = idx;
+            }
+        }
+
+        if let Some(ref frecency) = self.frecency {
+            self.scores = frecency.scores();
+        }
+
+        let _span = trace::shown("select", self.prompt.as_deref());
+
+        let _cleanup = Cleanup::install(term);
+
+        let sel = if self.accessible {
+            self._interact_accessible(term, allow_quit)
+        } else if self.fullscreen {
+            self._interact_fullscreen(term, allow_quit)
+        } else if self.grid {
+            self._interact_grid(term, allow_quit)
+        } else {
+            self._interact_list(term, allow_quit)
+        };
+        let sel = trace::answered(sel, |&sel| Some(self.items[sel].to_string()))?;
+
+        if let (Some(memory), Some(sel)) = (&self.memory, sel) {
+            memory.remember(&[&self.items[sel]]);
+        }
+
+        if let (Some(frecency), Some(sel)) = (&self.frecency, sel) {
+            frecency.record(&self.items[sel]);
+        }
+
+        Ok(sel)
+    }
+
+    /// Like `_interact_on` but renders the prompt on the alternate screen and
+    /// reports the selection on the main screen.
+    fn _interact_fullscreen(&mut self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
+        let report = mem::replace(&mut self.report, false);
+        let alt_screen = AltScreen::enable(term)?;
+
+        let sel = if self.grid {
+            self._interact_grid(term, allow_quit)
+        } else {
+            self._interact_list(term, allow_quit)
+        };
+
+        drop(alt_screen);
+        self.report = report;
+        let sel = sel?;
+
+        if let (Some(prompt), Some(sel), true) = (&self.prompt, sel, report) {
+            TermThemeRenderer::new(term, self.theme)
+                .select_prompt_selection(prompt, &self.items[sel])?;
+            term.flush()?;
         }
 
+        Ok(sel)
+    }
+
+    /// Like `_interact_on` but renders one item per line.
+    fn _interact_list(&mut self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
+        let mut paging = Paging::new(term, self.len(), self.max_length);
+
+        paging.set_scroll_mode(self.scroll_mode);
+        paging.set_scroll_margin(self.scroll_margin);
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        // The items in the order they are listed, the pinned ones first
+        let (mut view, mut pinned) = self.pinned_view();
+        let mut sel = view
+            .iter()
+            .position(|&idx| idx == self.default)
+            .unwrap_or(!0);
+        let mut filter = String::new();
+        let mut filtering = false;
+        let mut error: Option<String> = None;
+
+        let mut size_vec = self.line_sizes(&view);
+
+        paging.reserve_lines(
+            usize::from(self.validator.is_some())
+                + usize::from(self.fullscreen)
+                + usize::from(self.filter_mode),
+        );
+
         term.hide_cursor()?;
         paging.update_page(sel);
 
         loop {
+            render.begin_frame();
+
             if let Some(ref prompt) = self.prompt {
                 paging.render_prompt(|paging_info| render.select_prompt(prompt, paging_info))?;
             }
 
-            for (idx, item) in self
-                .items
+            if filtering || !filter.is_empty() {
+                render.filter_prompt(&filter, filtering)?;
+            }
+
+            for (pos, &idx) in view
                 .iter()
                 .enumerate()
-                .skip(paging.current_page * paging.capacity)
+                .skip(paging.first_item())
                 .take(paging.capacity)
             {
-                render.select_prompt_item(item, sel == idx)?;
+                if pos < pinned {
+                    render.select_prompt_pinned_item(&self.items[idx], sel == pos)?;
+                } else {
+                    render.select_prompt_item(&self.items[idx], sel == pos)?;
+                }
             }
 
-            term.flush()?;
+            let first = paging.first_item();
 
-            match term.read_key()? {
-                Key::ArrowDown | Key::Tab | Key::Char('j') => {
-                    if sel == !0 {
-                        sel = 0;
+            if self.loader.is_some() && view.len() < first + paging.capacity {
+                render.load_more_item(sel == view.len())?;
+            }
+
+            if self.fullscreen {
+                render.key_help(&translate::text(
+                    "help-select",
+                    "Arrows move · Enter select",
+                ))?;
+            }
+
+            if let Some(err) = error.take() {
+                render.error(&err)?;
+            }
+
+            render.end_frame()?;
+
+            let state = PromptState::Select {
+                highlighted: view.get(sel).copied(),
+            };
+            self.observers.rendered(state);
+
+            let len = view.len();
+            // The number of rows, including the row to load more items
+            let rows = len + usize::from(self.loader.is_some());
+            let mut filter_changed = false;
+            let mut items_changed = false;
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+
+            match key {
+                Key::Char('/') if self.filter_mode && !filtering => {
+                    filtering = true;
+                }
+                Key::Escape if filtering => {
+                    filtering = false;
+
+                    if !filter.is_empty() {
+                        filter.clear();
+                        filter_changed = true;
+                    }
+                }
+                Key::Backspace if filtering => {
+                    filter_changed = filter.pop().is_some();
+                }
+                Key::Char(chr) if filtering && !chr.is_ascii_control() => {
+                    filter.push(chr);
+                    filter_changed = true;
+                }
+                key if !filtering && self.watch_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
                     } else {
-                        sel = (sel as u64 + 1).rem(self.items.len() as u64) as usize;
+                        term.clear_last_lines(paging.capacity)?;
                     }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    let highlighted = view.get(sel).copied();
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
                 }
-                Key::Escape | Key::Char('q') => {
-                    if allow_quit {
-                        if self.clear {
-                            render.clear()?;
-                        } else {
-                            term.clear_last_lines(paging.capacity)?;
-                        }
+                key if !filtering
+                    && self
+                        .key_actions
+                        .iter()
+                        .any(|(action_key, _)| *action_key == key) =>
+                {
+                    render.clear()?;
+                    term.show_cursor()?;
+                    let highlighted =
+                        self.run_key_action(&key, view.get(sel).copied().unwrap_or(!0))?;
+                    term.hide_cursor()?;
 
-                        term.show_cursor()?;
-                        term.flush()?;
+                    if self.items.is_empty() {
+                        return Err(Error::EmptyItems);
+                    }
+
+                    (view, pinned) = self.filtered_view(&filter);
+                    sel = view.iter().position(|&idx| idx == highlighted).unwrap_or(0);
+                    items_changed = true;
+                    size_vec = self.line_sizes(&view);
+                }
+                key if !filtering && self.pin_key.as_ref() == Some(&key) && sel < len => {
+                    let idx = view[sel];
+                    self.toggle_pin(idx);
+
+                    (view, pinned) = self.filtered_view(&filter);
+                    sel = view.iter().position(|&pos| pos == idx).unwrap_or(0);
+                    items_changed = true;
+                    size_vec = self.line_sizes(&view);
+                }
+                key if key == CTRL_Z && cfg!(unix) => {
+                    render.clear()?;
+                    suspend(term)?;
+                    items_changed = true;
+                }
+                Key::ArrowDown | Key::Tab | Key::Char('j') | Key::Enter | Key::Char(' ')
+                    if self.loader.is_some() && sel == len =>
+                {
+                    items_changed = true;
+                    let loaded = self.items.len();
+
+                    if !self.load_batch() {
+                        sel = len.checked_sub(1).unwrap_or(!0);
+                    }
 
-                        return Ok(None);
+                    for idx in loaded..self.items.len() {
+                        if matches_filter(&self.items[idx], &filter) {
+                            view.push(idx);
+                            size_vec.extend(self.items[idx].split('\n').map(str::len));
+                        }
                     }
                 }
-                Key::ArrowUp | Key::BackTab | Key::Char('k') => {
+                Key::ArrowDown | Key::Tab | Key::Char('j') if rows > 0 => {
                     if sel == !0 {
-                        sel = self.items.len() - 1;
+                        sel = 0;
                     } else {
-                        sel = ((sel as i64 - 1 + self.items.len() as i64)
-                            % (self.items.len() as i64)) as usize;
+                        sel = (sel as u64 + 1).rem(rows as u64) as usize;
                     }
                 }
-                Key::ArrowLeft | Key::Char('h') => {
-                    if paging.active {
-                        sel = paging.previous_page();
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
                     }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
                 }
-                Key::ArrowRight | Key::Char('l') => {
-                    if paging.active {
-                        sel = paging.next_page();
+                Key::ArrowUp | Key::BackTab | Key::Char('k') if rows > 0 => {
+                    if sel == !0 {
+                        sel = rows - 1;
+                    } else {
+                        sel = ((sel as i64 - 1 + rows as i64) % (rows as i64)) as usize;
                     }
                 }
+                Key::ArrowLeft | Key::Char('h') if paging.active => {
+                    sel = paging.previous_page();
+                }
+                Key::ArrowRight | Key::Char('l') if paging.active => {
+                    sel = paging.next_page();
+                }
+                Key::Home if paging.active => {
+                    sel = paging.first_page();
+                }
+                Key::End if paging.active => {
+                    sel = paging.last_page();
+                }
 
-                Key::Enter | Key::Char(' ') if sel != !0 => {
-                    if self.clear {
-                        render.clear()?;
-                    }
+                Key::Enter | Key::Char(' ') if sel < len => {
+                    let sel = view[sel];
 
-                    if let Some(ref prompt) = self.prompt {
-                        if self.report {
-                            render.select_prompt_selection(prompt, &self.items[sel])?;
+                    if let Some(err) = self.validate(sel) {
+                        error = Some(err);
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+                    } else {
+                        if self.clear {
+                            render.clear()?;
                         }
-                    }
 
-                    term.show_cursor()?;
-                    term.flush()?;
+                        if let Some(ref prompt) = self.prompt {
+                            if self.report {
+                                render.select_prompt_selection(prompt, &self.items[sel])?;
+                            }
+                        }
 
-                    return Ok(Some(sel));
+                        term.show_cursor()?;
+                        term.flush()?;
+
+                        return Ok(Some(sel));
+                    }
+                }
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
                 }
-                _ => {}
             }
 
-            paging.update(sel)?;
+            if filter_changed {
+                (view, pinned) = self.filtered_view(&filter);
+                sel = if view.is_empty() { !0 } else { 0 };
+                size_vec = self.line_sizes(&view);
+            }
+
+            render.begin_frame();
 
-            if paging.active {
+            if filter_changed || items_changed {
+                paging.set_items_len(view.len() + usize::from(self.loader.is_some()));
+                paging.update_page(sel);
                 render.clear()?;
             } else {
-                render.clear_preserve_prompt(&size_vec)?;
+                paging.update(sel)?;
+
+                if paging.active {
+                    render.clear()?;
+                } else {
+                    render.clear_preserve_prompt(&size_vec)?;
+                }
             }
         }
     }
-}
 
-impl<'a> Select<'a> {
-    /// Creates a select prompt with a specific theme.
-    ///
-    /// ## Example
-    ///
-    /// ```rust,no_run
-    /// use dialoguer::{theme::ColorfulTheme, Select};
-    ///
+    /// Like `_interact_on` but announces changes on new lines instead of redrawing.
+    fn _interact_accessible(&self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut sel = self.default;
+        let mut moved = sel != !0;
+
+        if let Some(ref prompt) = self.prompt {
+            render.select_prompt(prompt, None)?;
+        }
+
+        loop {
+            if moved {
+                render.accessible_item(&self.items[sel], sel + 1, self.items.len(), None)?;
+            }
+
+            term.flush()?;
+            moved = true;
+
+            let state = PromptState::Select {
+                highlighted: (sel < self.items.len()).then_some(sel),
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+
+            match key {
+                key if self.watch_keys.contains(&key) => {
+                    let highlighted = (sel < self.items.len()).then_some(sel);
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
+                }
+                Key::ArrowDown | Key::Tab | Key::Char('j') => {
+                    if sel == !0 {
+                        sel = 0;
+                    } else {
+                        sel = (sel as u64 + 1).rem(self.items.len() as u64) as usize;
+                    }
+                }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    return Ok(None);
+                }
+                Key::ArrowUp | Key::BackTab | Key::Char('k') => {
+                    if sel == !0 {
+                        sel = self.items.len() - 1;
+                    } else {
+                        sel = ((sel as i64 - 1 + self.items.len() as i64)
+                            % (self.items.len() as i64)) as usize;
+                    }
+                }
+                Key::Enter | Key::Char(' ') if sel != !0 => {
+                    if let Some(err) = self.validate(sel) {
+                        moved = false;
+                        render.error(&err)?;
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+
+                        continue;
+                    }
+
+                    if let Some(ref prompt) = self.prompt {
+                        if self.report {
+                            render.select_prompt_selection(prompt, &self.items[sel])?;
+                        }
+                    }
+
+                    term.flush()?;
+
+                    return Ok(Some(sel));
+                }
+                _ => {
+                    moved = false;
+
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Like `_interact_on` but renders the items in columns.
+    fn _interact_grid(&self, term: &Term, allow_quit: bool) -> Result<Option<usize>> {
+        let len = self.items.len();
+        let cell_width = self
+            .items
+            .iter()
+            .flat_map(|item| [(item, false), (item, true)])
+            .map(|(item, active)| {
+                let mut cell = String::new();
+                let _ = self
+                    .theme
+                    .format_select_prompt_item(&mut cell, item, active);
+                measure_text_width(&cell)
+            })
+            .max()
+            .unwrap_or(0)
+            + 2;
+        let cols = (term.size().1 as usize / cell_width).max(1);
+        let rows = (len + cols - 1) / cols;
+        let row_of = |sel: usize| if sel == !0 { !0 } else { sel / cols };
+
+        let mut paging = Paging::new(term, rows, self.max_length);
+
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut sel = self.default;
+        let mut error: Option<String> = None;
+
+        paging.reserve_lines(usize::from(self.validator.is_some()) + usize::from(self.fullscreen));
+
+        term.hide_cursor()?;
+        paging.update_page(row_of(sel));
+
+        loop {
+            render.begin_frame();
+
+            if let Some(ref prompt) = self.prompt {
+                paging.render_prompt(|paging_info| render.select_prompt(prompt, paging_info))?;
+            }
+
+            for row in (0..rows).skip(paging.first_item()).take(paging.capacity) {
+                render.formatted_line(|theme, buf| {
+                    for idx in row * cols..((row + 1) * cols).min(len) {
+                        let mut cell = String::new();
+                        theme.format_select_prompt_item(&mut cell, &self.items[idx], sel == idx)?;
+
+                        if idx % cols == cols - 1 || idx == len - 1 {
+                            write!(buf, "{}", cell)?;
+                        } else {
+                            let padding = cell_width - measure_text_width(&cell);
+                            write!(buf, "{}{:padding$}", cell, "", padding = padding)?;
+                        }
+                    }
+
+                    Ok(())
+                })?;
+            }
+
+            if self.fullscreen {
+                render.key_help(&translate::text(
+                    "help-select",
+                    "Arrows move · Enter select",
+                ))?;
+            }
+
+            if let Some(err) = error.take() {
+                render.error(&err)?;
+            }
+
+            render.end_frame()?;
+
+            let state = PromptState::Select {
+                highlighted: (sel < self.items.len()).then_some(sel),
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+            let mut suspended = false;
+
+            match key {
+                key if self.watch_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    let highlighted = (sel < self.items.len()).then_some(sel);
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
+                }
+                key if key == CTRL_Z && cfg!(unix) => {
+                    render.clear()?;
+                    suspend(term)?;
+                    suspended = true;
+                }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
+                Key::ArrowRight
+                | Key::Tab
+                | Key::Char('l')
+                | Key::ArrowLeft
+                | Key::BackTab
+                | Key::Char('h')
+                | Key::ArrowDown
+                | Key::Char('j')
+                | Key::ArrowUp
+                | Key::Char('k')
+                    if sel == !0 =>
+                {
+                    sel = 0;
+                }
+                Key::ArrowRight | Key::Tab | Key::Char('l') => {
+                    sel = (sel + 1) % len;
+                }
+                Key::ArrowLeft | Key::BackTab | Key::Char('h') => {
+                    sel = (sel + len - 1) % len;
+                }
+                Key::ArrowDown | Key::Char('j') => {
+                    sel = if sel + cols < len {
+                        sel + cols
+                    } else {
+                        sel % cols
+                    };
+                }
+                Key::ArrowUp | Key::Char('k') => {
+                    if sel >= cols {
+                        sel -= cols;
+                    } else {
+                        sel += (rows - 1) * cols;
+
+                        if sel >= len {
+                            sel -= cols;
+                        }
+                    }
+                }
+                Key::Home if paging.active => {
+                    sel = paging.first_page() * cols;
+                }
+                Key::End if paging.active => {
+                    sel = paging.last_page() * cols;
+                }
+                Key::Enter | Key::Char(' ') if sel != !0 => {
+                    if let Some(err) = self.validate(sel) {
+                        error = Some(err);
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+                    } else {
+                        if self.clear {
+                            render.clear()?;
+                        }
+
+                        if let Some(ref prompt) = self.prompt {
+                            if self.report {
+                                render.select_prompt_selection(prompt, &self.items[sel])?;
+                            }
+                        }
+
+                        term.show_cursor()?;
+                        term.flush()?;
+
+                        return Ok(Some(sel));
+                    }
+                }
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+
+            render.begin_frame();
+
+            if suspended {
+                // The prompt was cleared, so it is rendered again from scratch
+                paging.set_items_len(rows);
+                paging.update_page(row_of(sel));
+            } else {
+                paging.update(row_of(sel))?;
+
+                if paging.active {
+                    render.clear()?;
+                } else {
+                    render.clear_preserve_prompt(&[])?;
+                }
+            }
+        }
+    }
+
+    /// Renders the prompt with a spinner until the items are fetched and appends them.
+    fn fetch_items_on(&mut self, term: &Term, fetch: &SelectFetchCallback<'_, T>) -> Result {
+        let fetched = render_fetching(term, self.theme, self.prompt.as_deref(), |tick| {
+            fetch.lock().unwrap()(tick)
+        })?;
+
+        for (item, value) in fetched {
+            self.items.push(item.into());
+            self.values.push(value);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of rows, including the row to load more items.
+    fn len(&self) -> usize {
+        self.items.len() + self.loader.is_some() as usize
+    }
+
+    /// Appends the next batch of the loader and returns whether there were more items.
+    ///
+    /// The loader is dropped once it returns no items.
+    fn load_batch(&mut self) -> bool {
+        let batch = match &self.loader {
+            Some(loader) => loader.lock().unwrap()(),
+            None => return false,
+        };
+
+        if batch.is_empty() {
+            self.loader = None;
+            return false;
+        }
+
+        for (item, value) in batch {
+            self.items.push(item.into());
+            self.values.push(value);
+        }
+
+        true
+    }
+
+    /// Runs the validator on the selected item and returns its error.
+    fn validate(&self, sel: usize) -> Option<String> {
+        let err = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator.lock().unwrap()(&sel));
+
+        if let Some(ref err) = err {
+            trace::validation_failed(err);
+        }
+
+        err
+    }
+}
+
+impl<'a> Select<'a> {
+    /// Adds multiple items without copying them.
+    ///
+    /// Unlike [`items`](Self::items), which copies each item into a `String`, this
+    /// keeps borrowed items as they are. This matters for prompts built from large
+    /// in-memory datasets.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let names: Vec<String> = (0..100_000).map(|idx| format!("Item {}", idx)).collect();
+    ///
+    ///     let selection = Select::new()
+    ///         .items_borrowed(names.iter().map(String::as_str))
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn items_borrowed<T, I>(mut self, items: I) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.items.extend(items.into_iter().map(Into::into));
+        self.values.resize(self.items.len(), ());
+
+        self
+    }
+
+    /// Loads the items in batches, for item sources which are slow to fetch completely.
+    ///
+    /// The prompt shows a row to load more items after the items. Choosing the row or
+    /// moving past it calls `loader` for the next batch of items. Once `loader` returns
+    /// no items, the row disappears. If there are no items initially, the first batch
+    /// is loaded when the prompt starts.
+    ///
+    /// In [`grid`](Self::grid) and [`accessible`](Self::accessible) mode, all batches
+    /// are loaded when the prompt starts.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let mut page = 0;
+    ///
+    ///     let selection = Select::new()
+    ///         .with_prompt("Pick a repository")
+    ///         .load_more(move || {
+    ///             page += 1;
+    ///
+    ///             if page <= 3 {
+    ///                 (1..=10).map(|i| format!("repo-{}-{}", page, i)).collect()
+    ///             } else {
+    ///                 vec![]
+    ///             }
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn load_more<F, I, S>(mut self, mut loader: F) -> Self
+    where
+        F: FnMut() -> I + 'a,
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.loader = Some(Arc::new(Mutex::new(move || {
+            loader()
+                .into_iter()
+                .map(|item| (item.to_string(), ()))
+                .collect()
+        })));
+        self
+    }
+
+    /// Sets a function which fetches the items when the prompt is shown.
+    ///
+    /// The function is called on a background thread while the prompt shows a
+    /// spinner in place of the items, so a slow request doesn't leave the terminal
+    /// looking frozen. The fetched items are added after the ones given upfront,
+    /// then the prompt becomes interactive. Keys pressed while fetching are handled
+    /// afterwards. `FuzzySelect` has [`fetch_items`](crate::FuzzySelect::fetch_items)
+    /// as well, and fetches items per search text with an
+    /// [`item_source`](crate::FuzzySelect::item_source).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let selection = Select::new()
+    ///         .with_prompt("Branch")
+    ///         .fetch_items(|| {
+    ///             // e.g. a request to the API of a code host
+    ///             vec!["main", "develop", "release"]
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn fetch_items<F, I, S>(mut self, fetch: F) -> Self
+    where
+        F: FnMut() -> I + Send + 'a,
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let fetch = Mutex::new(fetch);
+
+        self.fetch = Some(Arc::new(Mutex::new(
+            move |tick: &mut dyn FnMut(usize) -> Result| {
+                fetch_on_thread(
+                    || {
+                        (fetch.lock().unwrap())()
+                            .into_iter()
+                            .map(|item| (item.to_string(), ()))
+                            .collect()
+                    },
+                    tick,
+                )
+            },
+        )));
+        self
+    }
+
+    /// Creates a select prompt with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{theme::ColorfulTheme, Select};
+    ///
     /// fn main() {
     ///     let selection = Select::with_theme(&ColorfulTheme::default())
     ///         .items(&["foo", "bar", "baz"])
@@ -325,16 +1336,306 @@ impl<'a> Select<'a> {
     /// }
     /// ```
     pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self::with_values_and_theme(theme)
+    }
+}
+
+impl<'a, T> Select<'a, T> {
+    /// Creates a select prompt whose items carry values, with a specific theme.
+    pub fn with_values_and_theme(theme: &'a dyn Theme) -> Self {
         Self {
             default: !0,
             items: vec![],
+            values: vec![],
             prompt: None,
             report: false,
             clear: true,
+            accessible: accessible_from_env(),
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, Key::Char('q'), CTRL_D]),
+            watch_keys: WatchKeys::default(),
+            output: Target::default(),
+            memory: None,
+            frecency: None,
+            scores: HashMap::new(),
             max_length: None,
+            scroll_mode: ScrollMode::Page,
+            scroll_margin: 0,
+            grid: false,
+            fullscreen: false,
+            filter_mode: false,
+            validator: None,
+            loader: None,
+            fetch: None,
+            key_actions: vec![],
+            pin_key: None,
+            pins: vec![],
+            pins_changed: None,
+            observers: Observers::default(),
             theme,
         }
     }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+
+    /// Registers a validator for the selected item.
+    ///
+    /// The validator receives the index of the item when the user submits the
+    /// selection. If it returns an error, the error is shown below the items
+    /// and the prompt stays open.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let selection = Select::new()
+    ///         .with_prompt("Which region?")
+    ///         .items(&["us-east-1", "eu-west-1", "ap-south-1 (unavailable)"])
+    ///         .validate_with(|sel: &usize| -> Result<(), &str> {
+    ///             if *sel == 2 {
+    ///                 Err("This region is currently unavailable")
+    ///             } else {
+    ///                 Ok(())
+    ///             }
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn validate_with<V>(mut self, mut validator: V) -> Self
+    where
+        V: InputValidator<usize> + 'a,
+        V::Err: ToString,
+    {
+        let mut old_validator_func = self.validator.take();
+
+        self.validator = Some(Arc::new(Mutex::new(
+            move |value: &usize| -> Option<String> {
+                if let Some(old) = old_validator_func.as_mut() {
+                    if let Some(err) = old.lock().unwrap()(value) {
+                        return Some(err);
+                    }
+                }
+
+                match validator.validate(value) {
+                    Ok(()) => None,
+                    Err(err) => Some(err.to_string()),
+                }
+            },
+        )));
+
+        self
+    }
+
+    /// Registers an action which changes the items when `key` is pressed.
+    ///
+    /// The prompt is cleared while the action runs, so it can ask for input itself,
+    /// e.g. for the name of a new item. Afterwards the prompt shows the changed items
+    /// and highlights the item the action left highlighted. Registered keys take
+    /// precedence over the keys of the prompt. If the action removes all items, the
+    /// prompt fails with [`Error::EmptyItems`]. Actions are not available in
+    /// [`grid`](Self::grid) and [`accessible`](Self::accessible) mode.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use console::Key;
+    /// use dialoguer::{Input, Select};
+    ///
+    /// fn main() {
+    ///     let profile = Select::new()
+    ///         .with_prompt("Profile")
+    ///         .items(&["default", "staging"])
+    ///         .on_key(Key::Char('n'), |items| {
+    ///             let name: String = Input::new().with_prompt("New profile").interact_text()?;
+    ///             items.push(name);
+    ///             items.highlight(items.len() - 1);
+    ///             Ok(())
+    ///         })
+    ///         .on_key(Key::Char('d'), |items| {
+    ///             items.remove(items.highlighted());
+    ///             Ok(())
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn on_key<F>(mut self, key: Key, action: F) -> Self
+    where
+        F: FnMut(&mut ItemList<'a, T>) -> Result + 'a,
+    {
+        self.key_actions.push((key, Arc::new(Mutex::new(action))));
+        self
+    }
+
+    /// Lets the user pin the highlighted item with `key`, or unpin a pinned item.
+    ///
+    /// Pinned items are listed at the top in the order they were pinned, and themes
+    /// can render them differently. Interaction still returns the index of the item
+    /// as it was added. Items are pinned by their text, so that the pins can be kept
+    /// across runs with [`pinned`](Self::pinned) and
+    /// [`on_pins_changed`](Self::on_pins_changed). Pins are not shown in
+    /// [`grid`](Self::grid) and [`accessible`](Self::accessible) mode.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use std::fs;
+    ///
+    /// use console::Key;
+    /// use dialoguer::Select;
+    ///
+    /// fn main() {
+    ///     let pins = fs::read_to_string("pins.txt").unwrap_or_default();
+    ///
+    ///     let cluster = Select::new()
+    ///         .with_prompt("Cluster")
+    ///         .items(&["dev", "staging", "prod-eu", "prod-us"])
+    ///         .pin_key(Key::Char('p'))
+    ///         .pinned(pins.lines())
+    ///         .on_pins_changed(|pins| {
+    ///             let _ = fs::write("pins.txt", pins.join("\n"));
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn pin_key(mut self, key: Key) -> Self {
+        self.pin_key = Some(key);
+        self
+    }
+
+    /// Pins the items with the given texts, see [`pin_key`](Self::pin_key).
+    pub fn pinned<S: Into<String>, I: IntoIterator<Item = S>>(mut self, items: I) -> Self {
+        for item in items {
+            let item = item.into();
+
+            if !self.pins.contains(&item) {
+                self.pins.push(item);
+            }
+        }
+
+        self
+    }
+
+    /// Registers a callback which receives the texts of the pinned items whenever the
+    /// user pins or unpins an item, see [`pin_key`](Self::pin_key).
+    pub fn on_pins_changed<F: FnMut(&[String]) + 'a>(mut self, callback: F) -> Self {
+        self.pins_changed = Some(Arc::new(Mutex::new(callback)));
+        self
+    }
+
+    /// Returns the indices of the items in the order they are listed, the pinned
+    /// items first and the others by their frecency, and the number of pinned items.
+    fn pinned_view(&self) -> (Vec<usize>, usize) {
+        let mut view: Vec<usize> = self
+            .pins
+            .iter()
+            .flat_map(|pin| {
+                (0..self.items.len()).filter(move |&idx| self.items[idx] == pin.as_str())
+            })
+            .collect();
+        let pinned = view.len();
+        let mut rest: Vec<usize> = (0..self.items.len())
+            .filter(|idx| !view.contains(idx))
+            .collect();
+
+        if !self.scores.is_empty() {
+            let score = |idx: &usize| self.scores.get(&*self.items[*idx]).copied().unwrap_or(0.0);
+            rest.sort_by(|a, b| score(b).total_cmp(&score(a)));
+        }
+
+        view.extend(rest);
+        (view, pinned)
+    }
+
+    /// Like `pinned_view` but only with the items which match `filter`.
+    fn filtered_view(&self, filter: &str) -> (Vec<usize>, usize) {
+        let (mut view, pinned) = self.pinned_view();
+        let pinned = view[..pinned]
+            .iter()
+            .filter(|&&idx| matches_filter(&self.items[idx], filter))
+            .count();
+
+        view.retain(|&idx| matches_filter(&self.items[idx], filter));
+        (view, pinned)
+    }
+
+    /// Pins or unpins the item at `idx` and notifies the callback.
+    fn toggle_pin(&mut self, idx: usize) {
+        let item = &self.items[idx];
+
+        match self.pins.iter().position(|pin| pin.as_str() == item) {
+            Some(pos) => {
+                self.pins.remove(pos);
+            }
+            None => self.pins.push(item.to_string()),
+        }
+
+        if let Some(ref callback) = self.pins_changed {
+            callback.lock().unwrap()(&self.pins);
+        }
+    }
+
+    /// Returns the length of every line of the items in the order of `view`.
+    fn line_sizes(&self, view: &[usize]) -> Vec<usize> {
+        view.iter()
+            .flat_map(|&idx| self.items[idx].split('\n'))
+            .map(str::len)
+            .collect()
+    }
+
+    /// Runs the action registered for `key` and returns the highlighted item.
+    fn run_key_action(&mut self, key: &Key, sel: usize) -> Result<usize> {
+        let action = match self
+            .key_actions
+            .iter()
+            .find(|(action_key, _)| action_key == key)
+        {
+            Some((_, action)) => action.clone(),
+            None => return Ok(sel),
+        };
+
+        let mut list = ItemList::new(
+            mem::take(&mut self.items),
+            mem::take(&mut self.values),
+            if sel == !0 { 0 } else { sel },
+        );
+        let result = action.lock().unwrap()(&mut list);
+        let (items, values, _, highlighted) = list.into_parts();
+
+        self.items = items;
+        self.values = values;
+        result?;
+
+        Ok(highlighted)
+    }
+}
+
+impl<T: Clone> Prompt for Select<'_, T> {
+    type Output = usize;
+
+    fn interact_on(&mut self, term: &Term) -> Result<usize> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<usize> {
+        self.clone().interact()
+    }
 }
 
 #[cfg(test)]
@@ -383,6 +1684,51 @@ mod tests {
         assert_eq!(Select::new().default(0).items(selections).items, selections);
     }
 
+    #[test]
+    fn test_values() {
+        let select = Select::new().item("a").items(["b", "c"]);
+        assert_eq!(select.values.len(), select.items.len());
+
+        let select = Select::with_values()
+            .item_with_value("a", 1)
+            .item_with_value("b", 2);
+        assert_eq!(select.items, ["a", "b"]);
+        assert_eq!(select.values, [1, 2]);
+    }
+
+    #[test]
+    fn test_pinned_view() {
+        let mut select = Select::new()
+            .items(["a", "b", "c", "d"])
+            .pinned(["d", "b", "x"]);
+        assert_eq!(select.pinned_view(), (vec![3, 1, 0, 2], 2));
+
+        select.toggle_pin(3);
+        select.toggle_pin(0);
+        assert_eq!(select.pins, ["b", "x", "a"]);
+        assert_eq!(select.pinned_view(), (vec![1, 0, 2, 3], 2));
+    }
+
+    #[test]
+    fn test_fetch_items() {
+        let select = Select::new().fetch_items(|| ["a", "b"]);
+        let fetch = select.fetch.unwrap();
+        let fetched = fetch.lock().unwrap()(&mut |_| Ok(())).unwrap();
+
+        assert_eq!(fetched, [("a".to_string(), ()), ("b".to_string(), ())]);
+    }
+
+    #[test]
+    fn test_filtered_view() {
+        let select = Select::new()
+            .items(["Apple", "Banana", "Cherry", "Pineapple"])
+            .pinned(["Banana", "Pineapple"]);
+
+        assert_eq!(select.filtered_view(""), (vec![1, 3, 0, 2], 2));
+        assert_eq!(select.filtered_view("APPLE"), (vec![3, 0], 1));
+        assert_eq!(select.filtered_view("x"), (vec![], 0));
+    }
+
     #[test]
     fn test_iterator() {
         let items = ["First", "Second", "Third"];
@@ -1,12 +1,24 @@
-use std::{io, ops::Rem};
+use std::{
+    borrow::Cow,
+    mem,
+    ops::Rem,
+    sync::{Arc, Mutex},
+};
 
 use console::{Key, Term};
 
 use crate::{
+    observe::{Observers, PromptState},
+    prompts::{AbortKeys, Outcome, Prompt, Target, WatchKeys, CTRL_D, CTRL_Z},
+    signals::{suspend, Cleanup},
     theme::{render::TermThemeRenderer, SimpleTheme, Theme},
-    Paging, Result,
+    trace,
+    validate::InputValidator,
+    Error, Paging, Result, ScrollMode,
 };
 
+type OrderValidatorCallback<'a> = Arc<Mutex<dyn FnMut(&[usize]) -> Option<String> + 'a>>;
+
 /// Renders a sort prompt.
 ///
 /// Returns list of indices in original items list sorted according to user input.
@@ -34,21 +46,31 @@ use crate::{
 /// ```
 #[derive(Clone)]
 pub struct Sort<'a> {
-    items: Vec<String>,
-    prompt: Option<String>,
+    pub(crate) items: Vec<Cow<'a, str>>,
+    pub(crate) prompt: Option<String>,
+    /// The order the items start in, e.g. the previous answer in a session.
+    pub(crate) initial_order: Option<Vec<usize>>,
     report: bool,
     clear: bool,
     max_length: Option<usize>,
+    scroll_mode: ScrollMode,
+    scroll_margin: usize,
+    bell: bool,
+    pub(crate) abort_keys: AbortKeys,
+    watch_keys: WatchKeys,
+    output: Target,
+    observers: Observers<'a>,
     theme: &'a dyn Theme,
+    validator: Option<OrderValidatorCallback<'a>>,
 }
 
-impl Default for Sort<'static> {
+impl Default for Sort<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Sort<'static> {
+impl Sort<'_> {
     /// Creates a sort prompt with default theme.
     pub fn new() -> Self {
         Self::with_theme(&SimpleTheme)
@@ -76,9 +98,30 @@ impl Sort<'_> {
         self
     }
 
+    /// Sets how the items are scrolled if they don't fit on the terminal.
+    ///
+    /// [`ScrollMode::Window`] slides the visible items line by line with the cursor
+    /// and shows the position of the cursor, like "Item 37/120", instead of the page.
+    /// The default is [`ScrollMode::Page`], which flips whole pages.
+    pub fn scroll_mode(mut self, val: ScrollMode) -> Self {
+        self.scroll_mode = val;
+        self
+    }
+
+    /// Sets the number of items kept visible above and below the cursor.
+    ///
+    /// This implies [`ScrollMode::Window`]: the list scrolls line by line as soon as
+    /// the cursor comes within `val` items of the top or bottom edge, like editors do.
+    /// The default is 0, i.e. to scroll when the cursor leaves the visible items.
+    pub fn scroll_margin(mut self, val: usize) -> Self {
+        self.scroll_mode = ScrollMode::Window;
+        self.scroll_margin = val;
+        self
+    }
+
     /// Add a single item to the selector.
     pub fn item<T: ToString>(mut self, item: T) -> Self {
-        self.items.push(item.to_string());
+        self.items.push(item.to_string().into());
         self
     }
 
@@ -89,7 +132,7 @@ impl Sort<'_> {
         I: IntoIterator<Item = T>,
     {
         self.items
-            .extend(items.into_iter().map(|item| item.to_string()));
+            .extend(items.into_iter().map(|item| item.to_string().into()));
 
         self
     }
@@ -111,21 +154,63 @@ impl Sort<'_> {
         self
     }
 
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning, e.g. `Key::Char('q')`
+    /// can be typed. If [`Key::CtrlC`] is in the list, Ctrl-C cancels the prompt
+    /// instead of interrupting the process. The default is 'Esc', 'q' and Ctrl-D.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets keys which end the prompt, so that the caller can react to them, e.g.
+    /// show help on '?' or details of the highlighted item on 'd'.
+    ///
+    /// Watched keys take precedence over the usual meaning of the keys. They are only
+    /// watched by [`interact_outcome`](Self::interact_outcome), the other `interact`
+    /// methods ignore them.
+    pub fn watch_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.watch_keys = WatchKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
     /// Enables user interaction and returns the result.
     ///
     /// The user can order the items with the 'Space' bar and the arrows. On 'Enter' ordered list of the incides of items will be returned.
-    /// The dialog is rendered on stderr.
+    /// A picked item moves to the top with 't' and to the bottom with 'b'. Typing a
+    /// number previews the picked item at that position, 'Enter' moves it there.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `Vec<index>` if user hit 'Enter'.
     /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc' or 'q'.
     #[inline]
     pub fn interact(self) -> Result<Vec<usize>> {
-        self.interact_on(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on(&term)
     }
 
     /// Enables user interaction and returns the result.
     ///
     /// The user can order the items with the 'Space' bar and the arrows. On 'Enter' ordered list of the incides of items will be returned.
-    /// The dialog is rendered on stderr.
+    /// A picked item moves to the top with 't' and to the bottom with 'b'. Typing a
+    /// number previews the picked item at that position, 'Enter' moves it there.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
     /// Result contains `Some(Vec<index>)` if user hit 'Enter' or `None` if user cancelled with 'Esc' or 'q'.
     ///
     /// ## Example
@@ -155,15 +240,14 @@ impl Sort<'_> {
     /// ```
     #[inline]
     pub fn interact_opt(self) -> Result<Option<Vec<usize>>> {
-        self.interact_on_opt(&Term::stderr())
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
     }
 
     /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
     #[inline]
     pub fn interact_on(self, term: &Term) -> Result<Vec<usize>> {
-        Ok(self
-            ._interact_on(term, false)?
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "Quit not allowed in this case"))?)
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
     }
 
     /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
@@ -172,19 +256,47 @@ impl Sort<'_> {
         self._interact_on(term, true)
     }
 
+    /// Enables user interaction and returns the outcome.
+    ///
+    /// Like [`interact_opt`](Self::interact_opt), but the prompt also ends when the user
+    /// presses one of the [`watch_keys`](Self::watch_keys), see
+    /// [`Select::interact_outcome`](crate::Select::interact_outcome).
+    pub fn interact_outcome(self) -> Result<Outcome<Vec<usize>>> {
+        let term = self.output.term()?;
+        self.interact_outcome_on(&term)
+    }
+
+    /// Like [`interact_outcome`](Self::interact_outcome) but allows a specific terminal to be set.
+    pub fn interact_outcome_on(mut self, term: &Term) -> Result<Outcome<Vec<usize>>> {
+        let watch_keys = self.watch_keys.activate();
+        let answer = self._interact_on(term, true)?;
+
+        Ok(watch_keys.outcome(answer))
+    }
+
     fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
         if !term.is_term() {
-            return Err(io::Error::new(io::ErrorKind::NotConnected, "not a terminal").into());
+            return Err(Error::NotATerminal);
         }
 
         if self.items.is_empty() {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                "Empty list of items given to `Sort`",
-            ))?;
+            return Err(Error::EmptyItems);
         }
 
+        let _span = trace::shown("sort", self.prompt.as_deref());
+
+        let _cleanup = Cleanup::install(term);
+
+        trace::answered(self._interact(term, allow_quit), |order| {
+            Some(format!("{:?}", order))
+        })
+    }
+
+    fn _interact(self, term: &Term, allow_quit: bool) -> Result<Option<Vec<usize>>> {
         let mut paging = Paging::new(term, self.items.len(), self.max_length);
+
+        paging.set_scroll_mode(self.scroll_mode);
+        paging.set_scroll_margin(self.scroll_margin);
         let mut render = TermThemeRenderer::new(term, self.theme);
         let mut sel = 0;
 
@@ -195,28 +307,110 @@ impl Sort<'_> {
             size_vec.push(*size);
         }
 
-        let mut order: Vec<_> = (0..self.items.len()).collect();
+        let mut order: Vec<_> = match self.initial_order {
+            Some(ref order) => order.clone(),
+            None => (0..self.items.len()).collect(),
+        };
         let mut checked: bool = false;
+        // The position typed to move the picked item to, starting at 1
+        let mut position = String::new();
+
+        let mut error: Option<String> = None;
+
+        // The line of the typed position and the line of a validation error
+        paging.reserve_lines(1 + usize::from(self.validator.is_some()));
 
         term.hide_cursor()?;
 
         loop {
+            render.begin_frame();
+
             if let Some(ref prompt) = self.prompt {
                 paging.render_prompt(|paging_info| render.sort_prompt(prompt, paging_info))?;
             }
 
-            for (idx, item) in order
+            // The picked item is shown at the typed position until it is moved there
+            let (shown_order, shown_sel) = match target(&position, self.items.len()) {
+                Some(target) => {
+                    let mut shown_order = order.clone();
+                    move_item(&mut shown_order, sel, target);
+                    (shown_order, target)
+                }
+                None => (order.clone(), sel),
+            };
+
+            for (idx, item) in shown_order
                 .iter()
                 .enumerate()
-                .skip(paging.current_page * paging.capacity)
+                .skip(paging.first_item())
                 .take(paging.capacity)
             {
-                render.sort_prompt_item(&self.items[*item], checked, sel == idx)?;
+                render.sort_prompt_item(&self.items[*item], checked, shown_sel == idx)?;
+            }
+
+            if !position.is_empty() {
+                render.sort_prompt_position(&position, self.items.len())?;
+            }
+
+            if let Some(err) = error.take() {
+                render.error(&err)?;
             }
 
-            term.flush()?;
+            render.end_frame()?;
 
-            match term.read_key()? {
+            let state = PromptState::Sort {
+                highlighted: (sel < order.len()).then_some(sel),
+                order: &order,
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+            let mut suspended = false;
+            // Any other key than editing the position discards the position
+            let typed_position = mem::take(&mut position);
+
+            match key {
+                key if self.watch_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    let highlighted = order.get(sel).copied();
+                    self.watch_keys.record(key, highlighted);
+                    return Ok(None);
+                }
+                Key::Char(chr) if checked && sel != !0 && chr.is_ascii_digit() => {
+                    position = typed_position;
+
+                    // More digits than items can't be a position
+                    if position.len() < self.items.len().to_string().len() {
+                        position.push(chr);
+                    } else if self.bell {
+                        render.bell()?;
+                    }
+                }
+                Key::Backspace if !typed_position.is_empty() => {
+                    position = typed_position;
+                    position.pop();
+                }
+                Key::Escape if !typed_position.is_empty() => {}
+                Key::Enter if !typed_position.is_empty() => {
+                    if let Some(target) = target(&typed_position, self.items.len()) {
+                        move_item(&mut order, sel, target);
+                        sel = target;
+                    }
+                }
+                key if key == CTRL_Z && cfg!(unix) => {
+                    render.clear()?;
+                    suspend(term)?;
+                    suspended = true;
+                }
                 Key::ArrowDown | Key::Tab | Key::Char('j') => {
                     let old_sel = sel;
 
@@ -230,6 +424,18 @@ impl Sort<'_> {
                         order.swap(old_sel, sel);
                     }
                 }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    if self.clear {
+                        render.clear()?;
+                    } else {
+                        term.clear_last_lines(paging.capacity)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
                 Key::ArrowUp | Key::BackTab | Key::Char('k') => {
                     let old_sel = sel;
 
@@ -244,103 +450,229 @@ impl Sort<'_> {
                         order.swap(old_sel, sel);
                     }
                 }
-                Key::ArrowLeft | Key::Char('h') => {
-                    if paging.active {
-                        let old_sel = sel;
-                        let old_page = paging.current_page;
-
-                        sel = paging.previous_page();
-
-                        if checked {
-                            let indexes: Vec<_> = if old_page == 0 {
-                                let indexes1: Vec<_> = (0..=old_sel).rev().collect();
-                                let indexes2: Vec<_> = (sel..self.items.len()).rev().collect();
-                                [indexes1, indexes2].concat()
-                            } else {
-                                (sel..=old_sel).rev().collect()
-                            };
-
-                            for index in 0..(indexes.len() - 1) {
-                                order.swap(indexes[index], indexes[index + 1]);
-                            }
+                Key::ArrowLeft | Key::Char('h') if paging.active => {
+                    let old_sel = sel;
+                    let old_page = paging.current_page;
+
+                    sel = paging.previous_page();
+
+                    if checked {
+                        let indexes: Vec<_> = if old_page == 0 {
+                            let indexes1: Vec<_> = (0..=old_sel).rev().collect();
+                            let indexes2: Vec<_> = (sel..self.items.len()).rev().collect();
+                            [indexes1, indexes2].concat()
+                        } else {
+                            (sel..=old_sel).rev().collect()
+                        };
+
+                        for index in 0..(indexes.len() - 1) {
+                            order.swap(indexes[index], indexes[index + 1]);
                         }
                     }
                 }
-                Key::ArrowRight | Key::Char('l') => {
-                    if paging.active {
-                        let old_sel = sel;
-                        let old_page = paging.current_page;
-
-                        sel = paging.next_page();
-
-                        if checked {
-                            let indexes: Vec<_> = if old_page == paging.pages - 1 {
-                                let indexes1: Vec<_> = (old_sel..self.items.len()).collect();
-                                let indexes2: Vec<_> = vec![0];
-                                [indexes1, indexes2].concat()
-                            } else {
-                                (old_sel..=sel).collect()
-                            };
-
-                            for index in 0..(indexes.len() - 1) {
-                                order.swap(indexes[index], indexes[index + 1]);
-                            }
+                Key::ArrowRight | Key::Char('l') if paging.active => {
+                    let old_sel = sel;
+                    let old_page = paging.current_page;
+
+                    sel = paging.next_page();
+
+                    if checked {
+                        let indexes: Vec<_> = if old_page == paging.pages - 1 {
+                            let indexes1: Vec<_> = (old_sel..self.items.len()).collect();
+                            let indexes2: Vec<_> = vec![0];
+                            [indexes1, indexes2].concat()
+                        } else {
+                            (old_sel..=sel).collect()
+                        };
+
+                        for index in 0..(indexes.len() - 1) {
+                            order.swap(indexes[index], indexes[index + 1]);
                         }
                     }
                 }
+                Key::Home if paging.active => {
+                    let old_sel = sel;
+
+                    sel = paging.first_page();
+
+                    if checked && old_sel != !0 {
+                        move_item(&mut order, old_sel, sel);
+                    }
+                }
+                Key::End if paging.active => {
+                    let old_sel = sel;
+
+                    sel = paging.last_page();
+
+                    if checked && old_sel != !0 {
+                        move_item(&mut order, old_sel, sel);
+                    }
+                }
+                Key::Char('t') if checked && sel != !0 => {
+                    move_item(&mut order, sel, 0);
+                    sel = 0;
+                }
+                Key::Char('b') if checked && sel != !0 => {
+                    move_item(&mut order, sel, self.items.len() - 1);
+                    sel = self.items.len() - 1;
+                }
                 Key::Char(' ') => {
                     checked = !checked;
                 }
-                Key::Escape | Key::Char('q') => {
-                    if allow_quit {
+                Key::Enter => {
+                    if let Some(err) = self.validate(&order) {
+                        error = Some(err);
+
+                        if self.bell {
+                            render.bell()?;
+                        }
+                    } else {
                         if self.clear {
                             render.clear()?;
-                        } else {
-                            term.clear_last_lines(paging.capacity)?;
+                        }
+
+                        if let Some(ref prompt) = self.prompt {
+                            if self.report {
+                                let list: Vec<_> =
+                                    order.iter().map(|item| &*self.items[*item]).collect();
+                                render.sort_prompt_selection(prompt, &list[..])?;
+                            }
                         }
 
                         term.show_cursor()?;
                         term.flush()?;
 
-                        return Ok(None);
+                        return Ok(Some(order));
                     }
                 }
-                Key::Enter => {
-                    if self.clear {
-                        render.clear()?;
+                _ => {
+                    if self.bell {
+                        render.bell()?;
                     }
+                }
+            }
 
-                    if let Some(ref prompt) = self.prompt {
-                        if self.report {
-                            let list: Vec<_> = order
-                                .iter()
-                                .enumerate()
-                                .map(|(_, item)| self.items[*item].as_str())
-                                .collect();
-                            render.sort_prompt_selection(prompt, &list[..])?;
-                        }
-                    }
+            render.begin_frame();
 
-                    term.show_cursor()?;
-                    term.flush()?;
+            let shown_sel = target(&position, self.items.len()).unwrap_or(sel);
+
+            if suspended {
+                // The prompt was cleared, so it is rendered again from scratch
+                paging.set_items_len(self.items.len());
+                paging.update_page(shown_sel);
+            } else {
+                paging.update(shown_sel)?;
 
-                    return Ok(Some(order));
+                if paging.active {
+                    render.clear()?;
+                } else {
+                    render.clear_preserve_prompt(&size_vec)?;
                 }
-                _ => {}
             }
+        }
+    }
 
-            paging.update(sel)?;
+    /// Validates the order and returns the error, if any.
+    fn validate(&self, order: &[usize]) -> Option<String> {
+        let err = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator.lock().unwrap()(order));
 
-            if paging.active {
-                render.clear()?;
-            } else {
-                render.clear_preserve_prompt(&size_vec)?;
-            }
+        if let Some(ref err) = err {
+            trace::validation_failed(err);
         }
+
+        err
     }
 }
 
 impl<'a> Sort<'a> {
+    /// Adds multiple items without copying them.
+    ///
+    /// Unlike [`items`](Self::items), which copies each item into a `String`, this
+    /// keeps borrowed items as they are. This matters for prompts built from large
+    /// in-memory datasets.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Sort;
+    ///
+    /// fn main() {
+    ///     let names: Vec<String> = (0..100_000).map(|idx| format!("Item {}", idx)).collect();
+    ///
+    ///     let selection = Sort::new()
+    ///         .items_borrowed(names.iter().map(String::as_str))
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn items_borrowed<T, I>(mut self, items: I) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.items.extend(items.into_iter().map(Into::into));
+
+        self
+    }
+
+    /// Registers a validator for the order of the items.
+    ///
+    /// The validator receives the indices of the items in the chosen order when the
+    /// user submits it. If it returns an error, the error is shown below the items and
+    /// the prompt stays open.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Sort;
+    ///
+    /// fn main() {
+    ///     let steps = ["migrate database", "seed database", "start server"];
+    ///
+    ///     let order = Sort::new()
+    ///         .with_prompt("Order the steps")
+    ///         .items(&steps)
+    ///         .validate_with(|order: &[usize]| -> Result<(), &str> {
+    ///             let position = |step| order.iter().position(|&idx| idx == step);
+    ///
+    ///             if position(0) < position(1) {
+    ///                 Ok(())
+    ///             } else {
+    ///                 Err("The database migration must run before seeding")
+    ///             }
+    ///         })
+    ///         .interact()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn validate_with<V>(mut self, mut validator: V) -> Self
+    where
+        V: InputValidator<[usize]> + 'a,
+        V::Err: ToString,
+    {
+        let mut old_validator_func = self.validator.take();
+
+        self.validator = Some(Arc::new(Mutex::new(
+            move |value: &[usize]| -> Option<String> {
+                if let Some(old) = old_validator_func.as_mut() {
+                    if let Some(err) = old.lock().unwrap()(value) {
+                        return Some(err);
+                    }
+                }
+
+                match validator.validate(value) {
+                    Ok(()) => None,
+                    Err(err) => Some(err.to_string()),
+                }
+            },
+        )));
+
+        self
+    }
+
     /// Creates a sort prompt with a specific theme.
     ///
     /// ## Example
@@ -360,11 +692,63 @@ impl<'a> Sort<'a> {
             items: vec![],
             clear: true,
             prompt: None,
+            initial_order: None,
             report: true,
             max_length: None,
+            scroll_mode: ScrollMode::Page,
+            scroll_margin: 0,
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape, Key::Char('q'), CTRL_D]),
+            watch_keys: WatchKeys::default(),
+            output: Target::default(),
+            observers: Observers::default(),
             theme,
+            validator: None,
         }
     }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+/// Returns the index of the typed position, which starts at 1, among `len` items.
+fn target(position: &str, len: usize) -> Option<usize> {
+    position
+        .parse::<usize>()
+        .ok()
+        .map(|position| position.clamp(1, len) - 1)
+}
+
+/// Moves the item at `from` to `to`, shifting the items in between.
+fn move_item(order: &mut [usize], from: usize, to: usize) {
+    if from < to {
+        order[from..=to].rotate_left(1);
+    } else {
+        order[to..=from].rotate_right(1);
+    }
+}
+
+impl Prompt for Sort<'_> {
+    type Output = Vec<usize>;
+
+    fn interact_on(&mut self, term: &Term) -> Result<Vec<usize>> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<Vec<usize>> {
+        self.clone().interact()
+    }
 }
 
 #[cfg(test)]
@@ -385,4 +769,39 @@ mod tests {
 
         assert_eq!(Sort::new().items(iterator).items, &items[1..]);
     }
+
+    #[test]
+    fn test_validate_with() {
+        let sort = Sort::new()
+            .items(["migrate", "seed"])
+            .validate_with(|order: &[usize]| {
+                if order[0] == 0 {
+                    Ok(())
+                } else {
+                    Err("migrate first")
+                }
+            });
+
+        assert_eq!(sort.validate(&[0, 1]), None);
+        assert_eq!(sort.validate(&[1, 0]), Some("migrate first".to_string()));
+    }
+
+    #[test]
+    fn test_move_item() {
+        let mut order = vec![0, 1, 2, 3, 4];
+
+        move_item(&mut order, 3, 0);
+        assert_eq!(order, [3, 0, 1, 2, 4]);
+
+        move_item(&mut order, 1, 4);
+        assert_eq!(order, [3, 1, 2, 4, 0]);
+    }
+
+    #[test]
+    fn test_target() {
+        assert_eq!(target("", 40), None);
+        assert_eq!(target("2", 40), Some(1));
+        assert_eq!(target("0", 40), Some(0));
+        assert_eq!(target("99", 40), Some(39));
+    }
 }
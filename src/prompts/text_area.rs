@@ -0,0 +1,463 @@
+use console::{Key, Term};
+
+use crate::{
+    observe::{Observers, PromptState},
+    prompts::{read_paste_lines, AbortKeys, BracketedPaste, Prompt, Target, PASTE_START},
+    signals::Cleanup,
+    theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    trace, translate, Error, Result,
+};
+
+/// Ctrl-S, which saves the text of a text area.
+const CTRL_S: Key = Key::Char('\x13');
+
+/// The number of spaces 'Tab' inserts.
+const TAB_WIDTH: usize = 4;
+
+/// Renders a multi-line text editor in the terminal.
+///
+/// The text is edited in a viewport of a few lines, which scrolls with the cursor.
+/// The arrow keys, 'Home', 'End', 'Page Up' and 'Page Down' move the cursor,
+/// Ctrl-S saves the text and 'Esc' cancels. Unlike [`Editor`](crate::Editor) it
+/// doesn't need an external editor, e.g. in containers.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::TextArea;
+///
+/// fn main() {
+///     let message = TextArea::new()
+///         .with_prompt("Commit message")
+///         .interact()
+///         .unwrap();
+///
+///     println!("{}", message);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TextArea<'a> {
+    prompt: String,
+    report: bool,
+    initial_text: Option<String>,
+    height: usize,
+    bell: bool,
+    abort_keys: AbortKeys,
+    output: Target,
+    observers: Observers<'a>,
+    theme: &'a dyn Theme,
+}
+
+impl Default for TextArea<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextArea<'static> {
+    /// Creates a text area with default theme.
+    pub fn new() -> Self {
+        Self::with_theme(&SimpleTheme)
+    }
+}
+
+impl TextArea<'_> {
+    /// Sets the text area prompt.
+    pub fn with_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    /// Sets the text which is edited initially.
+    pub fn with_initial_text<S: Into<String>>(mut self, val: S) -> Self {
+        self.initial_text = Some(val.into());
+        self
+    }
+
+    /// Indicates whether to report the first line of the text after interaction.
+    ///
+    /// The default is to report the text.
+    pub fn report(mut self, val: bool) -> Self {
+        self.report = val;
+        self
+    }
+
+    /// Sets the number of lines which are shown at once.
+    ///
+    /// The default is 10.
+    pub fn height(mut self, val: usize) -> Self {
+        self.height = val.max(1);
+        self
+    }
+
+    /// Indicates whether to ring the terminal bell when a key press is ignored.
+    ///
+    /// The default is to stay silent.
+    pub fn bell(mut self, val: bool) -> Self {
+        self.bell = val;
+        self
+    }
+
+    /// Sets the keys which cancel the prompt if it can be cancelled.
+    ///
+    /// Keys which are not in the list keep their usual meaning. If [`Key::CtrlC`]
+    /// is in the list, Ctrl-C cancels the prompt instead of interrupting the process.
+    /// The default is 'Esc'.
+    pub fn abort_keys<I: IntoIterator<Item = Key>>(mut self, keys: I) -> Self {
+        self.abort_keys = AbortKeys::new(keys);
+        self
+    }
+
+    /// Sets the terminal the prompt is rendered on by the `interact` methods.
+    ///
+    /// The default is [`Target::Stderr`].
+    pub fn output(mut self, target: Target) -> Self {
+        self.output = target;
+        self
+    }
+
+    /// Enables user interaction and returns the text once it's saved with Ctrl-S.
+    ///
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    /// This unlike [`interact_opt`](Self::interact_opt) does not allow to quit with 'Esc'.
+    pub fn interact(self) -> Result<String> {
+        let term = self.output.term()?;
+        self.interact_on(&term)
+    }
+
+    /// Enables user interaction and returns the text once it's saved with Ctrl-S.
+    ///
+    /// Result contains `Some(text)` if the user saved the text or `None` if the
+    /// user cancelled with 'Esc'.
+    /// The dialog is rendered on the [`output`](Self::output), stderr by default.
+    pub fn interact_opt(self) -> Result<Option<String>> {
+        let term = self.output.term()?;
+        self.interact_on_opt(&term)
+    }
+
+    /// Like [`interact`](Self::interact) but allows a specific terminal to be set.
+    pub fn interact_on(self, term: &Term) -> Result<String> {
+        self._interact_on(term, false)?.ok_or(Error::Cancelled)
+    }
+
+    /// Like [`interact_opt`](Self::interact_opt) but allows a specific terminal to be set.
+    pub fn interact_on_opt(self, term: &Term) -> Result<Option<String>> {
+        self._interact_on(term, true)
+    }
+
+    /// Like `interact_on` but allows to cancel the prompt with 'Esc' if `allow_quit` is set.
+    fn _interact_on(self, term: &Term, allow_quit: bool) -> Result<Option<String>> {
+        if !term.is_term() {
+            return Err(Error::NotATerminal);
+        }
+
+        let _span = trace::shown("text_area", Some(&self.prompt));
+
+        let _cleanup = Cleanup::install(term);
+        let _paste = BracketedPaste::enable(term)?;
+
+        trace::answered(self._interact(term, allow_quit), |text| Some(text.clone()))
+    }
+
+    fn _interact(self, term: &Term, allow_quit: bool) -> Result<Option<String>> {
+        let mut render = TermThemeRenderer::new(term, self.theme);
+        let mut buffer = TextBuffer::new(self.initial_text.as_deref().unwrap_or_default());
+        // The first line in the viewport
+        let mut top = 0;
+
+        term.hide_cursor()?;
+
+        loop {
+            if buffer.row < top {
+                top = buffer.row;
+            } else if buffer.row >= top + self.height {
+                top = buffer.row + 1 - self.height;
+            }
+
+            // The visible characters of a line, the rest scrolls horizontally
+            let width = (term.size().1 as usize).saturating_sub(4).max(1);
+
+            render.begin_frame();
+            render.clear()?;
+
+            if !self.prompt.is_empty() {
+                render.prompt(&self.prompt)?;
+            }
+
+            for (idx, line) in buffer.lines.iter().enumerate().skip(top).take(self.height) {
+                let cursor = (idx == buffer.row).then_some(buffer.col);
+                let start = match cursor {
+                    Some(col) if col >= width => col + 1 - width,
+                    _ => 0,
+                };
+                let shown: String = line.iter().skip(start).take(width).collect();
+
+                render.text_area_line(&shown, cursor.map(|col| col - start))?;
+            }
+
+            render.key_help(&if allow_quit {
+                translate::text("help-text-area-cancel", "Ctrl-S save · Esc cancel")
+            } else {
+                translate::text("help-text-area", "Ctrl-S save")
+            })?;
+
+            render.end_frame()?;
+
+            let text = buffer.text();
+            let state = PromptState::Input {
+                text: &text,
+                cursor: buffer.cursor(),
+            };
+            self.observers.rendered(state);
+
+            let key = self.abort_keys.read_key(term, allow_quit)?;
+            self.observers.key_pressed(&key, state);
+
+            match key {
+                CTRL_S => {
+                    render.clear()?;
+
+                    if self.report {
+                        render.text_area_prompt_selection(&self.prompt, &text)?;
+                    }
+
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(Some(text));
+                }
+                key if allow_quit && self.abort_keys.contains(&key) => {
+                    render.clear()?;
+                    term.show_cursor()?;
+                    term.flush()?;
+
+                    return Ok(None);
+                }
+                Key::UnknownEscSeq(seq) if seq == PASTE_START => {
+                    if let Some(text) = read_paste_lines(term)? {
+                        buffer.insert_text(&text);
+                    }
+                }
+                Key::Enter => buffer.newline(),
+                Key::Tab => buffer.insert_text(&" ".repeat(TAB_WIDTH)),
+                Key::Backspace => buffer.backspace(),
+                Key::Del => buffer.delete(),
+                Key::ArrowLeft => buffer.move_left(),
+                Key::ArrowRight => buffer.move_right(),
+                Key::ArrowUp => buffer.move_rows(-1),
+                Key::ArrowDown => buffer.move_rows(1),
+                Key::PageUp => buffer.move_rows(-(self.height as isize)),
+                Key::PageDown => buffer.move_rows(self.height as isize),
+                Key::Home => buffer.col = 0,
+                Key::End => buffer.col = buffer.lines[buffer.row].len(),
+                Key::Char(chr) if !chr.is_control() => buffer.insert(chr),
+                _ => {
+                    if self.bell {
+                        render.bell()?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> TextArea<'a> {
+    /// Creates a text area with a specific theme.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::{theme::ColorfulTheme, TextArea};
+    ///
+    /// fn main() {
+    ///     let notes = TextArea::with_theme(&ColorfulTheme::default())
+    ///         .with_prompt("Release notes")
+    ///         .height(5)
+    ///         .interact_opt()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self {
+            prompt: "".into(),
+            report: true,
+            initial_text: None,
+            height: 10,
+            bell: false,
+            abort_keys: AbortKeys::new([Key::Escape]),
+            output: Target::default(),
+            observers: Observers::default(),
+            theme,
+        }
+    }
+
+    /// Registers an observer which is called with the state of the prompt whenever
+    /// it was rendered.
+    pub fn on_render<F: FnMut(PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_render(observer);
+        self
+    }
+
+    /// Registers an observer which is called with every key press and the state of
+    /// the prompt before the key is handled.
+    pub fn on_key_event<F: FnMut(&Key, PromptState) + 'a>(mut self, observer: F) -> Self {
+        self.observers.set_key(observer);
+        self
+    }
+}
+
+impl Prompt for TextArea<'_> {
+    type Output = String;
+
+    fn interact_on(&mut self, term: &Term) -> Result<String> {
+        self.clone().interact_on(term)
+    }
+
+    fn interact(&mut self) -> Result<String> {
+        self.clone().interact()
+    }
+}
+
+/// The lines of a text area and the position of the cursor.
+struct TextBuffer {
+    lines: Vec<Vec<char>>,
+    row: usize,
+    col: usize,
+}
+
+impl TextBuffer {
+    /// Creates a buffer with the cursor at the end of the text.
+    fn new(text: &str) -> Self {
+        let mut buffer = TextBuffer {
+            lines: vec![Vec::new()],
+            row: 0,
+            col: 0,
+        };
+        buffer.insert_text(text);
+        buffer
+    }
+
+    fn text(&self) -> String {
+        let lines: Vec<String> = self
+            .lines
+            .iter()
+            .map(|line| line.iter().collect())
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Returns the position of the cursor in the text in characters.
+    fn cursor(&self) -> usize {
+        self.lines[..self.row]
+            .iter()
+            .map(|line| line.len() + 1)
+            .sum::<usize>()
+            + self.col
+    }
+
+    fn insert(&mut self, chr: char) {
+        self.lines[self.row].insert(self.col, chr);
+        self.col += 1;
+    }
+
+    /// Inserts text, which may contain line breaks, at the cursor.
+    fn insert_text(&mut self, text: &str) {
+        for chr in text.chars() {
+            match chr {
+                '\n' => self.newline(),
+                '\t' => (0..TAB_WIDTH).for_each(|_| self.insert(' ')),
+                chr if chr.is_control() => {}
+                chr => self.insert(chr),
+            }
+        }
+    }
+
+    /// Breaks the line at the cursor.
+    fn newline(&mut self) {
+        let rest = self.lines[self.row].split_off(self.col);
+        self.row += 1;
+        self.col = 0;
+        self.lines.insert(self.row, rest);
+    }
+
+    /// Deletes the character before the cursor, which joins the line with the
+    /// previous one at its start.
+    fn backspace(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+            self.lines[self.row].remove(self.col);
+        } else if self.row > 0 {
+            let line = self.lines.remove(self.row);
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+            self.lines[self.row].extend(line);
+        }
+    }
+
+    /// Deletes the character after the cursor, which joins the line with the
+    /// next one at its end.
+    fn delete(&mut self) {
+        if self.col < self.lines[self.row].len() {
+            self.lines[self.row].remove(self.col);
+        } else if self.row + 1 < self.lines.len() {
+            let line = self.lines.remove(self.row + 1);
+            self.lines[self.row].extend(line);
+        }
+    }
+
+    fn move_left(&mut self) {
+        if self.col > 0 {
+            self.col -= 1;
+        } else if self.row > 0 {
+            self.row -= 1;
+            self.col = self.lines[self.row].len();
+        }
+    }
+
+    fn move_right(&mut self) {
+        if self.col < self.lines[self.row].len() {
+            self.col += 1;
+        } else if self.row + 1 < self.lines.len() {
+            self.row += 1;
+            self.col = 0;
+        }
+    }
+
+    /// Moves the cursor up or down by `rows`, keeping the column if the line is long enough.
+    fn move_rows(&mut self, rows: isize) {
+        let row = self.row as isize + rows;
+        self.row = row.clamp(0, self.lines.len() as isize - 1) as usize;
+        self.col = self.col.min(self.lines[self.row].len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editing() {
+        let mut buffer = TextBuffer::new("first\nsecond");
+        assert_eq!((buffer.row, buffer.col), (1, 6));
+
+        buffer.move_rows(-1);
+        assert_eq!((buffer.row, buffer.col), (0, 5));
+
+        buffer.delete();
+        assert_eq!(buffer.text(), "firstsecond");
+
+        buffer.newline();
+        buffer.insert_text("\tthird\n");
+        assert_eq!(buffer.text(), "first\n    third\nsecond");
+        assert_eq!(buffer.cursor(), 16);
+
+        buffer.backspace();
+        buffer.move_left();
+        buffer.backspace();
+        assert_eq!(buffer.text(), "first\n    thidsecond");
+
+        buffer.move_rows(5);
+        buffer.move_right();
+        assert_eq!((buffer.row, buffer.col), (1, 8));
+    }
+}
@@ -0,0 +1,768 @@
+use std::{collections::HashMap, io, str::FromStr};
+
+use console::{measure_text_width, Key, Term};
+
+#[cfg(feature = "password")]
+use crate::Password;
+use crate::{
+    theme::{render::TermThemeRenderer, SimpleTheme, Theme},
+    translate, Confirm, Error, Input, MultiSelect, Result, Select, Sort,
+};
+
+/// Runs consecutive prompts as one dialog.
+///
+/// The session owns the terminal and the theme of its prompts. It collects the
+/// answers and reports them in one place instead of leaving a trail of
+/// reports. When the session is finished, or dropped e.g. because of a panic,
+/// the reports are cleared and the cursor is restored.
+///
+/// With [`summary`](Self::summary) the answers are instead shown as a panel of
+/// aligned `key: value` lines which stays above the following prompts.
+///
+/// With [`back`](Self::back) the user can return to the previous prompt with
+/// 'Esc' or 'Shift-Tab', see [`wizard`](Self::wizard).
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{theme::ColorfulTheme, Session};
+///
+/// fn main() -> dialoguer::Result<()> {
+///     let theme = ColorfulTheme::default();
+///     let mut session = Session::with_theme(&theme);
+///
+///     let name: String = session.input(|input| input.with_prompt("Project name"))?;
+///     let license = session.select(|select| {
+///         select
+///             .with_prompt("License")
+///             .items(&["MIT", "Apache-2.0"])
+///             .default(0)
+///     })?;
+///
+///     for (prompt, answer) in session.finish()? {
+///         println!("{}: {}", prompt, answer);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct Session<'a> {
+    term: Term,
+    theme: &'a dyn Theme,
+    answers: Vec<(String, String)>,
+    steps: Vec<Step>,
+    prefills: HashMap<String, Prefill>,
+    clear: bool,
+    summary: bool,
+    back: bool,
+    lines: usize,
+    json: Option<Box<dyn io::Write + 'a>>,
+    title: bool,
+    title_saved: bool,
+}
+
+/// A prompt which was answered in a session.
+struct Step {
+    /// Lines of the report of the prompt.
+    lines: usize,
+    /// Whether the answer was added to the answers.
+    answered: bool,
+}
+
+/// The previous answer of a prompt, used when the prompt is asked again.
+enum Prefill {
+    Index(usize),
+    Indices(Vec<usize>),
+    Order(Vec<usize>),
+    Bool(bool),
+    Text(String),
+}
+
+impl Default for Session<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Session<'static> {
+    /// Creates a session with the default theme.
+    pub fn new() -> Self {
+        Self::with_theme(&SimpleTheme)
+    }
+}
+
+impl<'a> Session<'a> {
+    /// Creates a session with a specific theme.
+    pub fn with_theme(theme: &'a dyn Theme) -> Self {
+        Self {
+            term: Term::stderr(),
+            theme,
+            answers: Vec::new(),
+            steps: Vec::new(),
+            prefills: HashMap::new(),
+            clear: true,
+            summary: false,
+            back: false,
+            lines: 0,
+            json: None,
+            title: false,
+            title_saved: false,
+        }
+    }
+
+    /// Sets the terminal the prompts are rendered on.
+    ///
+    /// The default is stderr.
+    pub fn term(mut self, term: Term) -> Self {
+        self.term = term;
+        self
+    }
+
+    /// Indicates whether the reports should be erased from the screen at the end.
+    ///
+    /// The default is to clear the reports.
+    pub fn clear(mut self, val: bool) -> Self {
+        self.clear = val;
+        self
+    }
+
+    /// Indicates whether to show the answers so far as a summary panel above the prompts.
+    ///
+    /// The panel replaces the reports of the prompts and is redrawn whenever a
+    /// prompt is answered. The default is to report every answer on its own.
+    pub fn summary(mut self, val: bool) -> Self {
+        self.summary = val;
+        self
+    }
+
+    /// Indicates whether the user can go back to the previous prompt with 'Esc'
+    /// or 'Shift-Tab'.
+    ///
+    /// Going back removes the answer of the previous prompt and the current
+    /// prompt returns [`Error::Back`]. Prompts which are asked again are
+    /// pre-filled with their previous answer. The default is to not allow going
+    /// back.
+    pub fn back(mut self, val: bool) -> Self {
+        self.back = val;
+        self
+    }
+
+    /// Indicates whether to show the current prompt in the title of the terminal.
+    ///
+    /// This helps to find a waiting wizard among background tabs. The previous
+    /// title is restored when the session ends, if the terminal supports it. The
+    /// default is to leave the title alone.
+    pub fn title(mut self, val: bool) -> Self {
+        self.title = val;
+        self
+    }
+
+    /// Writes the answers as JSON to `writer` when the session is finished.
+    ///
+    /// The answers are written as an array of objects with a `prompt` and an
+    /// `answer`, e.g. `[{"prompt":"License","answer":"MIT"}]`, followed by a newline.
+    /// Answers which were taken back are not included.
+    pub fn json<W: io::Write + 'a>(mut self, writer: W) -> Self {
+        self.json = Some(Box::new(writer));
+        self
+    }
+
+    /// Returns the terminal of the session, e.g. to run other prompts on it.
+    pub fn get_term(&self) -> &Term {
+        &self.term
+    }
+
+    /// Returns the theme of the session.
+    pub fn get_theme(&self) -> &'a dyn Theme {
+        self.theme
+    }
+
+    /// Returns the prompts and answers of the session so far.
+    pub fn answers(&self) -> &[(String, String)] {
+        &self.answers
+    }
+
+    /// Returns the answer of the prompt with the given text, if it was answered.
+    pub fn answer(&self, prompt: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .rev()
+            .find(|(answered, _)| answered == prompt)
+            .map(|(_, answer)| answer.as_str())
+    }
+
+    /// Runs a select prompt configured by `f` and returns the selected index.
+    pub fn select<F>(&mut self, f: F) -> Result<usize>
+    where
+        F: FnOnce(Select<'a>) -> Select<'a>,
+    {
+        let mut select = f(Select::with_theme(self.theme)).report(false);
+        let prompt = select.prompt.clone().unwrap_or_default();
+        self.show_title(&prompt)?;
+        let items = select.items.clone();
+
+        if let Some(&Prefill::Index(idx)) = self.prefills.get(&prompt) {
+            if idx < items.len() {
+                select = select.default(idx);
+            }
+        }
+
+        let sel = if self.back {
+            select.abort_keys.add(Key::BackTab);
+            select.interact_on_opt(&self.term)?
+        } else {
+            select.interact_on(&self.term).map(Some)?
+        };
+        let sel = self.or_back(sel)?;
+
+        self.record(
+            prompt,
+            items[sel].to_string(),
+            Some(Prefill::Index(sel)),
+            |render, prompt, answer| render.select_prompt_selection(prompt, answer),
+        )?;
+
+        Ok(sel)
+    }
+
+    /// Runs a multi select prompt configured by `f` and returns the selected indices.
+    pub fn multi_select<F>(&mut self, f: F) -> Result<Vec<usize>>
+    where
+        F: FnOnce(MultiSelect<'a>) -> MultiSelect<'a>,
+    {
+        let mut multi_select = f(MultiSelect::with_theme(self.theme)).report(false);
+        let prompt = multi_select.prompt.clone().unwrap_or_default();
+        self.show_title(&prompt)?;
+        let items = multi_select.items.clone();
+
+        if let Some(Prefill::Indices(selections)) = self.prefills.get(&prompt) {
+            let defaults: Vec<_> = (0..items.len())
+                .map(|idx| selections.contains(&idx))
+                .collect();
+            multi_select = multi_select.defaults(&defaults);
+        }
+
+        let selections = if self.back {
+            multi_select.abort_keys.add(Key::BackTab);
+            multi_select.interact_on_opt(&self.term)?
+        } else {
+            multi_select.interact_on(&self.term).map(Some)?
+        };
+        let selections = self.or_back(selections)?;
+        let selected: Vec<_> = selections.iter().map(|&idx| &*items[idx]).collect();
+
+        self.record(
+            prompt,
+            selected.join(", "),
+            Some(Prefill::Indices(selections.clone())),
+            |render, prompt, _| render.multi_select_prompt_selection(prompt, &selected),
+        )?;
+
+        Ok(selections)
+    }
+
+    /// Runs a sort prompt configured by `f` and returns the new order of the items.
+    pub fn sort<F>(&mut self, f: F) -> Result<Vec<usize>>
+    where
+        F: FnOnce(Sort<'a>) -> Sort<'a>,
+    {
+        let mut sort = f(Sort::with_theme(self.theme)).report(false);
+        let prompt = sort.prompt.clone().unwrap_or_default();
+        self.show_title(&prompt)?;
+        let items = sort.items.clone();
+
+        if let Some(Prefill::Order(order)) = self.prefills.get(&prompt) {
+            if is_permutation(order, items.len()) {
+                sort.initial_order = Some(order.clone());
+            }
+        }
+
+        let order = if self.back {
+            sort.abort_keys.add(Key::BackTab);
+            sort.interact_on_opt(&self.term)?
+        } else {
+            sort.interact_on(&self.term).map(Some)?
+        };
+        let order = self.or_back(order)?;
+        let sorted: Vec<_> = order.iter().map(|&idx| &*items[idx]).collect();
+
+        self.record(
+            prompt,
+            sorted.join(", "),
+            Some(Prefill::Order(order.clone())),
+            |render, prompt, _| render.sort_prompt_selection(prompt, &sorted),
+        )?;
+
+        Ok(order)
+    }
+
+    /// Runs a confirm prompt configured by `f` and returns the answer.
+    pub fn confirm<F>(&mut self, f: F) -> Result<bool>
+    where
+        F: FnOnce(Confirm<'a>) -> Confirm<'a>,
+    {
+        let mut confirm = f(Confirm::with_theme(self.theme)).report(false);
+        let prompt = confirm.prompt.clone();
+        self.show_title(&prompt)?;
+
+        if let Some(&Prefill::Bool(answer)) = self.prefills.get(&prompt) {
+            confirm = confirm.default(answer);
+        }
+
+        let answer = if self.back {
+            confirm.abort_keys.add(Key::BackTab);
+            confirm.interact_on_opt(&self.term)?
+        } else {
+            confirm.interact_on(&self.term).map(Some)?
+        };
+        let answer = self.or_back(answer)?;
+        let text = if answer {
+            translate::text("yes", "yes")
+        } else {
+            translate::text("no", "no")
+        };
+
+        self.record(
+            prompt,
+            text.into_owned(),
+            Some(Prefill::Bool(answer)),
+            |render, prompt, _| render.confirm_prompt_selection(prompt, Some(answer)),
+        )?;
+
+        Ok(answer)
+    }
+
+    /// Runs an input prompt configured by `f` and returns the input.
+    pub fn input<T, F>(&mut self, f: F) -> Result<T>
+    where
+        T: Clone + ToString + FromStr + 'a,
+        <T as FromStr>::Err: ToString,
+        F: FnOnce(Input<'a, T>) -> Input<'a, T>,
+    {
+        let mut input = f(Input::with_theme(self.theme)).report(false);
+        let prompt = input.prompt.clone();
+        self.show_title(&prompt)?;
+
+        if let Some(Prefill::Text(text)) = self.prefills.get(&prompt) {
+            input = input.with_initial_text(text.as_str());
+        }
+
+        let value = if self.back {
+            input.abort_keys.add(Key::BackTab);
+            input.interact_text_on_opt(&self.term)?
+        } else {
+            input.interact_text_on(&self.term).map(Some)?
+        };
+        let value = self.or_back(value)?;
+        let text = value.to_string();
+
+        self.record(
+            prompt,
+            text.clone(),
+            Some(Prefill::Text(text)),
+            |render, prompt, answer| render.input_prompt_selection(prompt, answer),
+        )?;
+
+        Ok(value)
+    }
+
+    /// Runs a password prompt configured by `f` and returns the password.
+    ///
+    /// The password is not kept in the [`answers`](Self::answers).
+    #[cfg(feature = "password")]
+    pub fn password<F>(&mut self, f: F) -> Result<String>
+    where
+        F: FnOnce(Password<'a>) -> Password<'a>,
+    {
+        let mut password = f(Password::with_theme(self.theme)).report(false);
+        let prompt = password.prompt.clone();
+        self.show_title(&prompt)?;
+
+        let value = if self.back {
+            password.abort_keys.add(Key::BackTab);
+            password.interact_on_opt(&self.term)?
+        } else {
+            password.interact_on(&self.term).map(Some)?
+        };
+        let value = self.or_back(value)?;
+
+        let mut lines = 0;
+
+        if !prompt.is_empty() && !self.summary {
+            TermThemeRenderer::new(&self.term, self.theme).password_prompt_selection(&prompt)?;
+            lines = 1;
+        }
+
+        self.lines += lines;
+        self.steps.push(Step {
+            lines,
+            answered: false,
+        });
+
+        Ok(value)
+    }
+
+    /// Runs the steps of a wizard in order.
+    ///
+    /// Every step should ask one prompt of the session. If a step returns
+    /// [`Error::Back`], the previous step is run again. Other errors abort the
+    /// wizard. Steps which ask no prompt, e.g. because of [`when`](Self::when),
+    /// are skipped when going back.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Session;
+    ///
+    /// fn main() -> dialoguer::Result<()> {
+    ///     let mut name = String::new();
+    ///     let mut license = 0;
+    ///
+    ///     Session::new().back(true).wizard(&mut [
+    ///         &mut |session: &mut Session| {
+    ///             name = session.input(|input| input.with_prompt("Project name"))?;
+    ///             Ok(())
+    ///         },
+    ///         &mut |session: &mut Session| {
+    ///             license = session
+    ///                 .select(|select| select.with_prompt("License").items(&["MIT", "Apache-2.0"]))?;
+    ///             Ok(())
+    ///         },
+    ///     ])?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn wizard(&mut self, steps: &mut [&mut dyn FnMut(&mut Self) -> Result]) -> Result {
+        let mut idx = 0;
+        let mut back = false;
+
+        while idx < steps.len() {
+            let asked = self.steps.len();
+
+            match steps[idx](self) {
+                Ok(()) if back && idx > 0 && self.steps.len() == asked => idx -= 1,
+                Ok(()) => {
+                    idx += 1;
+                    back = false;
+                }
+                Err(Error::Back) => {
+                    idx = idx.saturating_sub(1);
+                    back = true;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a wizard step which only runs `step` if `condition` holds.
+    ///
+    /// The condition usually looks at the previous [`answer`](Self::answer)s. When it
+    /// does not hold, the step asks nothing and leaves no answer.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use dialoguer::Session;
+    ///
+    /// fn main() -> dialoguer::Result<()> {
+    ///     let mut tls = 0;
+    ///     let mut certificate = String::new();
+    ///
+    ///     Session::new().back(true).wizard(&mut [
+    ///         &mut |session: &mut Session| {
+    ///             tls = session
+    ///                 .select(|select| select.with_prompt("TLS").items(&["none", "manual"]))?;
+    ///             Ok(())
+    ///         },
+    ///         &mut Session::when(
+    ///             |session| session.answer("TLS") == Some("manual"),
+    ///             |session| {
+    ///                 certificate = session.input(|input| input.with_prompt("Certificate path"))?;
+    ///                 Ok(())
+    ///             },
+    ///         ),
+    ///     ])?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn when<C, F>(mut condition: C, mut step: F) -> impl FnMut(&mut Self) -> Result
+    where
+        C: FnMut(&Self) -> bool,
+        F: FnMut(&mut Self) -> Result,
+    {
+        move |session| {
+            if condition(session) {
+                step(session)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Finishes the session and returns the prompts and their answers.
+    pub fn finish(mut self) -> Result<Vec<(String, String)>> {
+        self.restore()?;
+
+        if let Some(mut writer) = self.json.take() {
+            writeln!(writer, "{}", answers_json(&self.answers))?;
+            writer.flush()?;
+        }
+
+        Ok(std::mem::take(&mut self.answers))
+    }
+
+    /// Shows the prompt in the title of the terminal if enabled.
+    fn show_title(&mut self, prompt: &str) -> io::Result<()> {
+        if !self.title || prompt.is_empty() {
+            return Ok(());
+        }
+
+        if !self.title_saved {
+            // Pushes the current title to be restored at the end
+            self.term.write_str("\x1b[22;0t")?;
+            self.title_saved = true;
+        }
+
+        let title: String = prompt.chars().filter(|chr| !chr.is_control()).collect();
+        self.term.write_str(&format!("\x1b]0;{}\x07", title))
+    }
+
+    /// Returns the answer of a prompt, or goes back if the prompt was cancelled.
+    fn or_back<T>(&mut self, answer: Option<T>) -> Result<T> {
+        match answer {
+            Some(answer) => Ok(answer),
+            None => {
+                if let Some(step) = self.steps.pop() {
+                    if step.answered {
+                        self.answers.pop();
+                    }
+
+                    if self.summary {
+                        self.render_summary()?;
+                    } else {
+                        self.term.clear_last_lines(step.lines)?;
+                        self.lines -= step.lines;
+                    }
+                }
+
+                Err(Error::Back)
+            }
+        }
+    }
+
+    /// Stores an answer and reports it below the previous answers.
+    fn record<F>(
+        &mut self,
+        prompt: String,
+        answer: String,
+        prefill: Option<Prefill>,
+        report: F,
+    ) -> Result
+    where
+        F: FnOnce(&mut TermThemeRenderer, &str, &str) -> Result,
+    {
+        let mut lines = 0;
+
+        if !prompt.is_empty() && !self.summary {
+            report(
+                &mut TermThemeRenderer::new(&self.term, self.theme),
+                &prompt,
+                &answer,
+            )?;
+            lines = 1;
+        }
+
+        self.lines += lines;
+        self.steps.push(Step {
+            lines,
+            answered: true,
+        });
+
+        if let Some(prefill) = prefill.filter(|_| !prompt.is_empty()) {
+            self.prefills.insert(prompt.clone(), prefill);
+        }
+
+        self.answers.push((prompt, answer));
+
+        if self.summary {
+            self.render_summary()?;
+        }
+
+        Ok(())
+    }
+
+    /// Redraws the summary panel with all answers so far.
+    fn render_summary(&mut self) -> Result {
+        self.term.clear_last_lines(self.lines)?;
+
+        let answers: Vec<_> = self
+            .answers
+            .iter()
+            .filter(|(prompt, _)| !prompt.is_empty())
+            .collect();
+        let key_width = answers
+            .iter()
+            .map(|(prompt, _)| measure_text_width(prompt))
+            .max()
+            .unwrap_or(0);
+
+        let mut render = TermThemeRenderer::new(&self.term, self.theme);
+
+        for (prompt, answer) in &answers {
+            render.summary_item(prompt, answer, key_width)?;
+        }
+
+        self.lines = answers.len();
+        self.term.flush()?;
+
+        Ok(())
+    }
+
+    /// Clears the reports and restores the cursor.
+    fn restore(&mut self) -> io::Result<()> {
+        if self.clear {
+            self.term.clear_last_lines(self.lines)?;
+        }
+
+        if self.title_saved {
+            // Pops the title saved by `show_title`
+            self.term.write_str("\x1b[23;0t")?;
+            self.title_saved = false;
+        }
+
+        self.lines = 0;
+        self.term.show_cursor()?;
+        self.term.flush()
+    }
+}
+
+impl Drop for Session<'_> {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
+
+/// Checks whether `order` is an order of `len` items, i.e. has every index once.
+fn is_permutation(order: &[usize], len: usize) -> bool {
+    let mut sorted = order.to_vec();
+    sorted.sort_unstable();
+
+    sorted.len() == len && sorted.iter().enumerate().all(|(idx, &item)| idx == item)
+}
+
+/// Formats the answers as a JSON array.
+fn answers_json(answers: &[(String, String)]) -> String {
+    let objects: Vec<String> = answers
+        .iter()
+        .map(|(prompt, answer)| {
+            format!(
+                "{{\"prompt\":{},\"answer\":{}}}",
+                json_string(prompt),
+                json_string(answer)
+            )
+        })
+        .collect();
+
+    format!("[{}]", objects.join(","))
+}
+
+/// Formats a JSON string literal.
+fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+
+    for chr in text.chars() {
+        match chr {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            chr if chr.is_control() => json.push_str(&format!("\\u{:04x}", chr as u32)),
+            chr => json.push(chr),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_json() {
+        let answers = vec![
+            ("Name".to_owned(), "say \"hi\"\n".to_owned()),
+            ("Path".to_owned(), "C:\\tmp\u{1b}".to_owned()),
+        ];
+
+        assert_eq!(
+            answers_json(&answers),
+            r#"[{"prompt":"Name","answer":"say \"hi\"\n"},{"prompt":"Path","answer":"C:\\tmp\u001b"}]"#
+        );
+        assert_eq!(answers_json(&[]), "[]");
+    }
+
+    #[test]
+    fn test_is_permutation() {
+        assert!(is_permutation(&[2, 0, 1], 3));
+        assert!(is_permutation(&[], 0));
+        assert!(!is_permutation(&[0, 1], 3));
+        assert!(!is_permutation(&[0, 0, 1], 3));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_back_tab() {
+        use std::{
+            fs::File,
+            io::Write,
+            os::unix::io::{AsRawFd, FromRawFd},
+            ptr,
+        };
+
+        // Keys are read from stdin when it is a terminal, so the steps are run
+        // on a pseudo terminal which replaces stdin for the test.
+        let (mut master, slave) = unsafe {
+            let (mut master, mut slave) = (0, 0);
+            let opened = libc::openpty(
+                &mut master,
+                &mut slave,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+            assert_eq!(opened, 0);
+            (File::from_raw_fd(master), File::from_raw_fd(slave))
+        };
+        let stdin = unsafe { libc::dup(0) };
+        unsafe { libc::dup2(slave.as_raw_fd(), 0) };
+
+        let term = Term::read_write_pair(slave.try_clone().unwrap(), slave);
+        let mut session = Session::new().term(term).back(true);
+        let items = ["a", "b", "c"];
+
+        master.write_all(b"\x1b[Z").unwrap();
+        let select = session.select(|select| select.with_prompt("Select").items(items));
+        master.write_all(b"\x1b[Z").unwrap();
+        let multi_select =
+            session.multi_select(|multi_select| multi_select.with_prompt("Pick").items(items));
+        master.write_all(b"\x1b[Z").unwrap();
+        let sort = session.sort(|sort| sort.with_prompt("Sort").items(items));
+
+        unsafe {
+            libc::dup2(stdin, 0);
+            libc::close(stdin);
+        }
+
+        assert!(matches!(select, Err(Error::Back)));
+        assert!(matches!(multi_select, Err(Error::Back)));
+        assert!(matches!(sort, Err(Error::Back)));
+    }
+}
@@ -0,0 +1,224 @@
+//! Restores the terminal if the process receives a signal while a prompt is shown.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use console::Term;
+
+use crate::Result;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Restores the terminal if the process is interrupted or terminated while a prompt
+/// is shown.
+///
+/// If enabled, prompts install a handler for `SIGINT` and `SIGTERM` while they are
+/// shown. On a signal the handler shows the cursor again, clears the partially
+/// rendered prompt and restores the terminal mode. Then it raises the signal again
+/// for the previous handler, which terminates the process by default.
+///
+/// `SIGTSTP` and `SIGCONT` are not handled. If another process stops the process
+/// while a prompt waits for a key, the terminal stays in raw mode with the cursor
+/// hidden until it is continued, and the prompt is not rendered again afterwards.
+/// Pressing Ctrl-Z suspends [`Select`](crate::Select), [`MultiSelect`](crate::MultiSelect),
+/// [`Sort`](crate::Sort), [`Confirm`](crate::Confirm) and `FuzzySelect` cleanly
+/// instead. [`Input`](crate::Input) binds Ctrl-Z to undo and `Password` ignores it,
+/// so these can't be suspended.
+///
+/// Disabled by default, as it replaces the signal handlers of the application for
+/// the duration of a prompt. Only supported on unix.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{set_cleanup_on_signal, Select};
+///
+/// fn main() {
+///     set_cleanup_on_signal(true);
+///
+///     let selection = Select::new()
+///         .items(["Apple", "Pear"])
+///         .interact()
+///         .unwrap();
+/// }
+/// ```
+pub fn set_cleanup_on_signal(val: bool) {
+    ENABLED.store(val, Ordering::Relaxed);
+}
+
+/// Handles signals while it is alive, see [`set_cleanup_on_signal`].
+pub(crate) struct Cleanup {
+    #[cfg(unix)]
+    installed: bool,
+}
+
+impl Cleanup {
+    /// Installs the signal handlers for a prompt on `term`, if enabled and not
+    /// installed by an enclosing prompt already.
+    pub(crate) fn install(term: &Term) -> Self {
+        #[cfg(unix)]
+        {
+            let installed =
+                ENABLED.load(Ordering::Relaxed) && term.is_term() && unix::install(term);
+
+            Cleanup { installed }
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = term;
+            Cleanup {}
+        }
+    }
+}
+
+impl Drop for Cleanup {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if self.installed {
+            unix::uninstall();
+        }
+    }
+}
+
+/// Suspends the process until it is continued, like Ctrl-Z in shells.
+///
+/// The prompt should be cleared before, as the shell writes below it while the
+/// process is stopped, and rendered again from scratch afterwards. The cursor is
+/// shown while the process is stopped.
+///
+/// Only prompts which read Ctrl-Z as a key suspend, as a `SIGTSTP` sent by another
+/// process is not handled, see [`set_cleanup_on_signal`].
+pub(crate) fn suspend(term: &Term) -> Result {
+    term.show_cursor()?;
+    term.flush()?;
+
+    #[cfg(unix)]
+    unsafe {
+        libc::raise(libc::SIGTSTP);
+    }
+
+    term.hide_cursor()?;
+    Ok(())
+}
+
+/// Records the number of lines a prompt rendered, which the handler clears.
+pub(crate) fn rendered_lines(lines: usize) {
+    #[cfg(unix)]
+    unix::LINES.store(lines, Ordering::Relaxed);
+
+    #[cfg(not(unix))]
+    let _ = lines;
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::{
+        mem::MaybeUninit,
+        os::{raw::c_int, unix::io::AsRawFd},
+        ptr,
+        sync::atomic::{AtomicBool, AtomicI32, AtomicUsize, Ordering},
+    };
+
+    use console::Term;
+
+    const SIGNALS: [c_int; 2] = [libc::SIGINT, libc::SIGTERM];
+
+    static INSTALLED: AtomicBool = AtomicBool::new(false);
+    static FD: AtomicI32 = AtomicI32::new(-1);
+    pub(super) static LINES: AtomicUsize = AtomicUsize::new(0);
+    static HAS_TERMIOS: AtomicBool = AtomicBool::new(false);
+
+    // Only written while no handler is installed, so the handler reads them safely.
+    static mut TERMIOS: MaybeUninit<libc::termios> = MaybeUninit::uninit();
+    static mut PREVIOUS: [MaybeUninit<libc::sigaction>; 2] = [MaybeUninit::uninit(); 2];
+
+    pub(super) fn install(term: &Term) -> bool {
+        if INSTALLED.swap(true, Ordering::SeqCst) {
+            return false;
+        }
+
+        let fd = term.as_raw_fd();
+        FD.store(fd, Ordering::SeqCst);
+        LINES.store(0, Ordering::SeqCst);
+
+        unsafe {
+            let termios = ptr::addr_of_mut!(TERMIOS).cast::<libc::termios>();
+            HAS_TERMIOS.store(libc::tcgetattr(fd, termios) == 0, Ordering::SeqCst);
+
+            let mut action: libc::sigaction = MaybeUninit::zeroed().assume_init();
+            action.sa_sigaction = handle as extern "C" fn(c_int) as libc::sighandler_t;
+            libc::sigemptyset(&mut action.sa_mask);
+
+            for (idx, &signal) in SIGNALS.iter().enumerate() {
+                libc::sigaction(signal, &action, previous(idx));
+            }
+        }
+
+        true
+    }
+
+    pub(super) fn uninstall() {
+        unsafe {
+            for (idx, &signal) in SIGNALS.iter().enumerate() {
+                libc::sigaction(signal, previous(idx), ptr::null_mut());
+            }
+        }
+
+        INSTALLED.store(false, Ordering::SeqCst);
+    }
+
+    fn previous(idx: usize) -> *mut libc::sigaction {
+        unsafe { ptr::addr_of_mut!(PREVIOUS[idx]).cast() }
+    }
+
+    extern "C" fn handle(signal: c_int) {
+        restore_terminal();
+
+        // Hands the signal to the previous handler, the default one terminates.
+        if let Some(idx) = SIGNALS.iter().position(|&s| s == signal) {
+            unsafe {
+                libc::sigaction(signal, previous(idx), ptr::null_mut());
+                libc::raise(signal);
+            }
+        }
+    }
+
+    /// Clears the rendered lines, shows the cursor and restores the terminal mode.
+    ///
+    /// Only uses async-signal-safe functions and no allocations.
+    fn restore_terminal() {
+        let fd = FD.load(Ordering::SeqCst);
+        let mut buf = [0u8; 64];
+        let mut len = 0;
+
+        let mut push = |bytes: &[u8]| {
+            buf[len..len + bytes.len()].copy_from_slice(bytes);
+            len += bytes.len();
+        };
+
+        let lines = LINES.load(Ordering::SeqCst);
+        if lines > 0 {
+            let mut digits = [0u8; 20];
+            let mut pos = digits.len();
+            let mut n = lines;
+            while n > 0 {
+                pos -= 1;
+                digits[pos] = b'0' + (n % 10) as u8;
+                n /= 10;
+            }
+
+            push(b"\x1b[");
+            push(&digits[pos..]);
+            push(b"A");
+        }
+        push(b"\r\x1b[0J\x1b[?25h\x1b[?2004l");
+
+        unsafe {
+            libc::write(fd, buf.as_ptr().cast(), len);
+
+            if HAS_TERMIOS.load(Ordering::SeqCst) {
+                let termios = ptr::addr_of!(TERMIOS).cast::<libc::termios>();
+                libc::tcsetattr(fd, libc::TCSADRAIN, termios);
+            }
+        }
+    }
+}
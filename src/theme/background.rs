@@ -0,0 +1,271 @@
+use std::env;
+
+/// The brightness of a terminal background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Background {
+    /// A dark background with light text.
+    Dark,
+    /// A light background with dark text.
+    Light,
+}
+
+impl Background {
+    /// Detects the background of the terminal.
+    ///
+    /// The terminal is asked for its background color with the OSC 11 escape
+    /// sequence. Terminals which don't answer it in time fall back to the
+    /// `COLORFGBG` variable which terminals like rxvt, Konsole and iTerm2 set to
+    /// the palette indices of the foreground and background colors. Returns `None`
+    /// if the background is unknown.
+    pub fn detect() -> Option<Background> {
+        match query_rgb() {
+            Some(rgb) => Some(Self::from_rgb(rgb)),
+            None => Self::from_index(index_from_colorfgbg(&env::var("COLORFGBG").ok()?)?),
+        }
+    }
+
+    /// Returns the brightness of the color `rgb` by its perceived luminance.
+    fn from_rgb([r, g, b]: [u8; 3]) -> Background {
+        let luminance = 299 * r as u32 + 587 * g as u32 + 114 * b as u32;
+
+        if luminance >= 128_000 {
+            Background::Light
+        } else {
+            Background::Dark
+        }
+    }
+
+    /// Returns the brightness of the color with the palette `index`.
+    pub(crate) fn from_index(index: u8) -> Option<Background> {
+        match index {
+            // Black, the dark colors and dark gray
+            0..=6 | 8 => Some(Background::Dark),
+            // Light gray and the bright colors
+            7 | 9..=15 => Some(Background::Light),
+            _ => None,
+        }
+    }
+}
+
+/// Detects the palette index of the terminal background, like [`Background::detect`].
+///
+/// The color answered by the terminal is mapped to the closest of the 16 colors
+/// of the xterm palette.
+pub(crate) fn detect_index() -> Option<u8> {
+    match query_rgb() {
+        Some(rgb) => Some(index_from_rgb(rgb)),
+        None => index_from_colorfgbg(&env::var("COLORFGBG").ok()?),
+    }
+}
+
+fn index_from_rgb(rgb: [u8; 3]) -> u8 {
+    const XTERM: [[u8; 3]; 16] = [
+        [0, 0, 0],
+        [205, 0, 0],
+        [0, 205, 0],
+        [205, 205, 0],
+        [0, 0, 238],
+        [205, 0, 205],
+        [0, 205, 205],
+        [229, 229, 229],
+        [127, 127, 127],
+        [255, 0, 0],
+        [0, 255, 0],
+        [255, 255, 0],
+        [92, 92, 255],
+        [255, 0, 255],
+        [0, 255, 255],
+        [255, 255, 255],
+    ];
+
+    let distance = |color: &[u8; 3]| -> u32 {
+        rgb.iter()
+            .zip(color)
+            .map(|(&a, &b)| (a as i32 - b as i32).pow(2) as u32)
+            .sum()
+    };
+
+    (0..16u8)
+        .min_by_key(|&idx| distance(&XTERM[idx as usize]))
+        .unwrap_or(0)
+}
+
+fn index_from_colorfgbg(value: &str) -> Option<u8> {
+    // The background is the last field, e.g. "15;0" or "15;default;0"
+    value.rsplit(';').next()?.trim().parse().ok()
+}
+
+/// Asks the terminal for its background color with OSC 11.
+///
+/// The query is followed by a request for the device attributes, which every
+/// terminal answers, so terminals without OSC 11 support don't delay the prompt
+/// until the timeout and don't leave a late answer in the input.
+#[cfg(unix)]
+fn query_rgb() -> Option<[u8; 3]> {
+    use std::{
+        fs::OpenOptions,
+        io::{Read, Write},
+        mem::MaybeUninit,
+        os::unix::io::AsRawFd,
+        time::{Duration, Instant},
+    };
+
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    if env::var("TERM").map_or(true, |term| term == "dumb") {
+        return None;
+    }
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let fd = tty.as_raw_fd();
+
+    let original = unsafe {
+        let mut termios = MaybeUninit::<libc::termios>::uninit();
+        if libc::tcgetattr(fd, termios.as_mut_ptr()) != 0 {
+            return None;
+        }
+        termios.assume_init()
+    };
+    let mut raw = original;
+    unsafe {
+        libc::cfmakeraw(&mut raw);
+        if libc::tcsetattr(fd, libc::TCSANOW, &raw) != 0 {
+            return None;
+        }
+    }
+
+    let mut reply = Vec::new();
+    if tty.write_all(b"\x1b]11;?\x1b\\\x1b[c").is_ok() && tty.flush().is_ok() {
+        let deadline = Instant::now() + TIMEOUT;
+        let mut buf = [0; 64];
+
+        while !has_device_attributes(&reply) {
+            let timeout = deadline.saturating_duration_since(Instant::now());
+            let mut pollfd = libc::pollfd {
+                fd,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+
+            if timeout.is_zero()
+                || unsafe { libc::poll(&mut pollfd, 1, timeout.as_millis() as _) } <= 0
+            {
+                break;
+            }
+
+            match tty.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(len) => reply.extend_from_slice(&buf[..len]),
+            }
+        }
+    }
+
+    unsafe {
+        libc::tcsetattr(fd, libc::TCSANOW, &original);
+    }
+
+    rgb_from_osc11(&reply)
+}
+
+#[cfg(not(unix))]
+fn query_rgb() -> Option<[u8; 3]> {
+    None
+}
+
+/// Indicates whether `reply` contains the answer to the device attributes
+/// request, e.g. "\x1b[?62;22c", which ends the answers to the queries.
+#[cfg(unix)]
+fn has_device_attributes(reply: &[u8]) -> bool {
+    reply
+        .windows(3)
+        .enumerate()
+        .any(|(idx, start)| start == b"\x1b[?" && reply[idx + 3..].contains(&b'c'))
+}
+
+/// Parses the color of an OSC 11 answer, e.g. "\x1b]11;rgb:1e1e/1e1e/2e2e\x07".
+///
+/// Each channel has 1 to 4 hex digits, which are scaled to 8 bits.
+fn rgb_from_osc11(reply: &[u8]) -> Option<[u8; 3]> {
+    const START: &[u8] = b"\x1b]11;rgb:";
+
+    let start = reply
+        .windows(START.len())
+        .position(|window| window == START)?
+        + START.len();
+    let reply = &reply[start..];
+    let end = reply
+        .iter()
+        .position(|&byte| byte == b'\x07' || byte == b'\x1b')?;
+    let mut channels = std::str::from_utf8(&reply[..end])
+        .ok()?
+        .split('/')
+        .map(|channel| {
+            if !(1..=4).contains(&channel.len()) {
+                return None;
+            }
+
+            let value = u32::from_str_radix(channel, 16).ok()?;
+            let max = (1u32 << (4 * channel.len())) - 1;
+            Some((value * 255 / max) as u8)
+        });
+
+    let rgb = [channels.next()??, channels.next()??, channels.next()??];
+    channels.next().is_none().then_some(rgb)
+}
+
+/// Indicates whether the terminal only supports the 8 basic colors, so that
+/// bright colors like dark gray can't be shown.
+pub(crate) fn basic_colors() -> bool {
+    if env::var("COLORTERM").map_or(false, |value| !value.is_empty()) {
+        return false;
+    }
+
+    matches!(
+        env::var("TERM").as_deref(),
+        Ok("linux" | "ansi" | "vt100" | "vt220" | "cons25" | "xterm-color")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_colorfgbg(value: &str) -> Option<Background> {
+        Background::from_index(index_from_colorfgbg(value)?)
+    }
+
+    #[test]
+    fn test_rgb_from_osc11() {
+        let rgb = rgb_from_osc11(b"\x1b]11;rgb:1e1e/1e1e/2e2e\x07\x1b[?62;22c");
+        assert_eq!(rgb, Some([0x1e, 0x1e, 0x2e]));
+        assert_eq!(
+            rgb_from_osc11(b"\x1b]11;rgb:f/80/fdf6\x1b\\"),
+            Some([255, 128, 252])
+        );
+        assert_eq!(rgb_from_osc11(b"\x1b]11;rgb:ff/ff\x07"), None);
+        assert_eq!(rgb_from_osc11(b"\x1b]11;rgb:ffffffff/0/0\x07"), None);
+        assert_eq!(rgb_from_osc11(b"\x1b[?62;22c"), None);
+    }
+
+    #[test]
+    fn test_from_rgb() {
+        assert_eq!(Background::from_rgb([0x00, 0x2b, 0x36]), Background::Dark);
+        assert_eq!(Background::from_rgb([0xfd, 0xf6, 0xe3]), Background::Light);
+        assert_eq!(index_from_rgb([0x00, 0x2b, 0x36]), 0);
+        assert_eq!(index_from_rgb([0xfd, 0xf6, 0xe3]), 7);
+        assert_eq!(index_from_rgb([0x00, 0xa0, 0xa0]), 6);
+    }
+
+    #[test]
+    fn test_from_colorfgbg() {
+        assert_eq!(from_colorfgbg("15;0"), Some(Background::Dark));
+        assert_eq!(from_colorfgbg("0;default;15"), Some(Background::Light));
+        assert_eq!(from_colorfgbg("0;7"), Some(Background::Light));
+        assert_eq!(from_colorfgbg("15;default"), None);
+        assert_eq!(from_colorfgbg(""), None);
+    }
+}
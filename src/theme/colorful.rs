@@ -1,10 +1,14 @@
 use std::fmt;
 
-use console::{style, Style, StyledObject};
-#[cfg(feature = "fuzzy-select")]
-use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use console::{measure_text_width, style, Color, Style, StyledObject};
 
-use crate::theme::Theme;
+use crate::{
+    theme::{
+        background::{basic_colors, detect_index},
+        Background, Theme,
+    },
+    translate,
+};
 
 /// A colorful theme
 pub struct ColorfulTheme {
@@ -22,10 +26,18 @@ pub struct ColorfulTheme {
     pub success_suffix: StyledObject<String>,
     /// Error prefix value and style
     pub error_prefix: StyledObject<String>,
-    /// The style for error message
+    /// The style for the error message of a rejected input
     pub error_style: Style,
+    /// Warning prefix value and style
+    pub warning_prefix: StyledObject<String>,
+    /// The style for warning message
+    pub warning_style: Style,
     /// The style for hints
     pub hint_style: Style,
+    /// The style for the line which explains the keys of a full screen prompt
+    pub help_style: Style,
+    /// The style for the page or item position of a paged list prompt
+    pub paging_style: Style,
     /// The style for values on prompt success
     pub values_style: Style,
     /// The style for active items
@@ -44,6 +56,8 @@ pub struct ColorfulTheme {
     pub picked_item_prefix: StyledObject<String>,
     /// Unpicked item in sort prefix value and style
     pub unpicked_item_prefix: StyledObject<String>,
+    /// The style for the picked item in sort
+    pub picked_item_style: Style,
     /// Formats the cursor for a fuzzy select prompt
     #[cfg(feature = "fuzzy-select")]
     pub fuzzy_cursor_style: Style,
@@ -63,7 +77,11 @@ impl Default for ColorfulTheme {
             success_suffix: style("·".to_string()).for_stderr().black().bright(),
             error_prefix: style("✘".to_string()).for_stderr().red(),
             error_style: Style::new().for_stderr().red(),
+            warning_prefix: style("!".to_string()).for_stderr().yellow(),
+            warning_style: Style::new().for_stderr().yellow(),
             hint_style: Style::new().for_stderr().black().bright(),
+            help_style: Style::new().for_stderr().black().bright(),
+            paging_style: Style::new().for_stderr(),
             values_style: Style::new().for_stderr().green(),
             active_item_style: Style::new().for_stderr().cyan(),
             inactive_item_style: Style::new().for_stderr(),
@@ -71,8 +89,9 @@ impl Default for ColorfulTheme {
             inactive_item_prefix: style(" ".to_string()).for_stderr(),
             checked_item_prefix: style("✔".to_string()).for_stderr().green(),
             unchecked_item_prefix: style("⬚".to_string()).for_stderr().magenta(),
-            picked_item_prefix: style("❯".to_string()).for_stderr().green(),
+            picked_item_prefix: style("↕".to_string()).for_stderr().yellow().bold(),
             unpicked_item_prefix: style(" ".to_string()).for_stderr(),
+            picked_item_style: Style::new().for_stderr().yellow().bold(),
             #[cfg(feature = "fuzzy-select")]
             fuzzy_cursor_style: Style::new().for_stderr().black().on_white(),
             #[cfg(feature = "fuzzy-select")]
@@ -81,6 +100,159 @@ impl Default for ColorfulTheme {
     }
 }
 
+impl ColorfulTheme {
+    /// Creates a colorful theme which is readable on light terminal backgrounds.
+    pub fn light() -> ColorfulTheme {
+        ColorfulTheme {
+            defaults_style: Style::new().for_stderr().blue(),
+            prompt_prefix: style("?".to_string()).for_stderr().blue(),
+            active_item_style: Style::new().for_stderr().blue(),
+            picked_item_prefix: style("↕".to_string()).for_stderr().magenta().bold(),
+            picked_item_style: Style::new().for_stderr().magenta().bold(),
+            #[cfg(feature = "fuzzy-select")]
+            fuzzy_cursor_style: Style::new().for_stderr().white().on_black(),
+            ..ColorfulTheme::default()
+        }
+    }
+
+    /// Creates a colorful theme tuned for the given terminal background.
+    pub fn for_background(background: Background) -> ColorfulTheme {
+        match background {
+            Background::Dark => ColorfulTheme::default(),
+            Background::Light => ColorfulTheme::light(),
+        }
+    }
+
+    /// Creates a colorful theme tuned for the detected terminal background.
+    ///
+    /// Falls back to the default theme for dark backgrounds if the background
+    /// can't be detected, see [`Background::detect`].
+    pub fn detect() -> ColorfulTheme {
+        ColorfulTheme::for_background(Background::detect().unwrap_or(Background::Dark))
+    }
+
+    /// Creates a colorful theme whose colors contrast with the terminal background.
+    ///
+    /// Like [`detect`](Self::detect) the background is queried from the terminal, but
+    /// every color which is hard to read on it is replaced, e.g. a cyan highlight
+    /// on a cyan background. Terminals with only 8 colors, like the Linux console,
+    /// get bold and reversed text instead of dark gray hints and cursors.
+    pub fn auto_contrast() -> ColorfulTheme {
+        Palette {
+            background: detect_index(),
+            basic: basic_colors(),
+        }
+        .theme()
+    }
+}
+
+/// The colors of a terminal, for which [`ColorfulTheme::auto_contrast`] picks
+/// readable colors.
+struct Palette {
+    /// The palette index of the background, if known.
+    background: Option<u8>,
+    /// Whether only the 8 basic colors are supported.
+    basic: bool,
+}
+
+impl Palette {
+    fn theme(&self) -> ColorfulTheme {
+        let fg = |color| Style::new().for_stderr().fg(self.readable(color));
+        let prefix = |text: &str, color| fg(color).apply_to(text.to_string());
+        let values = if self.basic {
+            fg(Color::Green).bold()
+        } else {
+            fg(Color::Green)
+        };
+        let hint = if self.basic || self.background == Some(8) {
+            Style::new().for_stderr()
+        } else {
+            Style::new().for_stderr().black().bright()
+        };
+
+        ColorfulTheme {
+            defaults_style: fg(Color::Cyan),
+            prompt_style: Style::new().for_stderr().bold(),
+            prompt_prefix: prefix("?", Color::Yellow),
+            prompt_suffix: hint.apply_to("›".to_string()),
+            success_prefix: prefix("✔", Color::Green),
+            success_suffix: hint.apply_to("·".to_string()),
+            error_prefix: prefix("✘", Color::Red),
+            error_style: fg(Color::Red),
+            warning_prefix: prefix("!", Color::Yellow),
+            warning_style: fg(Color::Yellow),
+            hint_style: hint.clone(),
+            help_style: hint.clone(),
+            paging_style: Style::new().for_stderr(),
+            values_style: values,
+            active_item_style: fg(Color::Cyan),
+            inactive_item_style: Style::new().for_stderr(),
+            active_item_prefix: prefix("❯", Color::Green),
+            inactive_item_prefix: style(" ".to_string()).for_stderr(),
+            checked_item_prefix: prefix("✔", Color::Green),
+            unchecked_item_prefix: prefix("⬚", Color::Magenta),
+            picked_item_prefix: prefix("↕", Color::Yellow).bold(),
+            unpicked_item_prefix: style(" ".to_string()).for_stderr(),
+            picked_item_style: fg(Color::Yellow).bold(),
+            #[cfg(feature = "fuzzy-select")]
+            fuzzy_cursor_style: if self.basic {
+                Style::new().for_stderr().reverse()
+            } else if self.light() {
+                Style::new().for_stderr().white().on_black()
+            } else {
+                Style::new().for_stderr().black().on_white()
+            },
+            #[cfg(feature = "fuzzy-select")]
+            fuzzy_match_highlight_style: Style::new().for_stderr().bold(),
+        }
+    }
+
+    fn light(&self) -> bool {
+        self.background.and_then(Background::from_index) == Some(Background::Light)
+    }
+
+    /// Returns `color`, or a replacement if it's hard to read on the background.
+    fn readable(&self, color: Color) -> Color {
+        let light = self.light();
+
+        let color = match color {
+            Color::Yellow if light => Color::Magenta,
+            Color::Cyan if light => Color::Blue,
+            Color::White if light => Color::Black,
+            Color::Blue if !light => Color::Cyan,
+            Color::Black if !light => Color::White,
+            color => color,
+        };
+
+        // Bright colors look like their basic color on many terminals
+        match self.background {
+            Some(background) if index(color) == Some(background % 8) => {
+                if light {
+                    Color::Black
+                } else {
+                    Color::White
+                }
+            }
+            _ => color,
+        }
+    }
+}
+
+/// Returns the palette index of a basic color.
+fn index(color: Color) -> Option<u8> {
+    match color {
+        Color::Black => Some(0),
+        Color::Red => Some(1),
+        Color::Green => Some(2),
+        Color::Yellow => Some(3),
+        Color::Blue => Some(4),
+        Color::Magenta => Some(5),
+        Color::Cyan => Some(6),
+        Color::White => Some(7),
+        Color::Color256(_) => None,
+    }
+}
+
 impl Theme for ColorfulTheme {
     /// Formats a prompt.
     fn format_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
@@ -106,6 +278,18 @@ impl Theme for ColorfulTheme {
         )
     }
 
+    /// Formats a warning
+    fn format_warning(&self, f: &mut dyn fmt::Write, warning: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            &self.warning_prefix,
+            self.warning_style.apply_to(warning),
+            self.hint_style
+                .apply_to(translate::text("press-enter", "(press Enter to continue)"))
+        )
+    }
+
     /// Formats an input prompt.
     fn format_input_prompt(
         &self,
@@ -161,14 +345,14 @@ impl Theme for ColorfulTheme {
                 "{} {} {}",
                 self.hint_style.apply_to("(y/n)"),
                 &self.prompt_suffix,
-                self.defaults_style.apply_to("yes")
+                self.defaults_style.apply_to(translate::text("yes", "yes"))
             ),
             Some(false) => write!(
                 f,
                 "{} {} {}",
                 self.hint_style.apply_to("(y/n)"),
                 &self.prompt_suffix,
-                self.defaults_style.apply_to("no")
+                self.defaults_style.apply_to(translate::text("no", "no"))
             ),
         }
     }
@@ -188,7 +372,13 @@ impl Theme for ColorfulTheme {
                 self.prompt_style.apply_to(prompt)
             )?;
         }
-        let selection = selection.map(|b| if b { "yes" } else { "no" });
+        let selection = selection.map(|b| {
+            if b {
+                translate::text("yes", "yes")
+            } else {
+                translate::text("no", "no")
+            }
+        });
 
         match selection {
             Some(selection) => {
@@ -205,6 +395,22 @@ impl Theme for ColorfulTheme {
         }
     }
 
+    /// Formats the checkbox of a confirm prompt.
+    fn format_confirm_remember_option(
+        &self,
+        f: &mut dyn fmt::Write,
+        label: &str,
+        checked: bool,
+    ) -> fmt::Result {
+        let prefix = if checked {
+            &self.checked_item_prefix
+        } else {
+            &self.unchecked_item_prefix
+        };
+
+        write!(f, "{} {}", prefix, self.hint_style.apply_to(label))
+    }
+
     /// Formats an input prompt after selection.
     fn format_input_prompt_selection(
         &self,
@@ -229,6 +435,11 @@ impl Theme for ColorfulTheme {
         )
     }
 
+    /// Formats the suggested continuation of an input.
+    fn format_input_ghost_text(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        write!(f, "{}", self.hint_style.apply_to(text))
+    }
+
     /// Formats a password prompt after selection.
     #[cfg(feature = "password")]
     fn format_password_prompt_selection(
@@ -269,6 +480,28 @@ impl Theme for ColorfulTheme {
         Ok(())
     }
 
+    /// Formats an answer in the summary of a session.
+    fn format_summary_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        key: &str,
+        value: &str,
+        key_width: usize,
+    ) -> fmt::Result {
+        let padding = key_width.saturating_sub(measure_text_width(key));
+
+        write!(
+            f,
+            "{} {:padding$}{} {} {}",
+            &self.success_prefix,
+            "",
+            self.prompt_style.apply_to(key),
+            &self.success_suffix,
+            self.values_style.apply_to(value),
+            padding = padding
+        )
+    }
+
     /// Formats a select prompt item.
     fn format_select_prompt_item(
         &self,
@@ -291,6 +524,49 @@ impl Theme for ColorfulTheme {
         write!(f, "{} {}", details.0, details.1)
     }
 
+    /// Formats a pinned select prompt item.
+    fn format_select_prompt_pinned_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        let (prefix, text) = if active {
+            (
+                &self.active_item_prefix,
+                self.active_item_style.apply_to(text),
+            )
+        } else {
+            (
+                &self.inactive_item_prefix,
+                self.inactive_item_style.apply_to(text),
+            )
+        };
+
+        write!(f, "{} {} {}", prefix, self.hint_style.apply_to("★"), text)
+    }
+
+    /// Formats the row of a select prompt which loads more items.
+    fn format_load_more_item(&self, f: &mut dyn fmt::Write, active: bool) -> fmt::Result {
+        if active {
+            write!(
+                f,
+                "{} {}",
+                self.active_item_prefix,
+                self.active_item_style
+                    .apply_to(translate::text("load-more", "Load more…"))
+            )
+        } else {
+            write!(
+                f,
+                "{} {}",
+                self.inactive_item_prefix,
+                self.hint_style
+                    .apply_to(translate::text("load-more", "Load more…"))
+            )
+        }
+    }
+
     /// Formats a multi select prompt item.
     fn format_multi_select_prompt_item(
         &self,
@@ -321,6 +597,41 @@ impl Theme for ColorfulTheme {
         write!(f, "{} {}", details.0, details.1)
     }
 
+    /// Formats the description of the highlighted multi select prompt item.
+    fn format_multi_select_prompt_item_description(
+        &self,
+        f: &mut dyn fmt::Write,
+        description: &str,
+    ) -> fmt::Result {
+        let indent = measure_text_width(&self.checked_item_prefix.to_string()) + 1;
+
+        write!(
+            f,
+            "{:indent$}{}",
+            "",
+            self.hint_style.apply_to(description),
+            indent = indent
+        )
+    }
+
+    /// Formats the status line of a multi select prompt.
+    fn format_multi_select_status(
+        &self,
+        f: &mut dyn fmt::Write,
+        checked: usize,
+        items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.hint_style.apply_to(translate::format(
+                "selected-items",
+                "{checked} selected / {items} items",
+                &[("checked", &checked), ("items", &items)],
+            ))
+        )
+    }
+
     /// Formats a sort prompt item.
     fn format_sort_prompt_item(
         &self,
@@ -332,7 +643,7 @@ impl Theme for ColorfulTheme {
         let details = match (picked, active) {
             (true, true) => (
                 &self.picked_item_prefix,
-                self.active_item_style.apply_to(text),
+                self.picked_item_style.apply_to(text),
             ),
             (false, true) => (
                 &self.unpicked_item_prefix,
@@ -347,16 +658,198 @@ impl Theme for ColorfulTheme {
         write!(f, "{} {}", details.0, details.1)
     }
 
+    /// Formats the position typed to move the picked item of a sort prompt to.
+    fn format_sort_prompt_position(
+        &self,
+        f: &mut dyn fmt::Write,
+        position: &str,
+        items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{} {}{}",
+            self.hint_style
+                .apply_to(format!("{}:", translate::text("move-to", "Move to"))),
+            self.values_style.apply_to(position),
+            self.hint_style.apply_to(format!("/{}", items))
+        )
+    }
+
+    /// Formats a line of a text area.
+    fn format_text_area_line(
+        &self,
+        f: &mut dyn fmt::Write,
+        line: &str,
+        cursor: Option<usize>,
+    ) -> fmt::Result {
+        match cursor {
+            Some(cursor) => {
+                let head: String = line.chars().take(cursor).collect();
+                let mut tail = line.chars().skip(cursor);
+                let chr = tail.next().unwrap_or(' ');
+                let tail: String = tail.collect();
+                write!(f, "  {}{}{}", head, style(chr).for_stderr().reverse(), tail)
+            }
+            None => write!(f, "  {}", line),
+        }
+    }
+
+    /// Formats the bar of a range slider.
+    fn format_range_slider_bar(
+        &self,
+        f: &mut dyn fmt::Write,
+        low: &str,
+        high: &str,
+        span: (usize, usize),
+        width: usize,
+        low_active: bool,
+    ) -> fmt::Result {
+        let handle = |active: bool| {
+            if active {
+                self.active_item_style.apply_to("●")
+            } else {
+                self.values_style.apply_to("●")
+            }
+        };
+        let value = |value: &str, active: bool| {
+            if active {
+                self.active_item_style.apply_to(value.to_string())
+            } else {
+                self.values_style.apply_to(value.to_string())
+            }
+        };
+
+        write!(f, "{} ", value(low, low_active))?;
+        for cell in 0..width {
+            match cell {
+                cell if cell == span.0 && (low_active || cell != span.1) => {
+                    write!(f, "{}", handle(low_active))?
+                }
+                cell if cell == span.1 => write!(f, "{}", handle(!low_active))?,
+                cell if cell > span.0 && cell < span.1 => {
+                    write!(f, "{}", self.values_style.apply_to("━"))?
+                }
+                _ => write!(f, "{}", self.hint_style.apply_to("─"))?,
+            }
+        }
+        write!(f, " {}", value(high, !low_active))
+    }
+
+    /// Formats the filter line of a list prompt.
+    fn format_filter_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        filter: &str,
+        active: bool,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.hint_style.apply_to("/"),
+            self.prompt_style.apply_to(filter)
+        )?;
+
+        if active {
+            write!(f, "{}", self.hint_style.apply_to("|"))?;
+        }
+
+        Ok(())
+    }
+
+    /// Formats the paging info of a paged list prompt.
+    fn format_paging_info(
+        &self,
+        f: &mut dyn fmt::Write,
+        page: usize,
+        pages: usize,
+        _items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            " {} ",
+            self.paging_style.apply_to(translate::format(
+                "page",
+                "[Page {page}/{pages}]",
+                &[("page", &page), ("pages", &pages)],
+            ))
+        )
+    }
+
+    /// Formats the position of the cursor in a list prompt scrolled in a window.
+    fn format_scroll_info(&self, f: &mut dyn fmt::Write, item: usize, items: usize) -> fmt::Result {
+        write!(
+            f,
+            " {} ",
+            self.paging_style.apply_to(translate::format(
+                "item",
+                "[Item {item}/{items}]",
+                &[("item", &item), ("items", &items)],
+            ))
+        )
+    }
+
+    /// Formats the path of the directory shown by a file picker as breadcrumbs.
+    fn format_breadcrumbs(
+        &self,
+        f: &mut dyn fmt::Write,
+        segments: &[&str],
+        active: Option<usize>,
+    ) -> fmt::Result {
+        for (idx, segment) in segments.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " {} ", self.hint_style.apply_to("›"))?;
+            }
+
+            if active == Some(idx) {
+                write!(f, "{}", self.active_item_style.apply_to(segment))?;
+            } else if idx + 1 == segments.len() {
+                write!(f, "{}", self.prompt_style.apply_to(segment))?;
+            } else {
+                write!(f, "{}", self.hint_style.apply_to(segment))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Formats the line which explains the keys of a full screen prompt.
+    fn format_key_help(&self, f: &mut dyn fmt::Write, help: &str) -> fmt::Result {
+        write!(f, "{}", self.help_style.apply_to(help))
+    }
+
+    /// Formats the line shown while the items of a prompt are loading.
+    fn format_loading_item(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.inactive_item_prefix,
+            self.hint_style
+                .apply_to(translate::text("loading", "Loading…"))
+        )
+    }
+
+    /// Formats the line with a spinner shown while the items of a prompt are fetched.
+    fn format_loading_spinner(&self, f: &mut dyn fmt::Write, tick: usize) -> fmt::Result {
+        const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+        write!(
+            f,
+            "{} {} {}",
+            self.inactive_item_prefix,
+            self.active_item_style.apply_to(FRAMES[tick % FRAMES.len()]),
+            self.hint_style
+                .apply_to(translate::text("loading", "Loading…"))
+        )
+    }
+
     /// Formats a fuzzy select prompt item.
     #[cfg(feature = "fuzzy-select")]
-    fn format_fuzzy_select_prompt_item(
+    fn format_fuzzy_select_item(
         &self,
         f: &mut dyn fmt::Write,
         text: &str,
+        match_indices: &[usize],
         active: bool,
-        highlight_matches: bool,
-        matcher: &SkimMatcherV2,
-        search_term: &str,
     ) -> fmt::Result {
         write!(
             f,
@@ -368,32 +861,31 @@ impl Theme for ColorfulTheme {
             }
         )?;
 
-        if highlight_matches {
-            if let Some((_score, indices)) = matcher.fuzzy_indices(text, search_term) {
-                for (idx, c) in text.chars().enumerate() {
-                    if indices.contains(&idx) {
-                        if active {
-                            write!(
-                                f,
-                                "{}",
-                                self.active_item_style
-                                    .apply_to(self.fuzzy_match_highlight_style.apply_to(c))
-                            )?;
-                        } else {
-                            write!(f, "{}", self.fuzzy_match_highlight_style.apply_to(c))?;
-                        }
-                    } else if active {
-                        write!(f, "{}", self.active_item_style.apply_to(c))?;
-                    } else {
-                        write!(f, "{}", c)?;
-                    }
-                }
+        // Without matches the text is written as is, like the theme always did
+        if match_indices.is_empty() {
+            return write!(f, "{}", text);
+        }
 
-                return Ok(());
+        for (idx, c) in text.chars().enumerate() {
+            if match_indices.binary_search(&idx).is_ok() {
+                if active {
+                    write!(
+                        f,
+                        "{}",
+                        self.active_item_style
+                            .apply_to(self.fuzzy_match_highlight_style.apply_to(c))
+                    )?;
+                } else {
+                    write!(f, "{}", self.fuzzy_match_highlight_style.apply_to(c))?;
+                }
+            } else if active {
+                write!(f, "{}", self.active_item_style.apply_to(c))?;
+            } else {
+                write!(f, "{}", c)?;
             }
         }
 
-        write!(f, "{}", text)
+        Ok(())
     }
 
     /// Formats a fuzzy-selectprompt after selection.
@@ -423,4 +915,53 @@ impl Theme for ColorfulTheme {
         let prompt_suffix = &self.prompt_suffix;
         write!(f, "{prompt_suffix} {st_head}{st_cursor}{st_tail}",)
     }
+
+    /// Formats the number of matching items of a fuzzy select prompt.
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_match_count(
+        &self,
+        f: &mut dyn fmt::Write,
+        matches: usize,
+        items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            " {}",
+            self.hint_style.apply_to(translate::format(
+                "matches",
+                "{matches}/{items} matches",
+                &[("matches", &matches), ("items", &items)],
+            ))
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_contrast() {
+        let palette = |background, basic| Palette { background, basic };
+
+        assert_eq!(palette(None, false).readable(Color::Cyan), Color::Cyan);
+        assert_eq!(palette(Some(15), false).readable(Color::Cyan), Color::Blue);
+        assert_eq!(
+            palette(Some(15), false).readable(Color::Yellow),
+            Color::Magenta
+        );
+        assert_eq!(palette(Some(0), false).readable(Color::Blue), Color::Cyan);
+        assert_eq!(palette(Some(2), false).readable(Color::Green), Color::White);
+        assert_eq!(
+            palette(Some(11), false).readable(Color::Magenta),
+            Color::Magenta
+        );
+
+        let basic = palette(Some(0), true).theme();
+        assert_eq!(basic.hint_style, Style::new().for_stderr());
+        assert_eq!(basic.values_style, Style::new().for_stderr().green().bold());
+
+        let dark = palette(Some(0), false).theme();
+        assert_eq!(dark.hint_style, ColorfulTheme::default().hint_style);
+    }
 }
@@ -0,0 +1,340 @@
+use std::fmt;
+
+use console::{style, Emoji};
+#[cfg(feature = "fuzzy-select")]
+use fuzzy_matcher::skim::SkimMatcherV2;
+
+use crate::theme::{ColorfulTheme, Theme};
+
+/// A colorful theme with emoji and other Unicode symbols.
+///
+/// The symbols fall back to plain ASCII on terminals which lack Unicode support.
+pub struct EmojiTheme {
+    theme: ColorfulTheme,
+}
+
+impl Default for EmojiTheme {
+    fn default() -> EmojiTheme {
+        let symbol = |emoji, fallback| Emoji(emoji, fallback).to_string();
+
+        EmojiTheme {
+            theme: ColorfulTheme {
+                prompt_suffix: style(symbol("›", ">")).for_stderr().black().bright(),
+                success_prefix: style(symbol("✔", "v")).for_stderr().green(),
+                success_suffix: style(symbol("·", "-")).for_stderr().black().bright(),
+                error_prefix: style(symbol("✖", "x")).for_stderr().red(),
+                warning_prefix: style(symbol("⚠", "!")).for_stderr().yellow(),
+                active_item_prefix: style(symbol("❯", ">")).for_stderr().green(),
+                checked_item_prefix: style(symbol("●", "[x]")).for_stderr().green(),
+                unchecked_item_prefix: style(symbol("○", "[ ]")).for_stderr().magenta(),
+                picked_item_prefix: style(symbol("↕", "=")).for_stderr().yellow().bold(),
+                ..ColorfulTheme::default()
+            },
+        }
+    }
+}
+
+impl Theme for EmojiTheme {
+    fn format_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
+        self.theme.format_prompt(f, prompt)
+    }
+
+    fn format_error(&self, f: &mut dyn fmt::Write, err: &str) -> fmt::Result {
+        self.theme.format_error(f, err)
+    }
+
+    fn format_warning(&self, f: &mut dyn fmt::Write, warning: &str) -> fmt::Result {
+        self.theme.format_warning(f, warning)
+    }
+
+    fn format_input_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        default: Option<&str>,
+    ) -> fmt::Result {
+        self.theme.format_input_prompt(f, prompt, default)
+    }
+
+    fn format_confirm_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        default: Option<bool>,
+    ) -> fmt::Result {
+        self.theme.format_confirm_prompt(f, prompt, default)
+    }
+
+    fn format_confirm_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        selection: Option<bool>,
+    ) -> fmt::Result {
+        self.theme
+            .format_confirm_prompt_selection(f, prompt, selection)
+    }
+
+    fn format_confirm_remember_option(
+        &self,
+        f: &mut dyn fmt::Write,
+        label: &str,
+        checked: bool,
+    ) -> fmt::Result {
+        self.theme.format_confirm_remember_option(f, label, checked)
+    }
+
+    fn format_input_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        sel: &str,
+    ) -> fmt::Result {
+        self.theme.format_input_prompt_selection(f, prompt, sel)
+    }
+
+    fn format_input_ghost_text(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        self.theme.format_input_ghost_text(f, text)
+    }
+
+    #[cfg(feature = "password")]
+    fn format_password_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+    ) -> fmt::Result {
+        self.theme.format_password_prompt_selection(f, prompt)
+    }
+
+    fn format_multi_select_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        selections: &[&str],
+    ) -> fmt::Result {
+        self.theme
+            .format_multi_select_prompt_selection(f, prompt, selections)
+    }
+
+    fn format_summary_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        key: &str,
+        value: &str,
+        key_width: usize,
+    ) -> fmt::Result {
+        self.theme.format_summary_item(f, key, value, key_width)
+    }
+
+    fn format_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        self.theme.format_select_prompt_item(f, text, active)
+    }
+
+    fn format_select_prompt_pinned_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        self.theme.format_select_prompt_pinned_item(f, text, active)
+    }
+
+    fn format_load_more_item(&self, f: &mut dyn fmt::Write, active: bool) -> fmt::Result {
+        self.theme.format_load_more_item(f, active)
+    }
+
+    fn format_multi_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        checked: bool,
+        active: bool,
+    ) -> fmt::Result {
+        self.theme
+            .format_multi_select_prompt_item(f, text, checked, active)
+    }
+
+    fn format_multi_select_prompt_item_description(
+        &self,
+        f: &mut dyn fmt::Write,
+        description: &str,
+    ) -> fmt::Result {
+        self.theme
+            .format_multi_select_prompt_item_description(f, description)
+    }
+
+    fn format_multi_select_status(
+        &self,
+        f: &mut dyn fmt::Write,
+        checked: usize,
+        items: usize,
+    ) -> fmt::Result {
+        self.theme.format_multi_select_status(f, checked, items)
+    }
+
+    fn format_sort_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        picked: bool,
+        active: bool,
+    ) -> fmt::Result {
+        self.theme.format_sort_prompt_item(f, text, picked, active)
+    }
+
+    fn format_sort_prompt_position(
+        &self,
+        f: &mut dyn fmt::Write,
+        position: &str,
+        items: usize,
+    ) -> fmt::Result {
+        self.theme.format_sort_prompt_position(f, position, items)
+    }
+
+    fn format_text_area_line(
+        &self,
+        f: &mut dyn fmt::Write,
+        line: &str,
+        cursor: Option<usize>,
+    ) -> fmt::Result {
+        self.theme.format_text_area_line(f, line, cursor)
+    }
+
+    fn format_text_area_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        text: &str,
+    ) -> fmt::Result {
+        self.theme
+            .format_text_area_prompt_selection(f, prompt, text)
+    }
+
+    fn format_range_slider_bar(
+        &self,
+        f: &mut dyn fmt::Write,
+        low: &str,
+        high: &str,
+        span: (usize, usize),
+        width: usize,
+        low_active: bool,
+    ) -> fmt::Result {
+        self.theme
+            .format_range_slider_bar(f, low, high, span, width, low_active)
+    }
+
+    fn format_range_slider_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        low: &str,
+        high: &str,
+    ) -> fmt::Result {
+        self.theme
+            .format_range_slider_prompt_selection(f, prompt, low, high)
+    }
+
+    fn format_filter_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        filter: &str,
+        active: bool,
+    ) -> fmt::Result {
+        self.theme.format_filter_prompt(f, filter, active)
+    }
+
+    fn format_paging_info(
+        &self,
+        f: &mut dyn fmt::Write,
+        page: usize,
+        pages: usize,
+        items: usize,
+    ) -> fmt::Result {
+        self.theme.format_paging_info(f, page, pages, items)
+    }
+
+    fn format_scroll_info(&self, f: &mut dyn fmt::Write, item: usize, items: usize) -> fmt::Result {
+        self.theme.format_scroll_info(f, item, items)
+    }
+
+    fn format_breadcrumbs(
+        &self,
+        f: &mut dyn fmt::Write,
+        segments: &[&str],
+        active: Option<usize>,
+    ) -> fmt::Result {
+        self.theme.format_breadcrumbs(f, segments, active)
+    }
+
+    fn format_key_help(&self, f: &mut dyn fmt::Write, help: &str) -> fmt::Result {
+        self.theme.format_key_help(f, help)
+    }
+
+    fn format_loading_item(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        self.theme.format_loading_item(f)
+    }
+
+    fn format_loading_spinner(&self, f: &mut dyn fmt::Write, tick: usize) -> fmt::Result {
+        self.theme.format_loading_spinner(f, tick)
+    }
+
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+        highlight_matches: bool,
+        matcher: &SkimMatcherV2,
+        search_term: &str,
+    ) -> fmt::Result {
+        self.theme.format_fuzzy_select_prompt_item(
+            f,
+            text,
+            active,
+            highlight_matches,
+            matcher,
+            search_term,
+        )
+    }
+
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        match_indices: &[usize],
+        active: bool,
+    ) -> fmt::Result {
+        self.theme
+            .format_fuzzy_select_item(f, text, match_indices, active)
+    }
+
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        search_term: &str,
+        bytes_pos: usize,
+    ) -> fmt::Result {
+        self.theme
+            .format_fuzzy_select_prompt(f, prompt, search_term, bytes_pos)
+    }
+
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_match_count(
+        &self,
+        f: &mut dyn fmt::Write,
+        matches: usize,
+        items: usize,
+    ) -> fmt::Result {
+        self.theme
+            .format_fuzzy_select_match_count(f, matches, items)
+    }
+}
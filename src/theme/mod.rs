@@ -1,17 +1,26 @@
 //! Customizes the rendering of the elements.
 use std::fmt;
 
-#[cfg(feature = "fuzzy-select")]
-use console::style;
+use console::{measure_text_width, style};
 #[cfg(feature = "fuzzy-select")]
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
 
+use crate::translate;
+
+mod background;
 mod colorful;
-pub(crate) mod render;
+mod emoji;
+#[cfg(feature = "theme-presets")]
+mod presets;
+pub mod render;
 mod simple;
+mod template;
 
+pub use background::Background;
 pub use colorful::ColorfulTheme;
+pub use emoji::EmojiTheme;
 pub use simple::SimpleTheme;
+pub use template::TemplateTheme;
 
 /// Implements a theme for dialoguer.
 pub trait Theme {
@@ -27,6 +36,19 @@ pub trait Theme {
         write!(f, "error: {}", err)
     }
 
+    /// Formats out a validation warning.
+    ///
+    /// The user can accept the input anyway by pressing 'Enter' or 'y'.
+    #[inline]
+    fn format_warning(&self, f: &mut dyn fmt::Write, warning: &str) -> fmt::Result {
+        write!(
+            f,
+            "warning: {} {}",
+            warning,
+            translate::text("press-enter", "(press Enter to continue)")
+        )
+    }
+
     /// Formats a confirm prompt.
     fn format_confirm_prompt(
         &self,
@@ -52,7 +74,13 @@ pub trait Theme {
         prompt: &str,
         selection: Option<bool>,
     ) -> fmt::Result {
-        let selection = selection.map(|b| if b { "yes" } else { "no" });
+        let selection = selection.map(|b| {
+            if b {
+                translate::text("yes", "yes")
+            } else {
+                translate::text("no", "no")
+            }
+        });
 
         match selection {
             Some(selection) if prompt.is_empty() => {
@@ -68,6 +96,17 @@ pub trait Theme {
         }
     }
 
+    /// Formats the checkbox of a confirm prompt below the question, e.g. "Don't ask
+    /// me again".
+    fn format_confirm_remember_option(
+        &self,
+        f: &mut dyn fmt::Write,
+        label: &str,
+        checked: bool,
+    ) -> fmt::Result {
+        write!(f, "{} {}", if checked { "[x]" } else { "[ ]" }, label)
+    }
+
     /// Formats an input prompt.
     fn format_input_prompt(
         &self,
@@ -93,6 +132,12 @@ pub trait Theme {
         write!(f, "{}: {}", prompt, sel)
     }
 
+    /// Formats the suggested continuation of an input, which is shown after the cursor.
+    #[inline]
+    fn format_input_ghost_text(&self, f: &mut dyn fmt::Write, text: &str) -> fmt::Result {
+        write!(f, "{}", style(text).for_stderr().dim())
+    }
+
     /// Formats a password prompt.
     #[inline]
     #[cfg(feature = "password")]
@@ -108,7 +153,7 @@ pub trait Theme {
         f: &mut dyn fmt::Write,
         prompt: &str,
     ) -> fmt::Result {
-        self.format_input_prompt_selection(f, prompt, "[hidden]")
+        self.format_input_prompt_selection(f, prompt, &translate::text("hidden", "[hidden]"))
     }
 
     /// Formats a select prompt.
@@ -165,6 +210,20 @@ pub trait Theme {
         self.format_multi_select_prompt_selection(f, prompt, selections)
     }
 
+    /// Formats an answer in the summary of a session.
+    ///
+    /// The keys of all answers are aligned to `key_width`.
+    fn format_summary_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        key: &str,
+        value: &str,
+        key_width: usize,
+    ) -> fmt::Result {
+        let padding = key_width.saturating_sub(measure_text_width(key));
+        write!(f, "{:padding$}{}: {}", "", key, value, padding = padding)
+    }
+
     /// Formats a select prompt item.
     fn format_select_prompt_item(
         &self,
@@ -175,6 +234,21 @@ pub trait Theme {
         write!(f, "{} {}", if active { ">" } else { " " }, text)
     }
 
+    /// Formats a pinned select prompt item, which is listed at the top.
+    fn format_select_prompt_pinned_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        self.format_select_prompt_item(f, &format!("* {}", text), active)
+    }
+
+    /// Formats the row of a select prompt which loads more items.
+    fn format_load_more_item(&self, f: &mut dyn fmt::Write, active: bool) -> fmt::Result {
+        self.format_select_prompt_item(f, &translate::text("load-more", "Load more…"), active)
+    }
+
     /// Formats a multi select prompt item.
     fn format_multi_select_prompt_item(
         &self,
@@ -196,7 +270,40 @@ pub trait Theme {
         )
     }
 
+    /// Formats the description of the highlighted multi select prompt item.
+    ///
+    /// The description is indented to line up with the text of the item.
+    fn format_multi_select_prompt_item_description(
+        &self,
+        f: &mut dyn fmt::Write,
+        description: &str,
+    ) -> fmt::Result {
+        write!(f, "      {}", description)
+    }
+
+    /// Formats the status line of a multi select prompt.
+    ///
+    /// `checked` is the number of checked items and `items` the number of all items.
+    fn format_multi_select_status(
+        &self,
+        f: &mut dyn fmt::Write,
+        checked: usize,
+        items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            translate::format(
+                "selected-items",
+                "{checked} selected / {items} items",
+                &[("checked", &checked), ("items", &items)],
+            )
+        )
+    }
+
     /// Formats a sort prompt item.
+    ///
+    /// The active item is `picked` while the user moves it.
     fn format_sort_prompt_item(
         &self,
         f: &mut dyn fmt::Write,
@@ -208,15 +315,255 @@ pub trait Theme {
             f,
             "{} {}",
             match (picked, active) {
-                (true, true) => "> [x]",
-                (false, true) => "> [ ]",
-                (_, false) => "  [ ]",
+                (true, true) => "↕",
+                (false, true) => ">",
+                (_, false) => " ",
             },
             text
         )
     }
 
+    /// Formats the position typed to move the picked item of a sort prompt to.
+    ///
+    /// `position` starts at 1, `items` is the number of all items.
+    fn format_sort_prompt_position(
+        &self,
+        f: &mut dyn fmt::Write,
+        position: &str,
+        items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}/{}",
+            translate::text("move-to", "Move to"),
+            position,
+            items
+        )
+    }
+
+    /// Formats the bar of a range slider.
+    ///
+    /// The bar is `width` cells wide and the selected range spans the cells `span`,
+    /// including both. `low` and `high` are the bounds of the range, the handle of
+    /// `low` is active if `low_active` is set and the handle of `high` otherwise.
+    fn format_range_slider_bar(
+        &self,
+        f: &mut dyn fmt::Write,
+        low: &str,
+        high: &str,
+        span: (usize, usize),
+        width: usize,
+        low_active: bool,
+    ) -> fmt::Result {
+        write!(f, "{} ", low)?;
+        for cell in 0..width {
+            let chr = match cell {
+                cell if cell == span.0 && (low_active || cell != span.1) => {
+                    if low_active {
+                        'O'
+                    } else {
+                        '|'
+                    }
+                }
+                cell if cell == span.1 => {
+                    if low_active {
+                        '|'
+                    } else {
+                        'O'
+                    }
+                }
+                cell if cell > span.0 && cell < span.1 => '=',
+                _ => '-',
+            };
+            write!(f, "{}", chr)?;
+        }
+        write!(f, " {}", high)
+    }
+
+    /// Formats a line of a text area.
+    ///
+    /// `cursor` is the position of the cursor in characters if it's in the line.
+    fn format_text_area_line(
+        &self,
+        f: &mut dyn fmt::Write,
+        line: &str,
+        cursor: Option<usize>,
+    ) -> fmt::Result {
+        match cursor {
+            Some(cursor) => {
+                let (head, tail) = line.split_at(
+                    line.char_indices()
+                        .nth(cursor)
+                        .map_or(line.len(), |(idx, _)| idx),
+                );
+                write!(f, "  {}|{}", head, tail)
+            }
+            None => write!(f, "  {}", line),
+        }
+    }
+
+    /// Formats a text area after selection.
+    ///
+    /// Only the first line of the text is shown, followed by "..." if there are more.
+    #[inline]
+    fn format_text_area_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        text: &str,
+    ) -> fmt::Result {
+        let mut lines = text.lines();
+        let first = lines.next().unwrap_or_default();
+
+        match lines.next() {
+            Some(_) => self.format_input_prompt_selection(f, prompt, &format!("{}...", first)),
+            None => self.format_input_prompt_selection(f, prompt, first),
+        }
+    }
+
+    /// Formats a range slider after selection.
+    #[inline]
+    fn format_range_slider_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        low: &str,
+        high: &str,
+    ) -> fmt::Result {
+        self.format_input_prompt_selection(f, prompt, &format!("{} - {}", low, high))
+    }
+
+    /// Formats the announcement of the highlighted item of a list prompt in accessible mode.
+    ///
+    /// `position` starts at 1 and `checked` is `None` for prompts without checkboxes.
+    fn format_accessible_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        position: usize,
+        count: usize,
+        checked: Option<bool>,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            translate::format(
+                "highlighted",
+                "Highlighted: option {position} of {count}, {text}",
+                &[("position", &position), ("count", &count), ("text", &text)],
+            )
+        )?;
+
+        match checked {
+            Some(true) => write!(f, ", {}", translate::text("checked", "checked")),
+            Some(false) => write!(f, ", {}", translate::text("not-checked", "not checked")),
+            None => Ok(()),
+        }
+    }
+
+    /// Formats the paging info which follows the prompt of a paged list prompt.
+    ///
+    /// `page` starts at 1 and `items` is the number of items in the list.
+    fn format_paging_info(
+        &self,
+        f: &mut dyn fmt::Write,
+        page: usize,
+        pages: usize,
+        _items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            " {} ",
+            translate::format(
+                "page",
+                "[Page {page}/{pages}]",
+                &[("page", &page), ("pages", &pages)]
+            )
+        )
+    }
+
+    /// Formats the position of the cursor which follows the prompt of a list prompt
+    /// scrolled in [`ScrollMode::Window`](crate::ScrollMode::Window).
+    ///
+    /// `item` starts at 1 and `items` is the number of items in the list.
+    fn format_scroll_info(&self, f: &mut dyn fmt::Write, item: usize, items: usize) -> fmt::Result {
+        write!(
+            f,
+            " {} ",
+            translate::format(
+                "item",
+                "[Item {item}/{items}]",
+                &[("item", &item), ("items", &items)]
+            )
+        )
+    }
+
+    /// Formats the filter line of a list prompt.
+    ///
+    /// `active` indicates whether the user is currently typing into the filter.
+    fn format_filter_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        filter: &str,
+        active: bool,
+    ) -> fmt::Result {
+        write!(f, "/{}{}", filter, if active { "|" } else { "" })
+    }
+
+    /// Formats the path of the directory shown by a file picker as breadcrumbs.
+    ///
+    /// `segments` are the names of the directories from the root, `active` is the
+    /// segment the user highlighted to jump to, if any.
+    fn format_breadcrumbs(
+        &self,
+        f: &mut dyn fmt::Write,
+        segments: &[&str],
+        active: Option<usize>,
+    ) -> fmt::Result {
+        for (idx, segment) in segments.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " > ")?;
+            }
+
+            if active == Some(idx) {
+                write!(f, "[{}]", segment)?;
+            } else {
+                write!(f, "{}", segment)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Formats the line which explains the keys of a full screen prompt.
+    fn format_key_help(&self, f: &mut dyn fmt::Write, help: &str) -> fmt::Result {
+        write!(f, "{}", help)
+    }
+
+    /// Formats the line shown while the items of a prompt are loading.
+    fn format_loading_item(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        write!(f, "  {}", translate::text("loading", "Loading..."))
+    }
+
+    /// Formats the line with a spinner shown while the items of a prompt are fetched.
+    ///
+    /// `tick` counts up while the items are fetched, to animate the spinner.
+    fn format_loading_spinner(&self, f: &mut dyn fmt::Write, tick: usize) -> fmt::Result {
+        const FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+        write!(
+            f,
+            "  {} {}",
+            FRAMES[tick % FRAMES.len()],
+            translate::text("loading", "Loading...")
+        )
+    }
+
     /// Formats a fuzzy select prompt item.
+    ///
+    /// Finds the characters which match the search term and formats the item with
+    /// [`format_fuzzy_select_item`](Self::format_fuzzy_select_item), which themes
+    /// usually implement instead.
     #[cfg(feature = "fuzzy-select")]
     fn format_fuzzy_select_prompt_item(
         &self,
@@ -227,23 +574,41 @@ pub trait Theme {
         matcher: &SkimMatcherV2,
         search_term: &str,
     ) -> fmt::Result {
-        write!(f, "{} ", if active { ">" } else { " " })?;
+        let match_indices = if highlight_matches {
+            matcher
+                .fuzzy_indices(text, search_term)
+                .map(|(_score, indices)| indices)
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
 
-        if highlight_matches {
-            if let Some((_score, indices)) = matcher.fuzzy_indices(text, search_term) {
-                for (idx, c) in text.chars().enumerate() {
-                    if indices.contains(&idx) {
-                        write!(f, "{}", style(c).for_stderr().bold())?;
-                    } else {
-                        write!(f, "{}", c)?;
-                    }
-                }
+        self.format_fuzzy_select_item(f, text, &match_indices, active)
+    }
+
+    /// Formats a fuzzy select prompt item, e.g. with the matched characters in bold.
+    ///
+    /// `match_indices` are the indices of the characters which match the search term,
+    /// in ascending order. They are empty if the matches are not highlighted.
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        match_indices: &[usize],
+        active: bool,
+    ) -> fmt::Result {
+        write!(f, "{} ", if active { ">" } else { " " })?;
 
-                return Ok(());
+        for (idx, c) in text.chars().enumerate() {
+            if match_indices.binary_search(&idx).is_ok() {
+                write!(f, "{}", style(c).for_stderr().bold())?;
+            } else {
+                write!(f, "{}", c)?;
             }
         }
 
-        write!(f, "{}", text)
+        Ok(())
     }
 
     /// Formats a fuzzy select prompt.
@@ -262,4 +627,24 @@ pub trait Theme {
         let (st_head, st_tail) = search_term.split_at(bytes_pos);
         write!(f, "{st_head}|{st_tail}")
     }
+
+    /// Formats the number of matching items which follows the search text of a
+    /// fuzzy select prompt.
+    #[cfg(feature = "fuzzy-select")]
+    fn format_fuzzy_select_match_count(
+        &self,
+        f: &mut dyn fmt::Write,
+        matches: usize,
+        items: usize,
+    ) -> fmt::Result {
+        write!(
+            f,
+            " ({})",
+            translate::format(
+                "matches",
+                "{matches}/{items} matches",
+                &[("matches", &matches), ("items", &items)],
+            )
+        )
+    }
 }
@@ -0,0 +1,215 @@
+use console::{style, Color, Style};
+
+use crate::theme::ColorfulTheme;
+
+/// The colors of a preset, as `0xRRGGBB` values of its official palette.
+///
+/// The background is only used for the cursor of a fuzzy select prompt.
+struct Preset {
+    #[cfg(feature = "fuzzy-select")]
+    background: u32,
+    text: u32,
+    hint: u32,
+    accent: u32,
+    prompt: u32,
+    success: u32,
+    error: u32,
+    warning: u32,
+    unchecked: u32,
+    picked: u32,
+}
+
+impl ColorfulTheme {
+    /// Creates a colorful theme with the colors of [Nord](https://www.nordtheme.com).
+    pub fn nord() -> ColorfulTheme {
+        ColorfulTheme::from_preset(&Preset {
+            #[cfg(feature = "fuzzy-select")]
+            background: 0x2e3440,
+            text: 0xd8dee9,
+            hint: 0x616e88,
+            accent: 0x88c0d0,
+            prompt: 0x81a1c1,
+            success: 0xa3be8c,
+            error: 0xbf616a,
+            warning: 0xebcb8b,
+            unchecked: 0xb48ead,
+            picked: 0xd08770,
+        })
+    }
+
+    /// Creates a colorful theme with the dark colors of
+    /// [Solarized](https://ethanschoonover.com/solarized).
+    pub fn solarized_dark() -> ColorfulTheme {
+        ColorfulTheme::from_preset(&Preset {
+            #[cfg(feature = "fuzzy-select")]
+            background: 0x002b36,
+            text: 0x839496,
+            hint: 0x586e75,
+            ..SOLARIZED
+        })
+    }
+
+    /// Creates a colorful theme with the light colors of
+    /// [Solarized](https://ethanschoonover.com/solarized).
+    pub fn solarized_light() -> ColorfulTheme {
+        ColorfulTheme::from_preset(&Preset {
+            #[cfg(feature = "fuzzy-select")]
+            background: 0xfdf6e3,
+            text: 0x657b83,
+            hint: 0x93a1a1,
+            ..SOLARIZED
+        })
+    }
+
+    /// Creates a colorful theme with the colors of [Dracula](https://draculatheme.com).
+    pub fn dracula() -> ColorfulTheme {
+        ColorfulTheme::from_preset(&Preset {
+            #[cfg(feature = "fuzzy-select")]
+            background: 0x282a36,
+            text: 0xf8f8f2,
+            hint: 0x6272a4,
+            accent: 0x8be9fd,
+            prompt: 0xbd93f9,
+            success: 0x50fa7b,
+            error: 0xff5555,
+            warning: 0xf1fa8c,
+            unchecked: 0xff79c6,
+            picked: 0xffb86c,
+        })
+    }
+
+    /// Creates a colorful theme with the colors of the light
+    /// [Catppuccin](https://catppuccin.com) flavor Latte.
+    pub fn catppuccin_latte() -> ColorfulTheme {
+        ColorfulTheme::from_preset(&Preset {
+            #[cfg(feature = "fuzzy-select")]
+            background: 0xeff1f5,
+            text: 0x4c4f69,
+            hint: 0x8c8fa1,
+            accent: 0x1e66f5,
+            prompt: 0x8839ef,
+            success: 0x40a02b,
+            error: 0xd20f39,
+            warning: 0xdf8e1d,
+            unchecked: 0x179299,
+            picked: 0xfe640b,
+        })
+    }
+
+    /// Creates a colorful theme with the colors of the dark
+    /// [Catppuccin](https://catppuccin.com) flavor Mocha.
+    pub fn catppuccin_mocha() -> ColorfulTheme {
+        ColorfulTheme::from_preset(&Preset {
+            #[cfg(feature = "fuzzy-select")]
+            background: 0x1e1e2e,
+            text: 0xcdd6f4,
+            hint: 0x7f849c,
+            accent: 0x89b4fa,
+            prompt: 0xcba6f7,
+            success: 0xa6e3a1,
+            error: 0xf38ba8,
+            warning: 0xf9e2af,
+            unchecked: 0x94e2d5,
+            picked: 0xfab387,
+        })
+    }
+
+    fn from_preset(preset: &Preset) -> ColorfulTheme {
+        let fg = |rgb| Style::new().for_stderr().fg(Color::Color256(ansi256(rgb)));
+        let prefix = |text: &str, rgb| fg(rgb).apply_to(text.to_string());
+
+        ColorfulTheme {
+            defaults_style: fg(preset.accent),
+            prompt_style: fg(preset.text).bold(),
+            prompt_prefix: prefix("?", preset.prompt),
+            prompt_suffix: prefix("›", preset.hint),
+            success_prefix: prefix("✔", preset.success),
+            success_suffix: prefix("·", preset.hint),
+            error_prefix: prefix("✘", preset.error),
+            error_style: fg(preset.error),
+            warning_prefix: prefix("!", preset.warning),
+            warning_style: fg(preset.warning),
+            hint_style: fg(preset.hint),
+            help_style: fg(preset.hint),
+            paging_style: fg(preset.hint),
+            values_style: fg(preset.success),
+            active_item_style: fg(preset.accent),
+            inactive_item_style: fg(preset.text),
+            active_item_prefix: prefix("❯", preset.success),
+            inactive_item_prefix: style(" ".to_string()).for_stderr(),
+            checked_item_prefix: prefix("✔", preset.success),
+            unchecked_item_prefix: prefix("⬚", preset.unchecked),
+            picked_item_prefix: prefix("↕", preset.picked).bold(),
+            unpicked_item_prefix: style(" ".to_string()).for_stderr(),
+            picked_item_style: fg(preset.picked).bold(),
+            #[cfg(feature = "fuzzy-select")]
+            fuzzy_cursor_style: fg(preset.background).bg(Color::Color256(ansi256(preset.text))),
+            #[cfg(feature = "fuzzy-select")]
+            fuzzy_match_highlight_style: fg(preset.accent).bold(),
+        }
+    }
+}
+
+/// The accent colors which both Solarized variants share.
+const SOLARIZED: Preset = Preset {
+    #[cfg(feature = "fuzzy-select")]
+    background: 0,
+    text: 0,
+    hint: 0,
+    accent: 0x268bd2,
+    prompt: 0xb58900,
+    success: 0x859900,
+    error: 0xdc322f,
+    warning: 0xcb4b16,
+    unchecked: 0x6c71c4,
+    picked: 0xd33682,
+};
+
+/// Returns the closest color of the 256 color palette to `rgb`.
+///
+/// Only the color cube and the gray ramp are considered, since the first 16
+/// colors differ between terminals.
+fn ansi256(rgb: u32) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    let channels = [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8];
+    let distance = |other: [u8; 3]| -> u32 {
+        channels
+            .iter()
+            .zip(other)
+            .map(|(&a, b)| (a as i32 - b as i32).pow(2) as u32)
+            .sum()
+    };
+
+    let level = |channel: u8| -> u8 {
+        (0..6u8)
+            .min_by_key(|&idx| (channel as i32 - LEVELS[idx as usize] as i32).abs())
+            .unwrap_or(0)
+    };
+    let [r, g, b] = channels.map(level);
+    let cube = (
+        16 + 36 * r + 6 * g + b,
+        distance([LEVELS[r as usize], LEVELS[g as usize], LEVELS[b as usize]]),
+    );
+
+    let gray = (0..24u8).map(|idx| (232 + idx, distance([8 + 10 * idx; 3])));
+    Some(cube)
+        .into_iter()
+        .chain(gray)
+        .min_by_key(|&(_, distance)| distance)
+        .map_or(cube.0, |(color, _)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ansi256() {
+        assert_eq!(ansi256(0x000000), 16);
+        assert_eq!(ansi256(0xffffff), 231);
+        assert_eq!(ansi256(0xff0000), 196);
+        assert_eq!(ansi256(0x808080), 244);
+        assert_eq!(ansi256(0x2e3440), 237);
+    }
+}
@@ -1,21 +1,45 @@
+//! Renders the elements of a theme to a terminal.
 use std::{fmt, io};
 
 use console::{measure_text_width, Term};
 #[cfg(feature = "fuzzy-select")]
 use fuzzy_matcher::skim::SkimMatcherV2;
 
-use crate::{theme::Theme, Result};
+use crate::{signals, theme::Theme, PagingInfo, Result};
 
 /// Helper struct to conveniently render a theme.
-pub(crate) struct TermThemeRenderer<'a> {
+///
+/// The renderer writes the elements formatted by a [`Theme`] to a terminal and
+/// keeps track of the written lines, so that they can be cleared again. Together
+/// with [`Paging`](crate::Paging) and [`Term::read_key`] it is the building
+/// block of the prompts of this crate and can be used to build custom prompts.
+pub struct TermThemeRenderer<'a> {
     term: &'a Term,
     theme: &'a dyn Theme,
     height: usize,
     prompt_height: usize,
     prompts_reset_height: bool,
+    frame: Option<String>,
+    item_cache: Vec<CachedItem>,
+}
+
+/// An item line formatted by the theme, which is reused as long as the item in
+/// its row and the state of the item don't change. Empty lines are not formatted yet.
+struct CachedItem {
+    text: String,
+    state: ItemState,
+    line: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ItemState {
+    Select { active: bool, pinned: bool },
+    MultiSelect { checked: bool, active: bool },
+    Sort { picked: bool, active: bool },
 }
 
 impl<'a> TermThemeRenderer<'a> {
+    /// Creates a renderer which renders the given theme to the terminal.
     pub fn new(term: &'a Term, theme: &'a dyn Theme) -> TermThemeRenderer<'a> {
         TermThemeRenderer {
             term,
@@ -23,21 +47,67 @@ impl<'a> TermThemeRenderer<'a> {
             height: 0,
             prompt_height: 0,
             prompts_reset_height: true,
+            frame: None,
+            item_cache: Vec::new(),
         }
     }
 
-    #[cfg(feature = "password")]
+    /// Indicates whether rendering a prompt resets the height of the rendered lines.
+    ///
+    /// By default [`clear_preserve_prompt`](Self::clear_preserve_prompt) keeps the last
+    /// rendered prompt. If disabled, everything since the last [`clear`](Self::clear) is
+    /// cleared.
     pub fn set_prompts_reset_height(&mut self, val: bool) {
         self.prompts_reset_height = val;
     }
 
-    #[cfg(feature = "password")]
+    /// Returns the terminal the renderer writes to.
     pub fn term(&self) -> &Term {
         self.term
     }
 
+    /// Counts an additional line, e.g. a line which was written to the terminal
+    /// directly, so that it is cleared by [`clear`](Self::clear).
     pub fn add_line(&mut self) {
         self.height += 1;
+        self.sync_lines();
+    }
+
+    /// Starts a frame.
+    ///
+    /// Until the frame is ended with [`end_frame`](Self::end_frame), rendered
+    /// elements are collected instead of being written to the terminal one by one.
+    /// This avoids that a prompt visibly builds up line by line on slow connections.
+    /// Clearing inside a frame is part of the frame, so that a prompt is cleared and
+    /// rendered again with a single write. Ringing the bell ends the frame.
+    pub fn begin_frame(&mut self) {
+        self.frame.get_or_insert_with(String::new);
+    }
+
+    /// Ends the current frame by writing it to the terminal at once and flushes it.
+    pub fn end_frame(&mut self) -> Result {
+        if let Some(frame) = self.frame.take() {
+            self.term.write_str(&frame)?;
+        }
+        self.term.flush()?;
+        self.sync_lines();
+        Ok(())
+    }
+
+    /// Renders a line formatted by `f`, e.g. an element of a custom prompt.
+    ///
+    /// The closure receives the theme of the renderer, so that the line can be
+    /// composed of the formatted elements of the theme.
+    pub fn formatted_line<F>(&mut self, f: F) -> Result
+    where
+        F: FnOnce(&dyn Theme, &mut dyn fmt::Write) -> fmt::Result,
+    {
+        self.write_formatted_line(|this, buf| f(this.theme, buf))
+    }
+
+    /// Renders a prompt line.
+    pub fn prompt(&mut self, prompt: &str) -> Result {
+        self.write_formatted_prompt(|this, buf| this.theme.format_prompt(buf, prompt))
     }
 
     fn write_formatted_str<
@@ -49,7 +119,7 @@ impl<'a> TermThemeRenderer<'a> {
         let mut buf = String::new();
         f(self, &mut buf).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
         self.height += buf.chars().filter(|&x| x == '\n').count();
-        self.term.write_str(&buf)?;
+        self.write_str(&buf)?;
         Ok(measure_text_width(&buf))
     }
 
@@ -62,7 +132,65 @@ impl<'a> TermThemeRenderer<'a> {
         let mut buf = String::new();
         f(self, &mut buf).map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
         self.height += buf.chars().filter(|&x| x == '\n').count() + 1;
-        Ok(self.term.write_line(&buf)?)
+        buf.push('\n');
+        self.write_str(&buf)
+    }
+
+    /// Like `write_formatted_line`, but only formats the item if the item in the
+    /// row or its state changed since the row was rendered last.
+    fn write_cached_item<F: FnOnce(&dyn Theme, &mut String) -> fmt::Result>(
+        &mut self,
+        text: &str,
+        state: ItemState,
+        f: F,
+    ) -> Result {
+        let row = self.height;
+        if self.item_cache.len() <= row {
+            self.item_cache.resize_with(row + 1, || CachedItem {
+                text: String::new(),
+                state,
+                line: String::new(),
+            });
+        }
+
+        let item = &mut self.item_cache[row];
+        if item.line.is_empty() || item.text != text || item.state != state {
+            item.text.clear();
+            item.text.push_str(text);
+            item.state = state;
+            item.line.clear();
+            if let Err(err) = f(self.theme, &mut item.line) {
+                item.line.clear();
+                return Err(io::Error::new(io::ErrorKind::Other, err).into());
+            }
+            item.line.push('\n');
+        }
+
+        self.height += item.line.chars().filter(|&x| x == '\n').count();
+        match self.frame {
+            Some(ref mut frame) => frame.push_str(&item.line),
+            None => self.term.write_str(&item.line)?,
+        }
+        self.sync_lines();
+        Ok(())
+    }
+
+    fn write_str(&mut self, s: &str) -> Result {
+        match self.frame {
+            Some(ref mut frame) => frame.push_str(s),
+            None => self.term.write_str(s)?,
+        }
+        self.sync_lines();
+        Ok(())
+    }
+
+    /// Records the rendered lines for the signal handler, see
+    /// [`set_cleanup_on_signal`](crate::set_cleanup_on_signal). Lines which are
+    /// collected in a frame are not on the terminal yet.
+    fn sync_lines(&self) {
+        if self.frame.is_none() {
+            signals::rendered_lines(self.height + self.prompt_height);
+        }
     }
 
     fn write_formatted_prompt<
@@ -79,47 +207,79 @@ impl<'a> TermThemeRenderer<'a> {
         Ok(())
     }
 
-    fn write_paging_info(buf: &mut dyn fmt::Write, paging_info: (usize, usize)) -> fmt::Result {
-        write!(buf, " [Page {}/{}] ", paging_info.0, paging_info.1)
-    }
-
+    /// Renders an error on a new line.
     pub fn error(&mut self, err: &str) -> Result {
         self.write_formatted_line(|this, buf| this.theme.format_error(buf, err))
     }
 
+    /// Renders a validation warning on a new line.
+    pub fn warning(&mut self, warning: &str) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_warning(buf, warning))
+    }
+
+    /// Renders a confirm prompt without a line break and returns its width.
     pub fn confirm_prompt(&mut self, prompt: &str, default: Option<bool>) -> Result<usize> {
         self.write_formatted_str(|this, buf| this.theme.format_confirm_prompt(buf, prompt, default))
     }
 
+    /// Renders the selection of a confirm prompt as a prompt line.
     pub fn confirm_prompt_selection(&mut self, prompt: &str, sel: Option<bool>) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_confirm_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders the checkbox of a confirm prompt on a new line below the prompt.
+    pub fn confirm_remember_option(&mut self, label: &str, checked: bool) -> Result {
+        self.write_formatted_str(|this, buf| {
+            writeln!(buf)?;
+            this.theme
+                .format_confirm_remember_option(buf, label, checked)
+        })?;
+        Ok(())
+    }
+
+    /// Renders a fuzzy select prompt with the search term and its cursor position.
     #[cfg(feature = "fuzzy-select")]
     pub fn fuzzy_select_prompt(
         &mut self,
         prompt: &str,
         search_term: &str,
         cursor_pos: usize,
+        match_count: Option<(usize, usize)>,
     ) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme
-                .format_fuzzy_select_prompt(buf, prompt, search_term, cursor_pos)
+                .format_fuzzy_select_prompt(buf, prompt, search_term, cursor_pos)?;
+
+            if let Some((matches, items)) = match_count {
+                this.theme
+                    .format_fuzzy_select_match_count(buf, matches, items)?;
+            }
+
+            Ok(())
         })
     }
 
+    /// Renders an input prompt without a line break and returns its width.
     pub fn input_prompt(&mut self, prompt: &str, default: Option<&str>) -> Result<usize> {
         self.write_formatted_str(|this, buf| this.theme.format_input_prompt(buf, prompt, default))
     }
 
+    /// Renders the suggested continuation of an input after the cursor and returns
+    /// its width.
+    pub fn input_ghost_text(&mut self, text: &str) -> Result<usize> {
+        self.write_formatted_str(|this, buf| this.theme.format_input_ghost_text(buf, text))
+    }
+
+    /// Renders the input of an input prompt as a prompt line.
     pub fn input_prompt_selection(&mut self, prompt: &str, sel: &str) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_input_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders a password prompt without a line break and returns its width.
     #[cfg(feature = "password")]
     pub fn password_prompt(&mut self, prompt: &str) -> Result<usize> {
         self.write_formatted_str(|this, buf| {
@@ -128,6 +288,7 @@ impl<'a> TermThemeRenderer<'a> {
         })
     }
 
+    /// Renders the report of a password prompt as a prompt line.
     #[cfg(feature = "password")]
     pub fn password_prompt_selection(&mut self, prompt: &str) -> Result {
         self.write_formatted_prompt(|this, buf| {
@@ -135,30 +296,58 @@ impl<'a> TermThemeRenderer<'a> {
         })
     }
 
-    pub fn select_prompt(&mut self, prompt: &str, paging_info: Option<(usize, usize)>) -> Result {
+    /// Renders a select prompt with the paging info.
+    pub fn select_prompt(&mut self, prompt: &str, paging_info: Option<PagingInfo>) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_select_prompt(buf, prompt)?;
 
-            if let Some(paging_info) = paging_info {
-                TermThemeRenderer::write_paging_info(buf, paging_info)?;
+            match paging_info {
+                Some(PagingInfo::Page { page, pages, items }) => {
+                    this.theme.format_paging_info(buf, page, pages, items)
+                }
+                Some(PagingInfo::Window { item, items }) => {
+                    this.theme.format_scroll_info(buf, item, items)
+                }
+                None => Ok(()),
             }
-
-            Ok(())
         })
     }
 
+    /// Renders the selection of a select prompt as a prompt line.
     pub fn select_prompt_selection(&mut self, prompt: &str, sel: &str) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_select_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders an item of a select prompt.
     pub fn select_prompt_item(&mut self, text: &str, active: bool) -> Result {
-        self.write_formatted_line(|this, buf| {
-            this.theme.format_select_prompt_item(buf, text, active)
+        let state = ItemState::Select {
+            active,
+            pinned: false,
+        };
+        self.write_cached_item(text, state, |theme, buf| {
+            theme.format_select_prompt_item(buf, text, active)
         })
     }
 
+    /// Renders a pinned item of a select prompt.
+    pub fn select_prompt_pinned_item(&mut self, text: &str, active: bool) -> Result {
+        let state = ItemState::Select {
+            active,
+            pinned: true,
+        };
+        self.write_cached_item(text, state, |theme, buf| {
+            theme.format_select_prompt_pinned_item(buf, text, active)
+        })
+    }
+
+    /// Renders the row of a select prompt which loads more items.
+    pub fn load_more_item(&mut self, active: bool) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_load_more_item(buf, active))
+    }
+
+    /// Renders an item of a fuzzy select prompt, optionally highlighting the matches.
     #[cfg(feature = "fuzzy-select")]
     pub fn fuzzy_select_prompt_item(
         &mut self,
@@ -180,22 +369,54 @@ impl<'a> TermThemeRenderer<'a> {
         })
     }
 
-    pub fn multi_select_prompt(
+    /// Renders the line which explains the keys of a full screen prompt.
+    pub fn key_help(&mut self, help: &str) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_key_help(buf, help))
+    }
+
+    /// Renders the line which indicates that items are loading.
+    #[cfg(feature = "fuzzy-select")]
+    pub fn fuzzy_select_loading(&mut self) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_loading_item(buf))
+    }
+
+    /// Renders the line with a spinner which indicates that items are fetched.
+    pub fn loading_spinner(&mut self, tick: usize) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_loading_spinner(buf, tick))
+    }
+
+    /// Renders an announcement of the highlighted item for screen readers.
+    pub fn accessible_item(
         &mut self,
-        prompt: &str,
-        paging_info: Option<(usize, usize)>,
+        text: &str,
+        position: usize,
+        count: usize,
+        checked: Option<bool>,
     ) -> Result {
+        self.write_formatted_line(|this, buf| {
+            this.theme
+                .format_accessible_item(buf, text, position, count, checked)
+        })
+    }
+
+    /// Renders a multi select prompt with the paging info, see [`select_prompt`](Self::select_prompt).
+    pub fn multi_select_prompt(&mut self, prompt: &str, paging_info: Option<PagingInfo>) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_multi_select_prompt(buf, prompt)?;
 
-            if let Some(paging_info) = paging_info {
-                TermThemeRenderer::write_paging_info(buf, paging_info)?;
+            match paging_info {
+                Some(PagingInfo::Page { page, pages, items }) => {
+                    this.theme.format_paging_info(buf, page, pages, items)
+                }
+                Some(PagingInfo::Window { item, items }) => {
+                    this.theme.format_scroll_info(buf, item, items)
+                }
+                None => Ok(()),
             }
-
-            Ok(())
         })
     }
 
+    /// Renders the selections of a multi select prompt as a prompt line.
     pub fn multi_select_prompt_selection(&mut self, prompt: &str, sel: &[&str]) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme
@@ -203,46 +424,142 @@ impl<'a> TermThemeRenderer<'a> {
         })
     }
 
+    /// Renders an item of a multi select prompt.
     pub fn multi_select_prompt_item(&mut self, text: &str, checked: bool, active: bool) -> Result {
+        let state = ItemState::MultiSelect { checked, active };
+        self.write_cached_item(text, state, |theme, buf| {
+            theme.format_multi_select_prompt_item(buf, text, checked, active)
+        })
+    }
+
+    /// Renders the status line of a multi select prompt.
+    pub fn multi_select_status(&mut self, checked: usize, items: usize) -> Result {
+        self.write_formatted_line(|this, buf| {
+            this.theme.format_multi_select_status(buf, checked, items)
+        })
+    }
+
+    /// Renders the filter line of a list prompt.
+    pub fn filter_prompt(&mut self, filter: &str, active: bool) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_filter_prompt(buf, filter, active))
+    }
+
+    /// Renders the path of the directory shown by a file picker.
+    pub fn breadcrumbs(&mut self, segments: &[&str], active: Option<usize>) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_breadcrumbs(buf, segments, active))
+    }
+
+    /// Renders the description of the active item of a multi select prompt.
+    pub fn multi_select_prompt_item_description(&mut self, description: &str) -> Result {
         self.write_formatted_line(|this, buf| {
             this.theme
-                .format_multi_select_prompt_item(buf, text, checked, active)
+                .format_multi_select_prompt_item_description(buf, description)
         })
     }
 
-    pub fn sort_prompt(&mut self, prompt: &str, paging_info: Option<(usize, usize)>) -> Result {
+    /// Renders a sort prompt with the paging info, see [`select_prompt`](Self::select_prompt).
+    pub fn sort_prompt(&mut self, prompt: &str, paging_info: Option<PagingInfo>) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_sort_prompt(buf, prompt)?;
 
-            if let Some(paging_info) = paging_info {
-                TermThemeRenderer::write_paging_info(buf, paging_info)?;
+            match paging_info {
+                Some(PagingInfo::Page { page, pages, items }) => {
+                    this.theme.format_paging_info(buf, page, pages, items)
+                }
+                Some(PagingInfo::Window { item, items }) => {
+                    this.theme.format_scroll_info(buf, item, items)
+                }
+                None => Ok(()),
             }
-
-            Ok(())
         })
     }
 
+    /// Renders the order of a sort prompt as a prompt line.
     pub fn sort_prompt_selection(&mut self, prompt: &str, sel: &[&str]) -> Result {
         self.write_formatted_prompt(|this, buf| {
             this.theme.format_sort_prompt_selection(buf, prompt, sel)
         })
     }
 
+    /// Renders an answer in the summary of a session with keys aligned to `key_width`.
+    pub fn summary_item(&mut self, key: &str, value: &str, key_width: usize) -> Result {
+        self.write_formatted_line(|this, buf| {
+            this.theme.format_summary_item(buf, key, value, key_width)
+        })
+    }
+
+    /// Renders an item of a sort prompt.
     pub fn sort_prompt_item(&mut self, text: &str, picked: bool, active: bool) -> Result {
+        let state = ItemState::Sort { picked, active };
+        self.write_cached_item(text, state, |theme, buf| {
+            theme.format_sort_prompt_item(buf, text, picked, active)
+        })
+    }
+
+    /// Renders the position typed to move the picked item of a sort prompt to.
+    pub fn sort_prompt_position(&mut self, position: &str, items: usize) -> Result {
+        self.write_formatted_line(|this, buf| {
+            this.theme.format_sort_prompt_position(buf, position, items)
+        })
+    }
+
+    /// Renders a line of a text area with the cursor, if it's in the line.
+    pub fn text_area_line(&mut self, line: &str, cursor: Option<usize>) -> Result {
+        self.write_formatted_line(|this, buf| this.theme.format_text_area_line(buf, line, cursor))
+    }
+
+    /// Renders the text of a text area as a prompt line.
+    pub fn text_area_prompt_selection(&mut self, prompt: &str, text: &str) -> Result {
+        self.write_formatted_prompt(|this, buf| {
+            this.theme
+                .format_text_area_prompt_selection(buf, prompt, text)
+        })
+    }
+
+    /// Renders the bar of a range slider.
+    pub fn range_slider_bar(
+        &mut self,
+        low: &str,
+        high: &str,
+        span: (usize, usize),
+        width: usize,
+        low_active: bool,
+    ) -> Result {
         self.write_formatted_line(|this, buf| {
             this.theme
-                .format_sort_prompt_item(buf, text, picked, active)
+                .format_range_slider_bar(buf, low, high, span, width, low_active)
         })
     }
 
+    /// Renders the selected range of a range slider as a prompt line.
+    pub fn range_slider_prompt_selection(&mut self, prompt: &str, low: &str, high: &str) -> Result {
+        self.write_formatted_prompt(|this, buf| {
+            this.theme
+                .format_range_slider_prompt_selection(buf, prompt, low, high)
+        })
+    }
+
+    /// Rings the terminal bell.
+    pub fn bell(&mut self) -> Result {
+        self.end_frame()?;
+        self.term.write_str("\x07")?;
+        self.term.flush()?;
+        Ok(())
+    }
+
+    /// Clears all lines which were rendered since the last clear, including the prompt.
     pub fn clear(&mut self) -> Result {
-        self.term
-            .clear_last_lines(self.height + self.prompt_height)?;
+        self.clear_last_lines(self.height + self.prompt_height)?;
         self.height = 0;
         self.prompt_height = 0;
+        self.sync_lines();
         Ok(())
     }
 
+    /// Clears the lines which were rendered since the last prompt.
+    ///
+    /// `size_vec` contains the widths of the rendered items, which is used to clear
+    /// lines which wrapped because they are wider than the terminal.
     pub fn clear_preserve_prompt(&mut self, size_vec: &[usize]) -> Result {
         let mut new_height = self.height;
         let prefix_width = 2;
@@ -255,8 +572,27 @@ impl<'a> TermThemeRenderer<'a> {
             }
         }
 
-        self.term.clear_last_lines(new_height)?;
+        self.clear_last_lines(new_height)?;
         self.height = 0;
+        self.sync_lines();
+        Ok(())
+    }
+
+    /// Clears the last `n` lines, as part of the current frame if there is one.
+    fn clear_last_lines(&mut self, n: usize) -> Result {
+        match self.frame {
+            // Moving the cursor and clearing the rest of the screen is what
+            // `Term::clear_last_lines` does line by line.
+            Some(ref mut frame) if cfg!(unix) || self.term.features().colors_supported() => {
+                if n > 0 {
+                    frame.push_str(&format!("\x1b[{}A\r\x1b[0J", n));
+                }
+            }
+            _ => {
+                self.end_frame()?;
+                self.term.clear_last_lines(n)?;
+            }
+        }
         Ok(())
     }
 }
@@ -0,0 +1,508 @@
+use std::{collections::HashMap, fmt, mem};
+
+use console::{measure_text_width, Style};
+
+use crate::{theme::Theme, translate};
+
+/// A theme driven by template strings.
+///
+/// Every template is parsed once when it is set. Placeholders are written as
+/// `{name}` and can be styled with a dotted [`Style`] string like `{name:cyan.bold}`.
+/// Literal braces are escaped by doubling them (`{{` and `}}`). Besides the values
+/// listed for each template, placeholders can refer to variables set with
+/// [`var`](Self::var). Unknown placeholders are rendered as empty text.
+///
+/// Formatting that has no template falls back to the defaults of [`Theme`].
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{theme::TemplateTheme, Input};
+///
+/// fn main() {
+///     let theme = TemplateTheme::new()
+///         .var("prefix", "?")
+///         .input_prompt("{prefix:yellow} {prompt:bold} ")
+///         .input_prompt_with_default("{prefix:yellow} {prompt:bold} {default:dim} ")
+///         .input_prompt_selection("{prompt:bold} {selection:green}");
+///
+///     let name: String = Input::with_theme(&theme)
+///         .with_prompt("Your name")
+///         .interact_text()
+///         .unwrap();
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TemplateTheme {
+    vars: HashMap<String, String>,
+    prompt: Template,
+    error: Template,
+    warning: Template,
+    confirm_prompt: Template,
+    confirm_prompt_selection: Template,
+    input_prompt: Template,
+    input_prompt_with_default: Template,
+    input_prompt_selection: Template,
+    select_item: Template,
+    active_select_item: Template,
+    multi_select_item: Template,
+    checked_multi_select_item: Template,
+    active_multi_select_item: Template,
+    active_checked_multi_select_item: Template,
+    multi_select_prompt_selection: Template,
+    summary_item: Template,
+    paging_info: Template,
+    scroll_info: Template,
+}
+
+impl Default for TemplateTheme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemplateTheme {
+    /// Creates a template theme which renders like [`SimpleTheme`](crate::theme::SimpleTheme).
+    pub fn new() -> Self {
+        Self {
+            vars: HashMap::new(),
+            prompt: Template::parse("{prompt}:"),
+            error: Template::parse("error: {error}"),
+            warning: Template::parse("warning: {warning} (press Enter to continue)"),
+            confirm_prompt: Template::parse("{prompt} {choices} "),
+            confirm_prompt_selection: Template::parse("{prompt} {selection}"),
+            input_prompt: Template::parse("{prompt}: "),
+            input_prompt_with_default: Template::parse("{prompt} [{default}]: "),
+            input_prompt_selection: Template::parse("{prompt}: {selection}"),
+            select_item: Template::parse("  {item}"),
+            active_select_item: Template::parse("> {item}"),
+            multi_select_item: Template::parse("  [ ] {item}"),
+            checked_multi_select_item: Template::parse("  [x] {item}"),
+            active_multi_select_item: Template::parse("> [ ] {item}"),
+            active_checked_multi_select_item: Template::parse("> [x] {item}"),
+            multi_select_prompt_selection: Template::parse("{prompt}: {selections}"),
+            summary_item: Template::parse("{padding}{key}: {value}"),
+            paging_info: Template::parse(" [Page {page}/{pages}] "),
+            scroll_info: Template::parse(" [Item {item}/{items}] "),
+        }
+    }
+
+    /// Sets a variable which can be used as placeholder in all templates.
+    pub fn var<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.vars.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the template for prompts, e.g. of select prompts.
+    ///
+    /// Placeholders: `{prompt}`
+    pub fn prompt(mut self, template: &str) -> Self {
+        self.prompt = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for errors.
+    ///
+    /// Placeholders: `{error}`
+    pub fn error(mut self, template: &str) -> Self {
+        self.error = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for validation warnings.
+    ///
+    /// Placeholders: `{warning}`
+    pub fn warning(mut self, template: &str) -> Self {
+        self.warning = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for confirm prompts.
+    ///
+    /// Placeholders: `{prompt}`, `{choices}` (like `[y/n]`)
+    pub fn confirm_prompt(mut self, template: &str) -> Self {
+        self.confirm_prompt = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for confirm prompts after selection.
+    ///
+    /// Placeholders: `{prompt}`, `{selection}` (`yes` or `no`)
+    pub fn confirm_prompt_selection(mut self, template: &str) -> Self {
+        self.confirm_prompt_selection = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for input prompts without a default.
+    ///
+    /// Placeholders: `{prompt}`
+    pub fn input_prompt(mut self, template: &str) -> Self {
+        self.input_prompt = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for input prompts with a default.
+    ///
+    /// Placeholders: `{prompt}`, `{default}`
+    pub fn input_prompt_with_default(mut self, template: &str) -> Self {
+        self.input_prompt_with_default = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for input and select prompts after selection.
+    ///
+    /// Placeholders: `{prompt}`, `{selection}`
+    pub fn input_prompt_selection(mut self, template: &str) -> Self {
+        self.input_prompt_selection = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for select prompt items.
+    ///
+    /// Placeholders: `{item}`
+    pub fn select_item(mut self, template: &str) -> Self {
+        self.select_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for the active select prompt item.
+    ///
+    /// Placeholders: `{item}`
+    pub fn active_select_item(mut self, template: &str) -> Self {
+        self.active_select_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for unchecked multi select prompt items.
+    ///
+    /// Placeholders: `{item}`
+    pub fn multi_select_item(mut self, template: &str) -> Self {
+        self.multi_select_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for checked multi select prompt items.
+    ///
+    /// Placeholders: `{item}`
+    pub fn checked_multi_select_item(mut self, template: &str) -> Self {
+        self.checked_multi_select_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for the active multi select prompt item while unchecked.
+    ///
+    /// Placeholders: `{item}`
+    pub fn active_multi_select_item(mut self, template: &str) -> Self {
+        self.active_multi_select_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for the active multi select prompt item while checked.
+    ///
+    /// Placeholders: `{item}`
+    pub fn active_checked_multi_select_item(mut self, template: &str) -> Self {
+        self.active_checked_multi_select_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for multi select and sort prompts after selection.
+    ///
+    /// Placeholders: `{prompt}`, `{selections}` (separated by commas)
+    pub fn multi_select_prompt_selection(mut self, template: &str) -> Self {
+        self.multi_select_prompt_selection = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for answers in the summary of a session.
+    ///
+    /// Placeholders: `{key}`, `{value}`, `{padding}` (spaces which align the keys)
+    pub fn summary_item(mut self, template: &str) -> Self {
+        self.summary_item = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for the paging info following the prompt of paged list prompts.
+    ///
+    /// Placeholders: `{page}`, `{pages}`, `{items}`
+    pub fn paging_info(mut self, template: &str) -> Self {
+        self.paging_info = Template::parse(template);
+        self
+    }
+
+    /// Sets the template for the position of the cursor following the prompt of
+    /// list prompts scrolled in [`ScrollMode::Window`](crate::ScrollMode::Window).
+    ///
+    /// Placeholders: `{item}`, `{items}`
+    pub fn scroll_info(mut self, template: &str) -> Self {
+        self.scroll_info = Template::parse(template);
+        self
+    }
+}
+
+impl Theme for TemplateTheme {
+    fn format_prompt(&self, f: &mut dyn fmt::Write, prompt: &str) -> fmt::Result {
+        self.prompt.render(f, &self.vars, &[("prompt", prompt)])
+    }
+
+    fn format_error(&self, f: &mut dyn fmt::Write, err: &str) -> fmt::Result {
+        self.error.render(f, &self.vars, &[("error", err)])
+    }
+
+    fn format_warning(&self, f: &mut dyn fmt::Write, warning: &str) -> fmt::Result {
+        self.warning.render(f, &self.vars, &[("warning", warning)])
+    }
+
+    fn format_confirm_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        default: Option<bool>,
+    ) -> fmt::Result {
+        let choices = match default {
+            None => "[y/n]",
+            Some(true) => "[Y/n]",
+            Some(false) => "[y/N]",
+        };
+
+        self.confirm_prompt
+            .render(f, &self.vars, &[("prompt", prompt), ("choices", choices)])
+    }
+
+    fn format_confirm_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        selection: Option<bool>,
+    ) -> fmt::Result {
+        let selection = match selection {
+            Some(true) => translate::text("yes", "yes"),
+            Some(false) => translate::text("no", "no"),
+            None => "".into(),
+        };
+
+        self.confirm_prompt_selection.render(
+            f,
+            &self.vars,
+            &[("prompt", prompt), ("selection", &selection)],
+        )
+    }
+
+    fn format_input_prompt(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        default: Option<&str>,
+    ) -> fmt::Result {
+        match default {
+            Some(default) => self.input_prompt_with_default.render(
+                f,
+                &self.vars,
+                &[("prompt", prompt), ("default", default)],
+            ),
+            None => self
+                .input_prompt
+                .render(f, &self.vars, &[("prompt", prompt)]),
+        }
+    }
+
+    fn format_input_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        sel: &str,
+    ) -> fmt::Result {
+        self.input_prompt_selection
+            .render(f, &self.vars, &[("prompt", prompt), ("selection", sel)])
+    }
+
+    fn format_multi_select_prompt_selection(
+        &self,
+        f: &mut dyn fmt::Write,
+        prompt: &str,
+        selections: &[&str],
+    ) -> fmt::Result {
+        self.multi_select_prompt_selection.render(
+            f,
+            &self.vars,
+            &[("prompt", prompt), ("selections", &selections.join(", "))],
+        )
+    }
+
+    fn format_summary_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        key: &str,
+        value: &str,
+        key_width: usize,
+    ) -> fmt::Result {
+        let padding = " ".repeat(key_width.saturating_sub(measure_text_width(key)));
+
+        self.summary_item.render(
+            f,
+            &self.vars,
+            &[("key", key), ("value", value), ("padding", &padding)],
+        )
+    }
+
+    fn format_paging_info(
+        &self,
+        f: &mut dyn fmt::Write,
+        page: usize,
+        pages: usize,
+        items: usize,
+    ) -> fmt::Result {
+        self.paging_info.render(
+            f,
+            &self.vars,
+            &[
+                ("page", &page.to_string()),
+                ("pages", &pages.to_string()),
+                ("items", &items.to_string()),
+            ],
+        )
+    }
+
+    fn format_scroll_info(&self, f: &mut dyn fmt::Write, item: usize, items: usize) -> fmt::Result {
+        self.scroll_info.render(
+            f,
+            &self.vars,
+            &[("item", &item.to_string()), ("items", &items.to_string())],
+        )
+    }
+
+    fn format_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        active: bool,
+    ) -> fmt::Result {
+        let template = if active {
+            &self.active_select_item
+        } else {
+            &self.select_item
+        };
+
+        template.render(f, &self.vars, &[("item", text)])
+    }
+
+    fn format_multi_select_prompt_item(
+        &self,
+        f: &mut dyn fmt::Write,
+        text: &str,
+        checked: bool,
+        active: bool,
+    ) -> fmt::Result {
+        let template = match (checked, active) {
+            (true, true) => &self.active_checked_multi_select_item,
+            (true, false) => &self.checked_multi_select_item,
+            (false, true) => &self.active_multi_select_item,
+            (false, false) => &self.multi_select_item,
+        };
+
+        template.render(f, &self.vars, &[("item", text)])
+    }
+}
+
+/// A parsed template string.
+#[derive(Clone)]
+struct Template {
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone)]
+enum Segment {
+    Text(String),
+    Placeholder { name: String, style: Option<Style> },
+}
+
+impl Template {
+    fn parse(template: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+
+        while let Some(pos) = rest.find(['{', '}']) {
+            text.push_str(&rest[..pos]);
+            let tail = &rest[pos..];
+
+            if tail.starts_with("{{") || tail.starts_with("}}") {
+                text.push_str(&tail[..1]);
+                rest = &tail[2..];
+            } else if let (true, Some(end)) = (tail.starts_with('{'), tail.find('}')) {
+                if !text.is_empty() {
+                    segments.push(Segment::Text(mem::take(&mut text)));
+                }
+
+                let placeholder = &tail[1..end];
+                segments.push(match placeholder.split_once(':') {
+                    Some((name, style)) => Segment::Placeholder {
+                        name: name.trim().to_string(),
+                        style: Some(Style::from_dotted_str(style.trim()).for_stderr()),
+                    },
+                    None => Segment::Placeholder {
+                        name: placeholder.trim().to_string(),
+                        style: None,
+                    },
+                });
+                rest = &tail[end + 1..];
+            } else {
+                // A brace without counterpart is kept as it is
+                text.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+
+        text.push_str(rest);
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        Template { segments }
+    }
+
+    fn render(
+        &self,
+        f: &mut dyn fmt::Write,
+        vars: &HashMap<String, String>,
+        values: &[(&str, &str)],
+    ) -> fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => f.write_str(text)?,
+                Segment::Placeholder { name, style } => {
+                    let value = values
+                        .iter()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| *value)
+                        .or_else(|| vars.get(name).map(String::as_str))
+                        .unwrap_or("");
+
+                    match style {
+                        Some(style) => write!(f, "{}", style.apply_to(value))?,
+                        None => f.write_str(value)?,
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let vars = HashMap::from([("prefix".to_string(), "?".to_string())]);
+        let template = Template::parse("{prefix} {prompt} {{{missing}}} {unclosed");
+
+        let mut buf = String::new();
+        template
+            .render(&mut buf, &vars, &[("prompt", "Name")])
+            .unwrap();
+
+        assert_eq!(buf, "? Name {} {unclosed");
+    }
+}
@@ -0,0 +1,62 @@
+//! Emits [`tracing`](https://docs.rs/tracing) spans and events for the lifecycle of
+//! prompts if the `tracing` feature is enabled, and nothing otherwise.
+#![cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+
+use console::Key;
+
+use crate::Result;
+
+/// The span of a prompt, which is left when it is dropped.
+pub(crate) struct Span {
+    #[cfg(feature = "tracing")]
+    _entered: tracing::span::EnteredSpan,
+}
+
+/// Enters the span of a prompt and emits an event that it is shown.
+pub(crate) fn shown(kind: &'static str, prompt: Option<&str>) -> Span {
+    #[cfg(feature = "tracing")]
+    {
+        let entered = tracing::info_span!("prompt", kind, prompt).entered();
+        tracing::info!("prompt shown");
+
+        Span { _entered: entered }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    Span {}
+}
+
+/// Emits an event for a key press. Characters are redacted for secret inputs.
+pub(crate) fn key_pressed(key: &Key, redact: bool) {
+    #[cfg(feature = "tracing")]
+    match key {
+        Key::Char(_) if redact => tracing::debug!(key = "[redacted]", "key pressed"),
+        key => tracing::debug!(key = ?key, "key pressed"),
+    }
+}
+
+/// Emits an event for an answer which the validator rejected.
+pub(crate) fn validation_failed(err: &str) {
+    #[cfg(feature = "tracing")]
+    tracing::info!(error = err, "validation failed");
+}
+
+/// Emits an event for the outcome of a prompt and passes the outcome on.
+///
+/// `describe` returns the text of an answer, or `None` if it must be redacted.
+pub(crate) fn answered<T, F>(outcome: Result<Option<T>>, describe: F) -> Result<Option<T>>
+where
+    F: FnOnce(&T) -> Option<String>,
+{
+    #[cfg(feature = "tracing")]
+    match outcome {
+        Ok(Some(ref answer)) => match describe(answer) {
+            Some(answer) => tracing::info!(answer, "prompt answered"),
+            None => tracing::info!(redacted = true, "prompt answered"),
+        },
+        Ok(None) => tracing::info!("prompt cancelled"),
+        Err(ref err) => tracing::info!(error = %err, "prompt failed"),
+    }
+
+    outcome
+}
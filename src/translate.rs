@@ -0,0 +1,138 @@
+use std::{borrow::Cow, fmt, sync::RwLock};
+
+static TRANSLATOR: RwLock<Option<Box<dyn Translator + Send + Sync>>> = RwLock::new(None);
+
+/// Translates the built-in texts of the themes and prompts.
+///
+/// The texts are looked up by key, so applications can plug in their own
+/// translation system, e.g. fluent or gettext. Keys which are not translated
+/// keep the English text. Placeholders in braces like `{page}` are replaced with
+/// their values, so translations have to keep them. The keys are:
+///
+/// * `yes`, `no`: the answers of confirm prompts
+/// * `press-enter`: the hint to accept a warning, "(press Enter to continue)"
+/// * `hidden`: the report of a password, "\[hidden\]"
+/// * `use-saved-password`, `save-password`: the questions of a password prompt
+///   [with a keyring](crate::Password::with_keyring)
+/// * `load-more`: the row of a select prompt which loads more items
+/// * `loading`: the row shown while items are loading
+/// * `checked`, `not-checked`: the state of an item in accessible mode
+/// * `highlighted`: the announcement of the highlighted item in accessible mode,
+///   "Highlighted: option {position} of {count}, {text}"
+/// * `page`, `item`: the paging info "\[Page {page}/{pages}\]" and the scroll info
+///   "\[Item {item}/{items}\]" of list prompts
+/// * `selected-items`: the status of a multi select prompt, "{checked} selected / {items} items"
+/// * `matches`: the number of matching items of a fuzzy select prompt, "{matches}/{items} matches"
+/// * `help-select`, `help-multi-select`, `help-fuzzy-select`: the keys of full screen prompts
+/// * `select-at-least-one`: the error of a [`required`](crate::MultiSelect::required) multi select prompt
+/// * `help-text-area`, `help-text-area-cancel`: the keys of a [`TextArea`](crate::TextArea),
+///   without and with cancelling
+/// * `move-to`: the position typed to move the picked item of a [`Sort`](crate::Sort) prompt to
+/// * `filter`: the prompt for the glob which filters the files of a [`FilePicker`](crate::FilePicker::filter)
+/// * `new-file`, `file-name`, `overwrite`, `invalid-file-name`: the row to enter a new file, the
+///   prompt for its name, the question whether to overwrite a file and the error for an invalid name
+///   of a [`FilePicker`](crate::FilePicker::save) in save mode
+/// * `password-too-short`, `password-lowercase`, `password-uppercase`, `password-digit`,
+///   `password-symbol`, `password-whitespace`, `password-common`: the errors of a
+///   `PasswordPolicy`, e.g. "Password must be at least {len} characters long"
+///
+/// Closures taking the key are translators as well.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{set_translator, Confirm};
+///
+/// fn main() {
+///     set_translator(|key: &str| match key {
+///         "yes" => Some("ja".to_string()),
+///         "no" => Some("nein".to_string()),
+///         _ => None,
+///     });
+///
+///     let proceed = Confirm::new().with_prompt("Fortfahren?").interact().unwrap();
+/// }
+/// ```
+pub trait Translator {
+    /// Returns the translation of the text for `key`, or `None` to keep the English text.
+    fn translate(&self, key: &str) -> Option<String>;
+}
+
+impl<F> Translator for F
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn translate(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
+
+/// Sets the translator for the built-in texts of all prompts.
+pub fn set_translator<T: Translator + Send + Sync + 'static>(translator: T) {
+    *TRANSLATOR.write().unwrap() = Some(Box::new(translator));
+}
+
+/// Returns the translation of the text for `key`, or the English text.
+pub(crate) fn text(key: &str, english: &'static str) -> Cow<'static, str> {
+    TRANSLATOR
+        .read()
+        .unwrap()
+        .as_ref()
+        .and_then(|translator| translator.translate(key))
+        .map_or(Cow::Borrowed(english), Cow::Owned)
+}
+
+/// Returns the translation of the text for `key`, or the English text, with its
+/// placeholders replaced by the values of `args`.
+pub(crate) fn format(
+    key: &str,
+    english: &'static str,
+    args: &[(&str, &dyn fmt::Display)],
+) -> String {
+    let text = text(key, english);
+    let mut formatted = String::with_capacity(text.len());
+    let mut rest = &text[..];
+
+    // Values are inserted as they are, even if they contain braces themselves
+    while let Some(start) = rest.find('{') {
+        formatted.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest.find('}').and_then(|end| {
+            let (_, value) = args.iter().find(|(name, _)| *name == &rest[1..end])?;
+            Some((end, value))
+        });
+
+        match value {
+            Some((end, value)) => {
+                formatted.push_str(&value.to_string());
+                rest = &rest[end + 1..];
+            }
+            None => {
+                formatted.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    formatted.push_str(rest);
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let text = format(
+            "page",
+            "[Page {page}/{pages}]",
+            &[("page", &2), ("pages", &5)],
+        );
+        assert_eq!(text, "[Page 2/5]");
+
+        let text = format("highlighted", "{text} {unknown}", &[("text", &"{text}")]);
+        assert_eq!(text, "{text} {unknown}");
+    }
+}
@@ -1,10 +1,16 @@
-//! Provides validation for text inputs
+//! Provides validation for text inputs and selections
+
+#[cfg(feature = "password")]
+use crate::translate;
 
 /// Trait for input validators.
 ///
 /// A generic implementation for `Fn(&str) -> Result<(), E>` is provided
 /// to facilitate development.
-pub trait InputValidator<T> {
+///
+/// Selection prompts use it to validate the chosen index, or the indices
+/// for prompts with multiple choices.
+pub trait InputValidator<T: ?Sized> {
     type Err;
 
     /// Invoked with the value to validate.
@@ -14,7 +20,7 @@ pub trait InputValidator<T> {
     fn validate(&mut self, input: &T) -> Result<(), Self::Err>;
 }
 
-impl<T, F, E> InputValidator<T> for F
+impl<T: ?Sized, F, E> InputValidator<T> for F
 where
     F: FnMut(&T) -> Result<(), E>,
 {
@@ -47,3 +53,376 @@ where
         self(input)
     }
 }
+
+/// Validators which are combined by [`all_of`] and [`any_of`].
+///
+/// Implemented for tuples of up to six validators, which may be of different types,
+/// and for vectors and arrays of validators of the same type.
+pub trait ValidatorSet<T: ?Sized> {
+    /// Invokes every validator with the value and returns the error of each, or
+    /// `None` if it accepted the value.
+    fn errors(&mut self, input: &T) -> Vec<Option<String>>;
+}
+
+impl<T: ?Sized, V> ValidatorSet<T> for Vec<V>
+where
+    V: InputValidator<T>,
+    V::Err: ToString,
+{
+    fn errors(&mut self, input: &T) -> Vec<Option<String>> {
+        self.iter_mut()
+            .map(|validator| validator.validate(input).err().map(|err| err.to_string()))
+            .collect()
+    }
+}
+
+impl<T: ?Sized, V, const N: usize> ValidatorSet<T> for [V; N]
+where
+    V: InputValidator<T>,
+    V::Err: ToString,
+{
+    fn errors(&mut self, input: &T) -> Vec<Option<String>> {
+        self.iter_mut()
+            .map(|validator| validator.validate(input).err().map(|err| err.to_string()))
+            .collect()
+    }
+}
+
+macro_rules! impl_validator_set {
+    ($($name:ident $idx:tt),+) => {
+        impl<T: ?Sized, $($name),+> ValidatorSet<T> for ($($name,)+)
+        where
+            $($name: InputValidator<T>, $name::Err: ToString,)+
+        {
+            fn errors(&mut self, input: &T) -> Vec<Option<String>> {
+                vec![$(self.$idx.validate(input).err().map(|err| err.to_string())),+]
+            }
+        }
+    };
+}
+
+impl_validator_set!(A 0);
+impl_validator_set!(A 0, B 1);
+impl_validator_set!(A 0, B 1, C 2);
+impl_validator_set!(A 0, B 1, C 2, D 3);
+impl_validator_set!(A 0, B 1, C 2, D 3, E 4);
+impl_validator_set!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Combines validators which must all accept a value.
+///
+/// Unlike calling `validate_with` several times, every validator is invoked and
+/// the errors of all rejecting validators are joined with "; ".
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{all_of, Input};
+///
+/// fn main() {
+///     let name: String = Input::new()
+///         .with_prompt("Crate name")
+///         .validate_with(all_of((
+///             |input: &String| if input.len() <= 64 {
+///                 Ok(())
+///             } else {
+///                 Err("must be at most 64 characters")
+///             },
+///             |input: &String| if input.contains(' ') {
+///                 Err("must not contain spaces")
+///             } else {
+///                 Ok(())
+///             },
+///         )))
+///         .interact_text()
+///         .unwrap();
+/// }
+/// ```
+pub fn all_of<T: ?Sized, S: ValidatorSet<T>>(
+    mut validators: S,
+) -> impl InputValidator<T, Err = String> {
+    move |input: &T| {
+        let errors: Vec<String> = validators.errors(input).into_iter().flatten().collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+}
+
+/// Combines validators of which at least one must accept a value.
+///
+/// If all validators reject the value, their errors are joined with "; ".
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{any_of, Input};
+///
+/// fn main() {
+///     let port: String = Input::new()
+///         .with_prompt("Port")
+///         .validate_with(any_of((
+///             |input: &String| input.parse::<u16>().map(|_| ()).map_err(|_| "not a port"),
+///             |input: &String| if input == "auto" {
+///                 Ok(())
+///             } else {
+///                 Err("not 'auto'")
+///             },
+///         )))
+///         .interact_text()
+///         .unwrap();
+/// }
+/// ```
+pub fn any_of<T: ?Sized, S: ValidatorSet<T>>(
+    mut validators: S,
+) -> impl InputValidator<T, Err = String> {
+    move |input: &T| {
+        let errors = validators.errors(input);
+
+        if errors.iter().any(Option::is_none) {
+            Ok(())
+        } else {
+            Err(errors.into_iter().flatten().collect::<Vec<_>>().join("; "))
+        }
+    }
+}
+
+/// Inverts a validator, which rejects the values it accepts with `message`.
+pub fn not<T: ?Sized, V, S>(mut validator: V, message: S) -> impl InputValidator<T, Err = String>
+where
+    V: InputValidator<T>,
+    S: Into<String>,
+{
+    let message = message.into();
+
+    move |input: &T| match validator.validate(input) {
+        Ok(()) => Err(message.clone()),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Skips a validator if the text is empty, e.g. for inputs which
+/// [allow empty input](crate::Input::allow_empty).
+pub fn optional<T, V>(mut validator: V) -> impl InputValidator<T, Err = V::Err>
+where
+    T: AsRef<str> + ?Sized,
+    V: InputValidator<T>,
+{
+    move |input: &T| {
+        if input.as_ref().is_empty() {
+            Ok(())
+        } else {
+            validator.validate(input)
+        }
+    }
+}
+
+/// A password policy which checks the strength of passwords.
+///
+/// The checks are combined with the builder methods and passed to
+/// [`Password::policy`](crate::Password::policy). A password which violates the policy
+/// is rejected with a message describing the first violated check, which is rendered
+/// like any other validation error.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use dialoguer::{Password, PasswordPolicy};
+///
+/// fn main() {
+///     let password = Password::new()
+///         .with_prompt("New password")
+///         .policy(
+///             PasswordPolicy::new()
+///                 .min_len(12)
+///                 .require_digit(true)
+///                 .deny(["password1234", "123456789012"]),
+///         )
+///         .interact()
+///         .unwrap();
+/// }
+/// ```
+#[cfg(feature = "password")]
+#[derive(Clone, Debug, Default)]
+pub struct PasswordPolicy {
+    min_len: usize,
+    lowercase: bool,
+    uppercase: bool,
+    digit: bool,
+    symbol: bool,
+    deny_whitespace: bool,
+    deny_list: Vec<String>,
+}
+
+#[cfg(feature = "password")]
+impl PasswordPolicy {
+    /// Creates a policy which accepts any password.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum number of characters.
+    pub fn min_len(mut self, len: usize) -> Self {
+        self.min_len = len;
+        self
+    }
+
+    /// Indicates whether a lowercase letter is required.
+    pub fn require_lowercase(mut self, val: bool) -> Self {
+        self.lowercase = val;
+        self
+    }
+
+    /// Indicates whether an uppercase letter is required.
+    pub fn require_uppercase(mut self, val: bool) -> Self {
+        self.uppercase = val;
+        self
+    }
+
+    /// Indicates whether a digit is required.
+    pub fn require_digit(mut self, val: bool) -> Self {
+        self.digit = val;
+        self
+    }
+
+    /// Indicates whether a symbol, i.e. a character which is neither a letter, a
+    /// digit nor whitespace, is required.
+    pub fn require_symbol(mut self, val: bool) -> Self {
+        self.symbol = val;
+        self
+    }
+
+    /// Indicates whether whitespace is rejected.
+    pub fn deny_whitespace(mut self, val: bool) -> Self {
+        self.deny_whitespace = val;
+        self
+    }
+
+    /// Adds passwords which are rejected, e.g. commonly used ones.
+    ///
+    /// The passwords are compared ignoring case.
+    pub fn deny<I, S>(mut self, passwords: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.deny_list.extend(
+            passwords
+                .into_iter()
+                .map(|password| password.into().to_lowercase()),
+        );
+        self
+    }
+}
+
+#[cfg(feature = "password")]
+impl PasswordValidator for PasswordPolicy {
+    type Err = String;
+
+    fn validate(&self, input: &String) -> Result<(), String> {
+        let contains = |class: fn(char) -> bool| input.chars().any(class);
+
+        let violation = if input.chars().count() < self.min_len {
+            translate::format(
+                "password-too-short",
+                "Password must be at least {len} characters long",
+                &[("len", &self.min_len)],
+            )
+        } else if self.lowercase && !contains(char::is_lowercase) {
+            translate::text(
+                "password-lowercase",
+                "Password must contain a lowercase letter",
+            )
+            .into()
+        } else if self.uppercase && !contains(char::is_uppercase) {
+            translate::text(
+                "password-uppercase",
+                "Password must contain an uppercase letter",
+            )
+            .into()
+        } else if self.digit && !contains(char::is_numeric) {
+            translate::text("password-digit", "Password must contain a digit").into()
+        } else if self.symbol && !contains(|chr| !chr.is_alphanumeric() && !chr.is_whitespace()) {
+            translate::text("password-symbol", "Password must contain a symbol").into()
+        } else if self.deny_whitespace && contains(char::is_whitespace) {
+            translate::text(
+                "password-whitespace",
+                "Password must not contain whitespace",
+            )
+            .into()
+        } else if self.deny_list.contains(&input.to_lowercase()) {
+            translate::text("password-common", "Password is too common").into()
+        } else {
+            return Ok(());
+        };
+
+        Err(violation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn min_len(len: usize) -> impl FnMut(&str) -> Result<(), String> {
+        move |input: &str| {
+            if input.len() >= len {
+                Ok(())
+            } else {
+                Err(format!("shorter than {}", len))
+            }
+        }
+    }
+
+    fn digits(input: &str) -> Result<(), &'static str> {
+        if input.chars().all(|chr| chr.is_ascii_digit()) {
+            Ok(())
+        } else {
+            Err("not digits")
+        }
+    }
+
+    #[test]
+    fn test_combinators() {
+        let mut all = all_of((min_len(3), digits));
+        assert_eq!(all.validate("123"), Ok(()));
+        assert_eq!(all.validate("1a"), Err("shorter than 3; not digits".into()));
+
+        let mut any = any_of([min_len(3), min_len(5)]);
+        assert_eq!(any.validate("1234"), Ok(()));
+        assert_eq!(
+            any.validate("1"),
+            Err("shorter than 3; shorter than 5".into())
+        );
+
+        let mut letters = not(digits, "must not be a number");
+        assert_eq!(letters.validate("abc"), Ok(()));
+        assert_eq!(letters.validate("123"), Err("must not be a number".into()));
+
+        let mut zip_code = optional(all_of((min_len(5), digits)));
+        assert_eq!(zip_code.validate(""), Ok(()));
+        assert!(zip_code.validate("12").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "password")]
+    fn test_password_policy() {
+        let policy = PasswordPolicy::new()
+            .min_len(8)
+            .require_uppercase(true)
+            .require_symbol(true)
+            .deny_whitespace(true)
+            .deny(["Passw0rd!"]);
+
+        let validate = |password: &str| policy.validate(&password.to_string());
+
+        assert!(validate("Sh0rt!").unwrap_err().contains("at least 8"));
+        assert!(validate("no uppercase!").unwrap_err().contains("uppercase"));
+        assert!(validate("NoSymbols1").unwrap_err().contains("symbol"));
+        assert!(validate("With Space!").unwrap_err().contains("whitespace"));
+        assert!(validate("PASSW0RD!").unwrap_err().contains("common"));
+        assert!(validate("C0rrect-Horse").is_ok());
+    }
+}